@@ -0,0 +1,72 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use qudoku::{
+    interpolate_line, recover_secret, InterpolatedPointPolynomial, InterpolatedSecretPolynomial,
+    Polynomial, SecretSharingPolynomial,
+};
+use secp::MaybeScalar;
+
+fn bench_threshold_two(c: &mut Criterion) {
+    let coefficients = [MaybeScalar::from(0xDEADBEEF), MaybeScalar::from(0xC0FFEE)];
+    let f = SecretSharingPolynomial::new(coefficients.to_vec());
+    let f_array = SecretSharingPolynomial::new(coefficients);
+    let q = qudoku::hash_to_point_tagged(b"qudoku/bench", b"benchmark");
+
+    let shares = [
+        f.issue_share(MaybeScalar::from(3)),
+        f.issue_share(MaybeScalar::from(8)),
+    ];
+    let point_shares = [shares[0] * q, shares[1] * q];
+
+    c.bench_function("deal 2-of-10: vec polynomial", |b| {
+        b.iter(|| {
+            (1..=10u128)
+                .map(|i| f.issue_share(black_box(MaybeScalar::from(i))))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("deal 2-of-10: array polynomial", |b| {
+        b.iter(|| {
+            (1..=10u128)
+                .map(|i| f_array.issue_share(black_box(MaybeScalar::from(i))))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    c.bench_function("recover secret: lagrange", |b| {
+        b.iter(|| {
+            InterpolatedSecretPolynomial::new(black_box(shares).to_vec())
+                .evaluate(MaybeScalar::Zero)
+        })
+    });
+    c.bench_function("recover secret: line", |b| {
+        b.iter(|| interpolate_line(&black_box(shares)[0], &shares[1], MaybeScalar::Zero))
+    });
+    c.bench_function("recover secret: recover_secret", |b| {
+        b.iter(|| recover_secret(black_box(&shares), 2))
+    });
+
+    c.bench_function("recover nested point: lagrange", |b| {
+        b.iter(|| {
+            let poly = InterpolatedPointPolynomial::new(black_box(point_shares).to_vec());
+            Polynomial::evaluate(&poly, MaybeScalar::Zero)
+        })
+    });
+    c.bench_function("recover nested point: line", |b| {
+        b.iter(|| {
+            interpolate_line(
+                &black_box(point_shares)[0],
+                &point_shares[1],
+                MaybeScalar::Zero,
+            )
+        })
+    });
+    c.bench_function("recover nested point: evaluate_fast", |b| {
+        b.iter(|| {
+            InterpolatedPointPolynomial::new(black_box(point_shares).to_vec())
+                .evaluate_fast(MaybeScalar::Zero)
+        })
+    });
+}
+
+criterion_group!(benches, bench_threshold_two);
+criterion_main!(benches);
@@ -0,0 +1,69 @@
+use crate::tagged_hash;
+use alloc::vec::Vec;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+/// The length of the authentication tag appended to each ciphertext.
+pub(crate) const TAG_LEN: usize = 16;
+
+/// Encrypt and authenticate a plaintext with ChaCha20-Poly1305.
+pub(crate) fn seal(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    ChaCha20Poly1305::new(&Key::from(*key))
+        .encrypt(
+            &Nonce::from(*nonce),
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .expect("ChaCha20-Poly1305 encryption cannot fail for in-memory buffers")
+}
+
+/// Authenticate and decrypt a ciphertext produced by [`seal`]. Returns `None` if
+/// authentication fails.
+pub(crate) fn open(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Option<Vec<u8>> {
+    ChaCha20Poly1305::new(&Key::from(*key))
+        .decrypt(
+            &Nonce::from(*nonce),
+            Payload {
+                msg: ciphertext,
+                aad,
+            },
+        )
+        .ok()
+}
+
+/// The length of the nonce prepended to ciphertexts by [`encrypt_with_secret`].
+const NONCE_LEN: usize = 12;
+
+/// Encrypt a plaintext with a key derived from a 32-byte secret. The nonce is derived
+/// deterministically from the key and plaintext, and prepended to the ciphertext, so
+/// equal plaintexts under the same secret produce equal ciphertexts.
+pub(crate) fn encrypt_with_secret(secret: &[u8; 32], plaintext: &[u8]) -> Vec<u8> {
+    let key = tagged_hash(b"qudoku/aead/key", secret);
+
+    let mut nonce_preimage = key.to_vec();
+    nonce_preimage.extend_from_slice(plaintext);
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce.copy_from_slice(&tagged_hash(b"qudoku/aead/nonce", &nonce_preimage)[..NONCE_LEN]);
+
+    let mut out = nonce.to_vec();
+    out.extend_from_slice(&seal(&key, &nonce, &[], plaintext));
+    out
+}
+
+/// Decrypt a ciphertext produced by [`encrypt_with_secret`].
+pub(crate) fn decrypt_with_secret(secret: &[u8; 32], ciphertext: &[u8]) -> Option<Vec<u8>> {
+    if ciphertext.len() < NONCE_LEN + TAG_LEN {
+        return None;
+    }
+    let key = tagged_hash(b"qudoku/aead/key", secret);
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce.copy_from_slice(&ciphertext[..NONCE_LEN]);
+    open(&key, &nonce, &[], &ciphertext[NONCE_LEN..])
+}
@@ -0,0 +1,181 @@
+//! A minimal [bech32m](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)
+//! codec for encoding shares as human-copyable strings.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// Errors which can occur when decoding a bech32m string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Bech32Error {
+    /// The string mixes upper and lower case characters.
+    MixedCase,
+
+    /// The string has no `1` separator, or an empty or invalid human-readable part.
+    InvalidHrp,
+
+    /// The data part contains a character outside the bech32 character set,
+    /// or is too short to contain a checksum.
+    InvalidData,
+
+    /// The checksum does not match, indicating a transcription error.
+    InvalidChecksum,
+
+    /// The data part has invalid padding bits.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for Bech32Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Bech32Error::MixedCase => write!(f, "bech32m string has mixed case"),
+            Bech32Error::InvalidHrp => write!(f, "invalid bech32m human-readable part"),
+            Bech32Error::InvalidData => write!(f, "invalid bech32m data characters"),
+            Bech32Error::InvalidChecksum => write!(f, "invalid bech32m checksum"),
+            Bech32Error::InvalidPadding => write!(f, "invalid bech32m padding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Bech32Error {}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATORS: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    let mut checksum: u32 = 1;
+    for v in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x01ff_ffff) << 5) ^ (v as u32);
+        for (i, g) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= g;
+            }
+        }
+    }
+    checksum
+}
+
+fn hrp_expand(hrp: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    hrp.iter()
+        .map(|c| c >> 5)
+        .chain(core::iter::once(0))
+        .chain(hrp.iter().map(|c| c & 31))
+}
+
+/// Regroup a sequence of `from`-bit values into `to`-bit values.
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, Bech32Error> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let max = (1 << to) - 1;
+    let mut out = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    for &value in data {
+        acc = (acc << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || (acc << (to - bits)) & max != 0 {
+        return Err(Bech32Error::InvalidPadding);
+    }
+    Ok(out)
+}
+
+/// Encode some bytes as a lower-case bech32m string with the given human-readable part.
+pub(crate) fn encode(hrp: &str, data: &[u8]) -> String {
+    let hrp = hrp.to_ascii_lowercase();
+    let values = convert_bits(data, 8, 5, true).unwrap();
+
+    let checksum = polymod(
+        hrp_expand(hrp.as_bytes())
+            .chain(values.iter().copied())
+            .chain([0u8; 6]),
+    ) ^ BECH32M_CONST;
+
+    let mut out = String::with_capacity(hrp.len() + 1 + values.len() + 6);
+    out.push_str(&hrp);
+    out.push('1');
+    for v in values {
+        out.push(CHARSET[v as usize] as char);
+    }
+    for i in 0..6 {
+        out.push(CHARSET[((checksum >> (5 * (5 - i))) & 31) as usize] as char);
+    }
+    out
+}
+
+/// Decode a bech32m string, returning its lower-cased human-readable part and data bytes.
+pub(crate) fn decode(s: &str) -> Result<(String, Vec<u8>), Bech32Error> {
+    if s.bytes().any(|c| c.is_ascii_lowercase()) && s.bytes().any(|c| c.is_ascii_uppercase()) {
+        return Err(Bech32Error::MixedCase);
+    }
+    let s = s.to_ascii_lowercase();
+
+    let sep = s.rfind('1').ok_or(Bech32Error::InvalidHrp)?;
+    let (hrp, data) = (&s[..sep], &s[sep + 1..]);
+    if hrp.is_empty() || hrp.bytes().any(|c| !(33..=126).contains(&c)) {
+        return Err(Bech32Error::InvalidHrp);
+    }
+    if data.len() < 6 {
+        return Err(Bech32Error::InvalidData);
+    }
+
+    let values = data
+        .bytes()
+        .map(|c| {
+            CHARSET
+                .iter()
+                .position(|&d| d == c)
+                .map(|v| v as u8)
+                .ok_or(Bech32Error::InvalidData)
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    if polymod(hrp_expand(hrp.as_bytes()).chain(values.iter().copied())) != BECH32M_CONST {
+        return Err(Bech32Error::InvalidChecksum);
+    }
+
+    let bytes = convert_bits(&values[..values.len() - 6], 5, 8, false)?;
+    Ok((hrp.to_string(), bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bech32m_vectors() {
+        // Valid bech32m strings from BIP-350.
+        for s in [
+            "A1LQFN3A",
+            "a1lqfn3a",
+            "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+            "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+            "?1v759aa",
+        ] {
+            let (hrp, data) = decode(s).unwrap();
+            assert_eq!(encode(&hrp, &data), s.to_ascii_lowercase());
+        }
+
+        // Invalid checksum (bech32 instead of bech32m).
+        assert_eq!(decode("a12uel5l"), Err(Bech32Error::InvalidChecksum));
+        assert_eq!(decode("A1LQfN3A"), Err(Bech32Error::MixedCase));
+        assert_eq!(decode("1qzzfhee"), Err(Bech32Error::InvalidHrp));
+        assert_eq!(decode("abc1b"), Err(Bech32Error::InvalidData));
+    }
+}
@@ -0,0 +1,244 @@
+//! Asynchronous dealing over an append-only bulletin board.
+//!
+//! The dealer posts everything in one pass, with no round-trips: the group's
+//! [`VssCommitment`], followed by each shareholder's share encrypted to their key as
+//! an [`EscrowShare`]. Each encrypted share carries a proof which ties it to the
+//! commitment, so anyone can [audit][audit_dealing] the whole dealing from the board
+//! alone, and each shareholder can [pull][pull_share] and verify their own share at
+//! any later time.
+//!
+//! Storage is abstracted by the [`BulletinBoard`] trait. A `Vec<BoardPost>` is a
+//! simple in-memory board.
+
+use crate::escrow::{EscrowError, EscrowShare};
+use crate::{SecretShare, VssCommitment};
+use alloc::{boxed::Box, vec::Vec};
+use secp::{MaybeScalar, Scalar, G};
+
+/// A post on a [`BulletinBoard`]. Every post names the dealing it belongs to by the
+/// [fingerprint][VssCommitment::fingerprint] of the dealing's commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BoardPost {
+    /// The commitment to a dealing's polynomial.
+    Commitment(VssCommitment),
+
+    /// A share of the dealing with the given fingerprint, encrypted to its recipient.
+    Share {
+        fingerprint: [u8; 32],
+        share: Box<EscrowShare>,
+    },
+}
+
+/// An append-only store of [`BoardPost`]s, readable by every participant.
+///
+/// Implementations must never modify or remove a post once appended, and must return
+/// posts to every reader in the same order.
+pub trait BulletinBoard {
+    /// The error type returned by the board.
+    type Error;
+
+    /// Append a post to the board.
+    fn post(&mut self, post: BoardPost) -> Result<(), Self::Error>;
+
+    /// Returns every post on the board, in the order they were appended.
+    fn posts(&self) -> Result<Vec<BoardPost>, Self::Error>;
+}
+
+/// An in-memory bulletin board.
+impl BulletinBoard for Vec<BoardPost> {
+    type Error = core::convert::Infallible;
+
+    fn post(&mut self, post: BoardPost) -> Result<(), Self::Error> {
+        self.push(post);
+        Ok(())
+    }
+
+    fn posts(&self) -> Result<Vec<BoardPost>, Self::Error> {
+        Ok(self.clone())
+    }
+}
+
+/// Errors which can occur when auditing a dealing or pulling a share from a board.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoardError<E> {
+    /// The board itself returned an error.
+    Board(E),
+
+    /// No commitment with the requested fingerprint was posted.
+    MissingCommitment,
+
+    /// The encrypted share at the given input is not consistent with the commitment.
+    InvalidShare(MaybeScalar),
+
+    /// More than one share was posted at the given input.
+    DuplicateInput(MaybeScalar),
+
+    /// No share was posted to the shareholder's key.
+    NoShare,
+
+    /// The shareholder's share could not be opened.
+    Escrow(EscrowError),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for BoardError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BoardError::Board(e) => write!(f, "bulletin board error: {}", e),
+            BoardError::MissingCommitment => write!(f, "no commitment posted for the dealing"),
+            BoardError::InvalidShare(x) => write!(f, "invalid share posted at input {:?}", x),
+            BoardError::DuplicateInput(x) => {
+                write!(f, "more than one share posted at input {:?}", x)
+            }
+            BoardError::NoShare => write!(f, "no share posted to this key"),
+            BoardError::Escrow(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for BoardError<E> {}
+
+/// A dealing read back from a board, whose encrypted shares have all been verified
+/// against its commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoardDealing {
+    pub commitment: VssCommitment,
+    pub shares: Vec<EscrowShare>,
+}
+
+/// Post a dealing to the board: first its commitment, then each encrypted share,
+/// issued with [`EscrowShare::issue`] at each shareholder's input.
+pub fn post_dealing<B: BulletinBoard>(
+    board: &mut B,
+    commitment: VssCommitment,
+    shares: Vec<EscrowShare>,
+) -> Result<(), B::Error> {
+    let fingerprint = commitment.fingerprint();
+    board.post(BoardPost::Commitment(commitment))?;
+    for share in shares {
+        board.post(BoardPost::Share {
+            fingerprint,
+            share: Box::new(share),
+        })?;
+    }
+    Ok(())
+}
+
+/// Read the dealing with the given commitment fingerprint from the board, and check
+/// every share posted for it against the commitment. Posts for other dealings are
+/// ignored. This needs no secrets, so anyone can audit a dealing.
+pub fn audit_dealing<B: BulletinBoard>(
+    board: &B,
+    fingerprint: &[u8; 32],
+) -> Result<BoardDealing, BoardError<B::Error>> {
+    let posts = board.posts().map_err(BoardError::Board)?;
+    let commitment = posts
+        .iter()
+        .find_map(|post| match post {
+            BoardPost::Commitment(c) if &c.fingerprint() == fingerprint => Some(c.clone()),
+            _ => None,
+        })
+        .ok_or(BoardError::MissingCommitment)?;
+
+    let mut shares: Vec<EscrowShare> = Vec::new();
+    for post in posts {
+        let share = match post {
+            BoardPost::Share {
+                fingerprint: f,
+                share,
+            } if &f == fingerprint => *share,
+            _ => continue,
+        };
+        if !share.verify(&commitment) {
+            return Err(BoardError::InvalidShare(share.input));
+        }
+        if shares.iter().any(|s| s.input == share.input) {
+            return Err(BoardError::DuplicateInput(share.input));
+        }
+        shares.push(share);
+    }
+    Ok(BoardDealing { commitment, shares })
+}
+
+/// Audit the dealing with the given fingerprint, then find and decrypt the share
+/// posted to the shareholder with the given secret key.
+pub fn pull_share<B: BulletinBoard>(
+    board: &B,
+    fingerprint: &[u8; 32],
+    secret: Scalar,
+) -> Result<SecretShare, BoardError<B::Error>> {
+    let dealing = audit_dealing(board, fingerprint)?;
+    let key = secret * G;
+    let share = dealing
+        .shares
+        .iter()
+        .find(|s| s.agent == key)
+        .ok_or(BoardError::NoShare)?;
+    share
+        .open(secret, &dealing.commitment)
+        .map_err(BoardError::Escrow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+
+    #[test]
+    fn test_bulletin_board_dealing() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(6)]);
+        let commitment = f.commitment();
+        let fingerprint = commitment.fingerprint();
+        let secrets: Vec<Scalar> = (1..=3u128)
+            .map(|i| MaybeScalar::from(700 + i).unwrap())
+            .collect();
+        let shares = secrets
+            .iter()
+            .enumerate()
+            .map(|(i, &secret)| {
+                let x = MaybeScalar::from(i as u128 + 1);
+                let ephemeral = MaybeScalar::from(800 + i as u128).unwrap();
+                EscrowShare::issue(&f, x, secret * G, ephemeral)
+            })
+            .collect();
+
+        let mut board: Vec<BoardPost> = Vec::new();
+        post_dealing(&mut board, commitment.clone(), shares).unwrap();
+
+        let dealing = audit_dealing(&board, &fingerprint).unwrap();
+        assert_eq!(dealing.commitment, commitment);
+        assert_eq!(dealing.shares.len(), 3);
+        for (i, &secret) in secrets.iter().enumerate() {
+            let x = MaybeScalar::from(i as u128 + 1);
+            assert_eq!(
+                pull_share(&board, &fingerprint, secret),
+                Ok(f.issue_share(x))
+            );
+        }
+
+        let stranger = MaybeScalar::from(999).unwrap();
+        assert_eq!(
+            pull_share(&board, &fingerprint, stranger),
+            Err(BoardError::NoShare)
+        );
+        assert_eq!(
+            audit_dealing(&board, &[0; 32]),
+            Err(BoardError::MissingCommitment)
+        );
+
+        // A share for another polynomial posted under this dealing fails the audit.
+        let g = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(7)]);
+        let x = MaybeScalar::from(4);
+        let bogus = EscrowShare::issue(&g, x, stranger * G, stranger);
+        board
+            .post(BoardPost::Share {
+                fingerprint,
+                share: Box::new(bogus),
+            })
+            .unwrap();
+        assert_eq!(
+            audit_dealing(&board, &fingerprint),
+            Err(BoardError::InvalidShare(x))
+        );
+    }
+}
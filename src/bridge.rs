@@ -0,0 +1,86 @@
+//! Export of qudoku secrets as keys on other curves.
+//!
+//! A nested secret derived with [`derive_secret`][crate::PointSharingPolynomial::derive_secret]
+//! is a 32-byte hash, which is not directly usable as a scalar on every curve. This module
+//! expands such a secret into 64 uniformly random bytes with a caller-chosen domain
+//! separation string, which each curve backend then reduces modulo its own group order.
+//! Because the wide reduction is nearly unbiased, one qudoku group can back keys in
+//! ecosystems on different curves, and every party performing the derivation arrives at
+//! the same key.
+//!
+//! Different `domain` strings yield independent keys. Reusing a domain across curves
+//! should be avoided.
+
+use crate::{scalar_from_wide, tagged_hash};
+use alloc::vec::Vec;
+use secp::MaybeScalar;
+
+/// Expand a 32-byte secret into 64 bytes bound to the given domain.
+///
+/// Each half is computed as a tagged hash of `i || len(domain) || domain || secret`,
+/// where `i` is the index of the half as a single byte and `len(domain)` is a
+/// 4-byte big-endian integer.
+pub fn bridge_secret(secret: &[u8; 32], domain: &[u8]) -> [u8; 64] {
+    let mut preimage = Vec::with_capacity(1 + 4 + domain.len() + 32);
+    preimage.push(0);
+    preimage.extend_from_slice(&(domain.len() as u32).to_be_bytes());
+    preimage.extend_from_slice(domain);
+    preimage.extend_from_slice(secret);
+
+    let mut out = [0u8; 64];
+    out[..32].copy_from_slice(&tagged_hash(b"qudoku/bridge", &preimage));
+    preimage[0] = 1;
+    out[32..].copy_from_slice(&tagged_hash(b"qudoku/bridge", &preimage));
+    out
+}
+
+/// Derive a secp256k1 scalar from a secret, by interpreting the output of
+/// [`bridge_secret`] as a big-endian integer and reducing it modulo the curve order.
+pub fn secp256k1_scalar(secret: &[u8; 32], domain: &[u8]) -> MaybeScalar {
+    scalar_from_wide(&bridge_secret(secret, domain))
+}
+
+/// Derive a Ristretto255 scalar from a secret, by interpreting the output of
+/// [`bridge_secret`] as a little-endian integer and reducing it modulo the group
+/// order, as is conventional for Ristretto.
+#[cfg(feature = "ristretto")]
+pub fn ristretto_scalar(secret: &[u8; 32], domain: &[u8]) -> curve25519_dalek::Scalar {
+    curve25519_dalek::Scalar::from_bytes_mod_order_wide(&bridge_secret(secret, domain))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 32] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31,
+    ];
+    const DOMAIN: &[u8] = b"example/ristretto-key";
+
+    #[test]
+    fn test_bridge_secret_vectors() {
+        assert_eq!(
+            hex::encode(bridge_secret(&SECRET, DOMAIN)),
+            "8357dcc0cb89b7ac0c1a9646f5c3a1b3d788c0f889d77f2edba86f1d72262b93\
+             8cd136a09261653049a77556be3e81a1d694cdd2df04b696d0988708b4b2e043"
+        );
+        assert_eq!(
+            hex::encode(secp256k1_scalar(&SECRET, DOMAIN).serialize()),
+            "9b17a0ffa3f61f446acab0619b48d2133699588e9dddcce82f15847eafd2c5ab"
+        );
+        assert_ne!(
+            bridge_secret(&SECRET, b"example/other-key"),
+            bridge_secret(&SECRET, DOMAIN)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ristretto")]
+    fn test_ristretto_scalar_vector() {
+        assert_eq!(
+            hex::encode(ristretto_scalar(&SECRET, DOMAIN).to_bytes()),
+            "0ce2326188b310dbf7f2cb2c8c442314ff20db0e345e97691179d5229b6e1a02"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    horner_poly_evaluate, Error, Evaluation, Gf256, LagrangePolynomial, StandardFormPolynomial,
+};
+use alloc::vec::Vec;
+
+/// A share of an arbitrary byte string, split bytewise using Shamir's scheme over
+/// `GF(2^8)`. The output holds one share byte for each byte of the secret.
+///
+/// Byte shares are information-theoretically secure, and reveal nothing about the
+/// secret except its length.
+pub type ByteShare = Evaluation<Gf256, Vec<u8>>;
+
+/// A set of polynomials over `GF(2^8)` which share a byte string of any length,
+/// such as a 64-byte seed or a passphrase, with one polynomial per secret byte.
+///
+/// Shares are issued at non-zero inputs, so at most 255 shares can be issued.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ByteSharingPolynomial {
+    /// One polynomial for each secret byte. All have the same number of coefficients.
+    pub polynomials: Vec<StandardFormPolynomial<Gf256>>,
+}
+
+impl ByteSharingPolynomial {
+    /// Construct a byte sharing polynomial for `secret` with the given higher-order
+    /// coefficients, in ascending order of degree. Byte `j` of each coefficient
+    /// belongs to the polynomial sharing byte `j` of the secret. The threshold is
+    /// `higher_coefficients.len() + 1`.
+    ///
+    /// Panics if any coefficient is not the same length as the secret.
+    pub fn new(secret: &[u8], higher_coefficients: &[Vec<u8>]) -> Self {
+        assert!(
+            higher_coefficients.iter().all(|c| c.len() == secret.len()),
+            "coefficients must be the same length as the secret"
+        );
+
+        let polynomials = secret
+            .iter()
+            .enumerate()
+            .map(|(j, &byte)| {
+                let coefficients = core::iter::once(byte)
+                    .chain(higher_coefficients.iter().map(|c| c[j]))
+                    .map(Gf256)
+                    .collect();
+                StandardFormPolynomial::new(coefficients)
+            })
+            .collect();
+        ByteSharingPolynomial { polynomials }
+    }
+
+    /// Sample a random byte sharing polynomial for `secret`, such that any
+    /// `threshold` shares can recover it.
+    ///
+    /// Panics if `threshold` is zero.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(
+        secret: &[u8],
+        threshold: usize,
+        rng: &mut R,
+    ) -> Self {
+        assert_ne!(threshold, 0, "threshold must be at least one");
+        let higher_coefficients: Vec<Vec<u8>> = (1..threshold)
+            .map(|_| {
+                let mut coefficient = alloc::vec![0u8; secret.len()];
+                rng.fill_bytes(&mut coefficient);
+                coefficient
+            })
+            .collect();
+        ByteSharingPolynomial::new(secret, &higher_coefficients)
+    }
+
+    /// Returns the shared secret.
+    pub fn secret(&self) -> Vec<u8> {
+        self.polynomials
+            .iter()
+            .map(|p| p.coefficients[0].0)
+            .collect()
+    }
+
+    /// Issue a byte share at the given non-zero index `x`.
+    ///
+    /// Panics if `x` is zero, as this would reveal the secret.
+    pub fn issue_share(&self, x: u8) -> ByteShare {
+        assert_ne!(x, 0, "cannot issue a byte share at index zero");
+        let output = self
+            .polynomials
+            .iter()
+            .map(|p| horner_poly_evaluate::<_, _, Gf256>(Gf256(x), &p.coefficients).0)
+            .collect();
+        Evaluation::new(Gf256(x), output)
+    }
+}
+
+/// Recover a byte string from a quorum of byte shares.
+///
+/// Returns an error if the shares are empty, if their outputs differ in length, or if
+/// any share has a duplicate or zero input. Too few shares yield an incorrect secret
+/// rather than an error.
+pub fn recover_bytes(shares: &[ByteShare]) -> Result<Vec<u8>, Error> {
+    let len = shares.first().ok_or(Error::Empty)?.output.len();
+    if let Some(index) = shares.iter().position(|share| share.output.len() != len) {
+        return Err(Error::LengthMismatch { index });
+    }
+
+    let basis = LagrangePolynomial::try_new_nonzero(shares.to_vec())?.basis_at(Gf256(0));
+
+    let mut secret = alloc::vec![0u8; len];
+    for (share, l) in shares.iter().zip(basis) {
+        for (byte, &share_byte) in secret.iter_mut().zip(share.output.iter()) {
+            *byte ^= (Gf256(share_byte) * l).0;
+        }
+    }
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_sharing() {
+        let secret = b"correct horse battery staple, with a seed longer than 32 bytes";
+        let coefficients = vec![vec![0x5A; secret.len()], (0..secret.len() as u8).collect()];
+        let polynomial = ByteSharingPolynomial::new(secret, &coefficients);
+        assert_eq!(polynomial.secret(), secret);
+
+        let shares: Vec<ByteShare> = (1..=5).map(|x| polynomial.issue_share(x)).collect();
+        assert_eq!(recover_bytes(&shares[..3]), Ok(secret.to_vec()));
+        assert_eq!(recover_bytes(&shares[2..]), Ok(secret.to_vec()));
+        assert_ne!(recover_bytes(&shares[..2]), Ok(secret.to_vec()));
+
+        let mut truncated = shares[..3].to_vec();
+        truncated[1].output.pop();
+        assert_eq!(
+            recover_bytes(&truncated),
+            Err(Error::LengthMismatch { index: 1 })
+        );
+        assert_eq!(
+            recover_bytes(&[shares[0].clone(), shares[0].clone()]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 1
+            })
+        );
+        assert_eq!(recover_bytes(&[]), Err(Error::Empty));
+    }
+}
@@ -0,0 +1,289 @@
+//! Cold-storage dealing, where the dealer machine is offline and never contacted
+//! again after dealing.
+//!
+//! The dealer emits a single public artifact, the encoded [`VssCommitment`], and one
+//! [`SealedBundle`] per shareholder, each of which can be carried away on removable
+//! media. A bundle holds the shareholder's share and the commitment's fingerprint,
+//! encrypted to the shareholder's public key. On import, [`import_bundle`] checks
+//! everything against the public artifact alone: the bundle must be addressed to the
+//! shareholder's key, be bound to the same commitment, and hold a share consistent
+//! with it.
+
+use crate::ecies::{decrypt, encrypt, EciesError};
+use crate::{LimitExceeded, Limits, PointSharingPolynomial, SecretShare, VssCommitment};
+use alloc::vec::Vec;
+use secp::{MaybePoint, Point, Scalar, G};
+
+/// Errors which can occur when importing cold-storage artifacts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColdStorageError {
+    /// The public artifact or bundle is malformed.
+    InvalidEncoding,
+
+    /// The public artifact or bundle is larger than the default [`Limits`] allow.
+    LimitExceeded(LimitExceeded),
+
+    /// The bundle is addressed to a different public key.
+    WrongRecipient,
+
+    /// The bundle could not be decrypted.
+    Decryption(EciesError),
+
+    /// The bundle was sealed for a different group commitment.
+    FingerprintMismatch,
+
+    /// The share in the bundle is not consistent with the group commitment.
+    Inconsistent,
+}
+
+impl core::fmt::Display for ColdStorageError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ColdStorageError::InvalidEncoding => write!(f, "invalid cold-storage encoding"),
+            ColdStorageError::LimitExceeded(e) => e.fmt(f),
+            ColdStorageError::WrongRecipient => {
+                write!(f, "bundle is addressed to a different key")
+            }
+            ColdStorageError::Decryption(e) => e.fmt(f),
+            ColdStorageError::FingerprintMismatch => {
+                write!(f, "bundle was sealed for a different group commitment")
+            }
+            ColdStorageError::Inconsistent => {
+                write!(f, "share is not consistent with the group commitment")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColdStorageError {}
+
+/// A share encrypted to a single shareholder, bound to the group commitment's
+/// fingerprint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SealedBundle {
+    /// The shareholder's public key.
+    pub recipient: Point,
+
+    /// The share and the commitment fingerprint, encrypted with [`encrypt`].
+    pub ciphertext: Vec<u8>,
+}
+
+impl SealedBundle {
+    /// Seal a share of the group with the given commitment to the recipient's public
+    /// key. The ephemeral key must be uniformly random and never reused.
+    pub fn seal(
+        share: &SecretShare,
+        commitment: &VssCommitment,
+        recipient: Point,
+        ephemeral_secret: Scalar,
+    ) -> Self {
+        let mut plaintext = [0u8; 64 + 32];
+        plaintext[..64].copy_from_slice(&share.to_bytes());
+        plaintext[64..].copy_from_slice(&commitment.fingerprint());
+        SealedBundle {
+            recipient,
+            ciphertext: encrypt(recipient, ephemeral_secret, &plaintext),
+        }
+    }
+
+    /// Encode the bundle as `recipient || ciphertext`, for writing to removable media.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(33 + self.ciphertext.len());
+        bytes.extend_from_slice(&self.recipient.serialize());
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes
+    }
+
+    /// Decode a bundle encoded with [`to_bytes`][Self::to_bytes].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ColdStorageError> {
+        Limits::default()
+            .check_message_size(bytes.len())
+            .map_err(ColdStorageError::LimitExceeded)?;
+        if bytes.len() < 33 {
+            return Err(ColdStorageError::InvalidEncoding);
+        }
+        let recipient =
+            Point::from_slice(&bytes[..33]).map_err(|_| ColdStorageError::InvalidEncoding)?;
+        Ok(SealedBundle {
+            recipient,
+            ciphertext: bytes[33..].to_vec(),
+        })
+    }
+
+    /// Decrypt the bundle with the shareholder's secret key, and check the share
+    /// against the group commitment.
+    pub fn open(
+        &self,
+        secret: Scalar,
+        commitment: &VssCommitment,
+    ) -> Result<SecretShare, ColdStorageError> {
+        if secret * G != self.recipient {
+            return Err(ColdStorageError::WrongRecipient);
+        }
+        let plaintext: [u8; 64 + 32] = decrypt(secret, &self.ciphertext)
+            .map_err(ColdStorageError::Decryption)?
+            .try_into()
+            .map_err(|_| ColdStorageError::InvalidEncoding)?;
+        if plaintext[64..] != commitment.fingerprint() {
+            return Err(ColdStorageError::FingerprintMismatch);
+        }
+        let share = SecretShare::from_bytes(plaintext[..64].try_into().unwrap())
+            .map_err(|_| ColdStorageError::InvalidEncoding)?;
+        if !commitment.verify_share(&share) {
+            return Err(ColdStorageError::Inconsistent);
+        }
+        Ok(share)
+    }
+}
+
+/// The output of an offline dealing: the public commitment, and a sealed bundle for
+/// each shareholder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColdDealing {
+    pub commitment: VssCommitment,
+    pub bundles: Vec<SealedBundle>,
+}
+
+impl ColdDealing {
+    /// Deal `polynomial` to each `(input, public key)` recipient, sealing every share
+    /// with a fresh random ephemeral key.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(
+        polynomial: &crate::SecretSharingPolynomial,
+        recipients: &[(secp::MaybeScalar, Point)],
+        rng: &mut R,
+    ) -> Self {
+        let commitment = polynomial.commitment();
+        let bundles = recipients
+            .iter()
+            .map(|&(input, key)| {
+                let share = polynomial.issue_share(input);
+                SealedBundle::seal(&share, &commitment, key, Scalar::random(rng))
+            })
+            .collect();
+        ColdDealing {
+            commitment,
+            bundles,
+        }
+    }
+
+    /// Encode the public artifact, the group commitment, as a 4-byte big-endian
+    /// coefficient count followed by each compressed coefficient.
+    pub fn public_artifact(&self) -> Vec<u8> {
+        let coefficients = self.commitment.coefficients();
+        let mut bytes = Vec::with_capacity(4 + 33 * coefficients.len());
+        bytes.extend_from_slice(&(coefficients.len() as u32).to_be_bytes());
+        for c in coefficients {
+            bytes.extend_from_slice(&c.serialize());
+        }
+        bytes
+    }
+}
+
+/// Decode a public artifact encoded with [`ColdDealing::public_artifact`].
+pub fn parse_public_artifact(bytes: &[u8]) -> Result<VssCommitment, ColdStorageError> {
+    let limits = Limits::default();
+    limits
+        .check_message_size(bytes.len())
+        .map_err(ColdStorageError::LimitExceeded)?;
+    if bytes.len() < 4 {
+        return Err(ColdStorageError::InvalidEncoding);
+    }
+    let count = u32::from_be_bytes(bytes[..4].try_into().unwrap()) as usize;
+    if count == 0 {
+        return Err(ColdStorageError::InvalidEncoding);
+    }
+    limits
+        .check_degree(count - 1)
+        .map_err(ColdStorageError::LimitExceeded)?;
+    if bytes.len() != 4 + 33 * count {
+        return Err(ColdStorageError::InvalidEncoding);
+    }
+    let coefficients = bytes[4..]
+        .chunks_exact(33)
+        .map(MaybePoint::from_slice)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| ColdStorageError::InvalidEncoding)?;
+    Ok(VssCommitment::new(PointSharingPolynomial::new(
+        coefficients,
+    )))
+}
+
+/// Import a shareholder's bundle using only the dealer's public artifact and the
+/// shareholder's secret key, returning the group commitment and the verified share.
+pub fn import_bundle(
+    public_artifact: &[u8],
+    bundle: &[u8],
+    secret: Scalar,
+) -> Result<(VssCommitment, SecretShare), ColdStorageError> {
+    let commitment = parse_public_artifact(public_artifact)?;
+    let share = SealedBundle::from_bytes(bundle)?.open(secret, &commitment)?;
+    Ok((commitment, share))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_cold_storage_dealing() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(13), MaybeScalar::from(21)]);
+        let commitment = f.commitment();
+        let secrets: Vec<Scalar> = (1..=3u128)
+            .map(|i| MaybeScalar::from(500 + i).unwrap())
+            .collect();
+        let bundles = secrets
+            .iter()
+            .enumerate()
+            .map(|(i, &secret)| {
+                let share = f.issue_share(MaybeScalar::from(i as u128 + 1));
+                let ephemeral = MaybeScalar::from(900 + i as u128).unwrap();
+                SealedBundle::seal(&share, &commitment, secret * G, ephemeral)
+            })
+            .collect();
+        let dealing = ColdDealing {
+            commitment: commitment.clone(),
+            bundles,
+        };
+
+        // Only bytes leave the dealer machine.
+        let artifact = dealing.public_artifact();
+        let media: Vec<Vec<u8>> = dealing.bundles.iter().map(SealedBundle::to_bytes).collect();
+
+        for (i, (bundle, &secret)) in media.iter().zip(&secrets).enumerate() {
+            let (imported, share) = import_bundle(&artifact, bundle, secret).unwrap();
+            assert_eq!(imported, commitment);
+            assert_eq!(share, f.issue_share(MaybeScalar::from(i as u128 + 1)));
+        }
+
+        assert_eq!(
+            import_bundle(&artifact, &media[0], secrets[1]),
+            Err(ColdStorageError::WrongRecipient)
+        );
+
+        // A bundle for another group is rejected against this group's artifact.
+        let g = SecretSharingPolynomial::new(vec![MaybeScalar::from(13), MaybeScalar::from(22)]);
+        let foreign = ColdDealing {
+            commitment: g.commitment(),
+            bundles: Vec::new(),
+        };
+        assert_eq!(
+            import_bundle(&foreign.public_artifact(), &media[0], secrets[0]),
+            Err(ColdStorageError::FingerprintMismatch)
+        );
+
+        assert_eq!(
+            import_bundle(&artifact[..artifact.len() - 1], &media[0], secrets[0]),
+            Err(ColdStorageError::InvalidEncoding)
+        );
+        let mut tampered = media[0].clone();
+        tampered[40] ^= 1;
+        assert_eq!(
+            import_bundle(&artifact, &tampered, secrets[0]),
+            Err(ColdStorageError::Decryption(EciesError::DecryptionFailed))
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{
+    tagged_hash, NestedPreshares, Roster, SecretShare, SecretSharingPolynomial, VssCommitment,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use secp::Point;
+
+/// Errors which can occur during a commit-reveal dealing ceremony.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CeremonyError {
+    /// The threshold is zero, or greater than the number of active roster entries.
+    InvalidThreshold,
+
+    /// The identity is not an active entry in the committed roster.
+    UnknownIdentity(String),
+
+    /// The acknowledgement is for a different dealing commitment.
+    WrongDigest(String),
+
+    /// Shares cannot be revealed until these identities have acknowledged the commitment.
+    MissingAcknowledgements(Vec<String>),
+}
+
+impl core::fmt::Display for CeremonyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CeremonyError::InvalidThreshold => {
+                write!(f, "threshold does not fit the active roster")
+            }
+            CeremonyError::UnknownIdentity(id) => write!(f, "identity {:?} is not active", id),
+            CeremonyError::WrongDigest(id) => {
+                write!(f, "{:?} acknowledged a different commitment", id)
+            }
+            CeremonyError::MissingAcknowledgements(ids) => {
+                write!(f, "waiting for acknowledgements from {:?}", ids)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CeremonyError {}
+
+/// Everything a dealer publishes in the commit phase of a [`CommittedDealing`]: the
+/// Feldman VSS commitment to `f(x)`, the roster fixing which identity receives a share
+/// at which input, and the nested `Q` points whose pre-shares will be revealed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DealingCommitment {
+    pub commitment: VssCommitment,
+    pub roster: Roster,
+    pub nested_points: Vec<Point>,
+}
+
+impl DealingCommitment {
+    /// Returns a digest binding the commitment, the roster, and the nested points,
+    /// which shareholders sign off on with an [`Acknowledgement`].
+    pub fn digest(&self) -> [u8; 32] {
+        let roster = self.roster.serialize();
+        let mut preimage =
+            Vec::with_capacity(32 + 8 + roster.len() + 33 * self.nested_points.len());
+        preimage.extend_from_slice(&self.commitment.fingerprint());
+        preimage.extend_from_slice(&(roster.len() as u64).to_be_bytes());
+        preimage.extend_from_slice(&roster);
+        for q in &self.nested_points {
+            preimage.extend_from_slice(&q.serialize());
+        }
+        tagged_hash(b"qudoku/commit_reveal/digest", &preimage)
+    }
+
+    /// Acknowledge the commitment as the shareholder `identity`, once it has been
+    /// checked, e.g. that the roster and nested points are the expected ones.
+    pub fn acknowledge(&self, identity: &str) -> Acknowledgement {
+        Acknowledgement {
+            identity: identity.to_string(),
+            digest: self.digest(),
+        }
+    }
+
+    /// Check a share revealed to the shareholder `identity`: it must be issued at the
+    /// input the roster assigns to `identity`, and be consistent with the commitment.
+    pub fn verify_reveal(&self, identity: &str, share: &SecretShare) -> bool {
+        self.roster.resolve(identity) == Some(share.input) && self.commitment.verify_share(share)
+    }
+}
+
+/// A shareholder's acknowledgement of a [`DealingCommitment`]. Acknowledgements are
+/// not signed, so they must be delivered over an authenticated channel.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Acknowledgement {
+    pub identity: String,
+    pub digest: [u8; 32],
+}
+
+/// The dealer's state in the commit phase of a two-phase dealing ceremony.
+///
+/// The dealer first publishes the [`DealingCommitment`], which fixes the polynomial,
+/// the roster and the nested points. Shares can only be revealed with
+/// [`reveal`][Self::reveal] once every active roster entry has acknowledged that
+/// commitment, so the dealer cannot adapt any parameter after seeing who responds.
+#[derive(Clone)]
+pub struct CommittedDealing {
+    polynomial: SecretSharingPolynomial,
+    public: DealingCommitment,
+    digest: [u8; 32],
+    acknowledged: Vec<bool>,
+}
+
+/// Omits the secret polynomial.
+impl core::fmt::Debug for CommittedDealing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CommittedDealing")
+            .field("public", &self.public)
+            .field("acknowledged", &self.acknowledged)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CommittedDealing {
+    /// Commit to dealing `polynomial` to the active entries of `roster`, with
+    /// pre-shares for each of the nested points.
+    ///
+    /// Returns [`CeremonyError::InvalidThreshold`] if the polynomial has no
+    /// coefficients, or more coefficients than the roster has active entries.
+    pub fn new(
+        polynomial: SecretSharingPolynomial,
+        roster: Roster,
+        nested_points: Vec<Point>,
+    ) -> Result<Self, CeremonyError> {
+        let n = roster.active().count();
+        let threshold = polynomial.coefficients.len();
+        if threshold == 0 || threshold > n {
+            return Err(CeremonyError::InvalidThreshold);
+        }
+        let public = DealingCommitment {
+            commitment: polynomial.commitment(),
+            roster,
+            nested_points,
+        };
+        Ok(CommittedDealing {
+            polynomial,
+            digest: public.digest(),
+            public,
+            acknowledged: alloc::vec![false; n],
+        })
+    }
+
+    /// Returns the commitment to publish to the shareholders.
+    pub fn public(&self) -> &DealingCommitment {
+        &self.public
+    }
+
+    /// Record a shareholder's acknowledgement of the commitment.
+    pub fn acknowledge(&mut self, ack: &Acknowledgement) -> Result<(), CeremonyError> {
+        let i = self
+            .public
+            .roster
+            .active()
+            .position(|entry| entry.identity == ack.identity)
+            .ok_or_else(|| CeremonyError::UnknownIdentity(ack.identity.clone()))?;
+        if ack.digest != self.digest {
+            return Err(CeremonyError::WrongDigest(ack.identity.clone()));
+        }
+        self.acknowledged[i] = true;
+        Ok(())
+    }
+
+    /// Returns the identities which have not yet acknowledged the commitment.
+    pub fn pending(&self) -> Vec<&str> {
+        self.public
+            .roster
+            .active()
+            .zip(&self.acknowledged)
+            .filter(|(_, &acked)| !acked)
+            .map(|(entry, _)| entry.identity.as_str())
+            .collect()
+    }
+
+    /// Reveal the shares and nested pre-shares, once every active roster entry has
+    /// acknowledged the commitment.
+    pub fn reveal(&self) -> Result<RevealedDealing, CeremonyError> {
+        let pending = self.pending();
+        if !pending.is_empty() {
+            return Err(CeremonyError::MissingAcknowledgements(
+                pending.into_iter().map(String::from).collect(),
+            ));
+        }
+        let shares = self
+            .public
+            .roster
+            .active()
+            .map(|entry| {
+                (
+                    entry.identity.clone(),
+                    self.polynomial.issue_share(entry.input),
+                )
+            })
+            .collect();
+        let nested = self
+            .public
+            .nested_points
+            .iter()
+            .map(|&q| NestedPreshares::new(&self.polynomial, q))
+            .collect();
+        Ok(RevealedDealing { shares, nested })
+    }
+}
+
+/// The output of the reveal phase of a [`CommittedDealing`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevealedDealing {
+    /// Each active roster identity with its share, in roster order.
+    pub shares: Vec<(String, SecretShare)>,
+
+    /// The pre-shares for each committed nested point, in order.
+    pub nested: Vec<NestedPreshares>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash_to_point_tagged;
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_commit_reveal_dealing() {
+        let mut roster = Roster::new();
+        for name in ["alice", "bob", "carol"] {
+            roster.register(name).unwrap();
+        }
+        let q = hash_to_point_tagged(b"test", b"nested");
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(77), MaybeScalar::from(8)]);
+
+        let mut dealing = CommittedDealing::new(f.clone(), roster.clone(), vec![q]).unwrap();
+        let public = dealing.public().clone();
+        assert_eq!(public.commitment, f.commitment());
+
+        dealing.acknowledge(&public.acknowledge("alice")).unwrap();
+        assert_eq!(
+            dealing.reveal(),
+            Err(CeremonyError::MissingAcknowledgements(vec![
+                "bob".to_string(),
+                "carol".to_string()
+            ]))
+        );
+
+        // An acknowledgement of any other commitment is rejected.
+        let mut other = public.clone();
+        other
+            .nested_points
+            .push(hash_to_point_tagged(b"test", b"other"));
+        assert_eq!(
+            dealing.acknowledge(&other.acknowledge("bob")),
+            Err(CeremonyError::WrongDigest("bob".to_string()))
+        );
+        assert_eq!(
+            dealing.acknowledge(&public.acknowledge("mallory")),
+            Err(CeremonyError::UnknownIdentity("mallory".to_string()))
+        );
+
+        dealing.acknowledge(&public.acknowledge("bob")).unwrap();
+        dealing.acknowledge(&public.acknowledge("carol")).unwrap();
+        assert!(dealing.pending().is_empty());
+
+        let revealed = dealing.reveal().unwrap();
+        assert_eq!(revealed.shares.len(), 3);
+        for (identity, share) in &revealed.shares {
+            assert!(public.verify_reveal(identity, share));
+        }
+        assert!(!public.verify_reveal("alice", &revealed.shares[1].1));
+        assert_eq!(revealed.nested, vec![NestedPreshares::new(&f, q)]);
+
+        assert_eq!(
+            CommittedDealing::new(f, Roster::new(), Vec::new()).err(),
+            Some(CeremonyError::InvalidThreshold)
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use crate::SecretShare;
+use subtle::{Choice, ConstantTimeEq};
+
+/// Compares the share inputs and outputs without branching on their values.
+impl ConstantTimeEq for SecretShare {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.input.ct_eq(&other.input) & self.output.ct_eq(&other.output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+    use secp::MaybeScalar;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_ct_eq() {
+        let share = SecretShare::new(MaybeScalar::from(3), MaybeScalar::from(0xC0FFEE));
+        assert!(bool::from(share.ct_eq(&share)));
+        assert!(!bool::from(share.ct_eq(&SecretShare::new(
+            MaybeScalar::from(3),
+            MaybeScalar::from(0xC0FFEF),
+        ))));
+
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(6)]);
+        let g = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(7)]);
+        assert!(bool::from(f.ct_eq(&f.clone())));
+        assert!(!bool::from(f.ct_eq(&g)));
+        assert!(!bool::from(f.ct_eq(&SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(5)
+        ]))));
+
+        let z = secp::Point::generator() * f;
+        let c = z.derive_secret(MaybeScalar::from(9));
+        assert!(bool::from(
+            z.derived_secret_matches(MaybeScalar::from(9), &c)
+        ));
+        assert!(!bool::from(
+            z.derived_secret_matches(MaybeScalar::from(8), &c)
+        ));
+    }
+}
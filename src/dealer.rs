@@ -0,0 +1,306 @@
+use crate::{
+    parallel::par_map, tagged_hash, Evaluation, PointShare, PointSharingPolynomial, SecretShare,
+    SecretSharingPolynomial, Shareholder, VssCommitment,
+};
+use alloc::vec::Vec;
+use secp::{MaybeScalar, Point};
+
+/// Errors which can occur when a [`Dealer`] issues shares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DealerError {
+    /// The threshold is zero.
+    ZeroThreshold,
+
+    /// The threshold is greater than the number of shares to issue.
+    ThresholdExceedsShares,
+
+    /// The number of higher-order coefficients is not `threshold - 1`.
+    WrongCoefficientCount,
+
+    /// No secret or no higher-order coefficients were given, and none can be sampled.
+    MissingCoefficients,
+}
+
+impl core::fmt::Display for DealerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DealerError::ZeroThreshold => write!(f, "threshold must be at least 1"),
+            DealerError::ThresholdExceedsShares => {
+                write!(f, "threshold exceeds the number of shares")
+            }
+            DealerError::WrongCoefficientCount => {
+                write!(f, "wrong number of higher-order coefficients for threshold")
+            }
+            DealerError::MissingCoefficients => write!(f, "missing secret or coefficients"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DealerError {}
+
+/// A builder which deals a qudoku group in one flow: it samples the secret-sharing
+/// polynomial `f(x)`, commits to it, issues a share to each shareholder, and
+/// publishes pre-shares of `Q * f(x)` for every nested point `Q`.
+///
+/// ```
+/// # #[cfg(feature = "rand")]
+/// # {
+/// use qudoku::{hash_to_point_tagged, Dealer};
+/// use secp::MaybeScalar;
+///
+/// let q = hash_to_point_tagged(b"my-app", b"my nested secret");
+/// let dealing = Dealer::new(3)
+///     .with_secret(MaybeScalar::from(1234))
+///     .with_nested_point(q)
+///     .issue_random(5, &mut rand::rngs::OsRng)
+///     .unwrap();
+///
+/// assert_eq!(dealing.shares.len(), 5);
+/// assert!(dealing.shares.iter().all(|s| dealing.commitment.verify_share(s)));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dealer {
+    threshold: usize,
+    secret: Option<MaybeScalar>,
+    coefficients: Option<Vec<MaybeScalar>>,
+    nested_points: Vec<Point>,
+}
+
+/// The pre-shares of the point-sharing polynomial `Z(x) = Q * f(x)` for one nested
+/// point `Q`. Anyone holding these needs only one more point share to interpolate
+/// `Z(x)` and derive the nested secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NestedPreshares {
+    pub q: Point,
+    pub preshares: Vec<PointShare>,
+}
+
+impl NestedPreshares {
+    /// Compute the `t - 1` pre-shares of `Z(x) = Q * f(x)` for the polynomial `f(x)`
+    /// with `t` coefficients.
+    ///
+    /// When a nested secret is rotated to a new point `Q'`, the dealer only needs to
+    /// publish the pre-shares for `Q'`. The shareholders' secret shares are unchanged.
+    pub fn new(polynomial: &SecretSharingPolynomial, q: Point) -> Self {
+        let z: PointSharingPolynomial = q * polynomial;
+        let preshares = (0..polynomial.coefficients.len().saturating_sub(1))
+            .map(|i| {
+                let x = preshare_input(&q, i);
+                Evaluation::new(x, z.issue_share(x).output)
+            })
+            .collect();
+        NestedPreshares { q, preshares }
+    }
+}
+
+/// The output of a [`Dealer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dealing {
+    /// The secret shares, issued at inputs `1..=n` in order.
+    pub shares: Vec<SecretShare>,
+
+    /// The Feldman VSS commitment to `f(x)`, which is safe to publish.
+    pub commitment: VssCommitment,
+
+    /// The pre-shares for each nested point, in the order they were added.
+    pub nested: Vec<NestedPreshares>,
+}
+
+impl Dealing {
+    /// Returns the [`Shareholder`] view of the `i`-th share, bundled with the
+    /// commitment and nested `Q` points. Panics if `i` is out of range.
+    pub fn shareholder(&self, i: usize) -> Shareholder {
+        Shareholder::new(
+            self.shares[i],
+            self.commitment.clone(),
+            self.nested.iter().map(|nested| nested.q).collect(),
+        )
+    }
+}
+
+/// Returns the input of the `i`-th pre-share for the nested point `Q`. Inputs are
+/// hashed so they do not collide with the small indices given to shareholders.
+fn preshare_input(q: &Point, i: usize) -> MaybeScalar {
+    let mut preimage = [0u8; 33 + 8];
+    preimage[..33].copy_from_slice(&q.serialize());
+    preimage[33..].copy_from_slice(&(i as u64).to_be_bytes());
+    MaybeScalar::reduce_from(&tagged_hash(b"qudoku/dealer/preshare", &preimage))
+}
+
+impl Dealer {
+    /// Start dealing a group in which `threshold` shares are needed to recover the secret.
+    pub fn new(threshold: usize) -> Self {
+        Dealer {
+            threshold,
+            secret: None,
+            coefficients: None,
+            nested_points: Vec::new(),
+        }
+    }
+
+    /// Set the primary secret `f(0)`. If not set, a random secret is sampled.
+    pub fn with_secret(mut self, secret: MaybeScalar) -> Self {
+        self.secret = Some(secret);
+        self
+    }
+
+    /// Set the `threshold - 1` higher-order coefficients of `f(x)`, in ascending order of
+    /// degree. If not set, random coefficients are sampled. Coefficients must be uniformly
+    /// random and secret, so this is mostly useful for deterministic tests.
+    pub fn with_coefficients(mut self, coefficients: Vec<MaybeScalar>) -> Self {
+        self.coefficients = Some(coefficients);
+        self
+    }
+
+    /// Add a nested point `Q`, for which pre-shares of `Q * f(x)` will be issued.
+    pub fn with_nested_point(mut self, q: Point) -> Self {
+        self.nested_points.push(q);
+        self
+    }
+
+    /// Issue `n` shares, using the secret and coefficients given to the builder.
+    ///
+    /// Returns [`DealerError::MissingCoefficients`] if either was not given; use
+    /// [`issue_random`][Self::issue_random] to sample them instead.
+    pub fn issue(mut self, n: usize) -> Result<Dealing, DealerError> {
+        self.check(n)?;
+        let secret = self.secret.ok_or(DealerError::MissingCoefficients)?;
+        let higher = self
+            .coefficients
+            .take()
+            .ok_or(DealerError::MissingCoefficients)?;
+        self.deal(secret, higher, n)
+    }
+
+    /// Issue `n` shares, sampling the secret and higher-order coefficients from `rng`
+    /// if they were not given to the builder.
+    #[cfg(feature = "rand")]
+    pub fn issue_random<R: rand::RngCore + rand::CryptoRng>(
+        mut self,
+        n: usize,
+        rng: &mut R,
+    ) -> Result<Dealing, DealerError> {
+        self.check(n)?;
+        let secret = self
+            .secret
+            .unwrap_or_else(|| secp::Scalar::random(rng).into());
+        let higher = match self.coefficients.take() {
+            Some(higher) => higher,
+            None => crate::random_coefficients(rng, self.threshold - 1),
+        };
+        self.deal(secret, higher, n)
+    }
+
+    fn check(&self, n: usize) -> Result<(), DealerError> {
+        if self.threshold == 0 {
+            return Err(DealerError::ZeroThreshold);
+        }
+        if self.threshold > n {
+            return Err(DealerError::ThresholdExceedsShares);
+        }
+        Ok(())
+    }
+
+    fn deal(
+        self,
+        secret: MaybeScalar,
+        higher: Vec<MaybeScalar>,
+        n: usize,
+    ) -> Result<Dealing, DealerError> {
+        if higher.len() + 1 != self.threshold {
+            return Err(DealerError::WrongCoefficientCount);
+        }
+
+        let mut coefficients = Vec::with_capacity(self.threshold);
+        coefficients.push(secret);
+        coefficients.extend(higher);
+        let polynomial = SecretSharingPolynomial::new(coefficients);
+
+        let xs: Vec<MaybeScalar> = (1..=n).map(|i| MaybeScalar::from(i as u128)).collect();
+        let shares = par_map(&xs, |&x| polynomial.issue_share(x));
+
+        let nested = self
+            .nested_points
+            .iter()
+            .map(|&q| NestedPreshares::new(&polynomial, q))
+            .collect();
+
+        Ok(Dealing {
+            shares,
+            commitment: polynomial.commitment(),
+            nested,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hash_to_point_tagged, InterpolatedPointPolynomial};
+
+    #[test]
+    fn test_dealer() {
+        let q = hash_to_point_tagged(b"qudoku/test", b"nested");
+        let secret = MaybeScalar::from(1234);
+        let higher = vec![MaybeScalar::from(5), MaybeScalar::from(6)];
+
+        let dealing = Dealer::new(3)
+            .with_secret(secret)
+            .with_coefficients(higher.clone())
+            .with_nested_point(q)
+            .issue(4)
+            .unwrap();
+
+        assert_eq!(dealing.shares.len(), 4);
+        assert_eq!(dealing.shares[0].input, MaybeScalar::from(1));
+        assert!(dealing
+            .shares
+            .iter()
+            .all(|s| dealing.commitment.verify_share(s)));
+
+        // A single shareholder completes the nested polynomial with the pre-shares.
+        let mut z_shares = dealing.nested[0].preshares.clone();
+        assert_eq!(z_shares.len(), 2);
+        z_shares.push(dealing.shares[3] * q);
+        let f = SecretSharingPolynomial::new(vec![secret, higher[0], higher[1]]);
+        assert_eq!(
+            InterpolatedPointPolynomial::new(z_shares)
+                .derive_secret_tagged(b"qudoku/test", MaybeScalar::Zero),
+            (q * &f).derive_secret_tagged(b"qudoku/test", MaybeScalar::Zero)
+        );
+
+        assert_eq!(NestedPreshares::new(&f, q), dealing.nested[0]);
+
+        // Rotating to a new point only needs new pre-shares, not new shares.
+        let rotated_q = hash_to_point_tagged(b"qudoku/test", b"rotated");
+        let mut z_shares = NestedPreshares::new(&f, rotated_q).preshares;
+        z_shares.push(dealing.shares[0] * rotated_q);
+        assert_eq!(
+            InterpolatedPointPolynomial::new(z_shares)
+                .derive_secret_tagged(b"qudoku/test", MaybeScalar::Zero),
+            (rotated_q * &f).derive_secret_tagged(b"qudoku/test", MaybeScalar::Zero)
+        );
+
+        assert!(dealing.shareholder(2).verify());
+        assert_eq!(dealing.shareholder(2).q_points(), &[q]);
+
+        assert_eq!(Dealer::new(0).issue(3), Err(DealerError::ZeroThreshold));
+        assert_eq!(
+            Dealer::new(4).issue(3),
+            Err(DealerError::ThresholdExceedsShares)
+        );
+        assert_eq!(
+            Dealer::new(2).with_secret(secret).issue(3),
+            Err(DealerError::MissingCoefficients)
+        );
+        assert_eq!(
+            Dealer::new(2)
+                .with_secret(secret)
+                .with_coefficients(higher)
+                .issue(3),
+            Err(DealerError::WrongCoefficientCount)
+        );
+    }
+}
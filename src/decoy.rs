@@ -0,0 +1,121 @@
+use crate::{Polynomial, SecretShare, SecretSharingPolynomial};
+use secp::MaybeScalar;
+
+/// A pair of shares issued to a single shareholder at the same input `x`: one
+/// from the real secret-sharing polynomial, and one from a decoy polynomial.
+///
+/// Both shares are plain [`SecretShare`]s with the same input, so they are
+/// indistinguishable by format. A shareholder under duress can hand over the
+/// `decoy` share, which reconstructs (together with other decoy shares) to a
+/// plausible but harmless decoy secret.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecoyShares {
+    /// The share of the real secret.
+    pub real: SecretShare,
+
+    /// The share of the decoy secret.
+    pub decoy: SecretShare,
+}
+
+/// A real secret-sharing polynomial dealt in parallel with a decoy polynomial
+/// of the same degree.
+///
+/// The decoy polynomial must be sampled independently of the real one. If the
+/// decoy coefficients were derived from the real coefficients by any public
+/// rule, a coercer holding decoy shares could recover the real secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecoyDealing {
+    pub real: SecretSharingPolynomial,
+    pub decoy: SecretSharingPolynomial,
+}
+
+impl DecoyDealing {
+    /// Construct a decoy dealing from a real polynomial and an independently
+    /// generated decoy polynomial.
+    ///
+    /// Panics if the two polynomials do not have the same degree, since decoy
+    /// shares with a different threshold would be distinguishable in practice.
+    pub fn new(real: SecretSharingPolynomial, decoy: SecretSharingPolynomial) -> Self {
+        assert_eq!(
+            real.degree(),
+            decoy.degree(),
+            "decoy polynomial must have the same degree as the real polynomial"
+        );
+        DecoyDealing { real, decoy }
+    }
+
+    /// Sample a random decoy polynomial with the given decoy secret as its constant
+    /// term, matching the degree of the `real` polynomial.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(
+        real: SecretSharingPolynomial,
+        decoy_secret: MaybeScalar,
+        rng: &mut R,
+    ) -> Self {
+        let mut coefficients = crate::random_coefficients(rng, real.degree() + 1);
+        coefficients[0] = decoy_secret;
+        DecoyDealing::new(real, SecretSharingPolynomial::new(coefficients))
+    }
+
+    /// Returns the decoy secret, which is the constant term of the decoy polynomial.
+    pub fn decoy_secret(&self) -> MaybeScalar {
+        self.decoy.evaluate(MaybeScalar::Zero)
+    }
+
+    /// Issue a real share and a decoy share at the same input `x`.
+    pub fn issue_shares(&self, x: MaybeScalar) -> DecoyShares {
+        DecoyShares {
+            real: self.real.issue_share(x),
+            decoy: self.decoy.issue_share(x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InterpolatedSecretPolynomial;
+
+    #[test]
+    fn test_decoy_dealing() {
+        let real = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(1234),
+            MaybeScalar::from(5),
+            MaybeScalar::from(99),
+        ]);
+        let decoy = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(42),
+            MaybeScalar::from(8008),
+            MaybeScalar::from(7),
+        ]);
+        let dealing = DecoyDealing::new(real, decoy);
+        assert_eq!(dealing.decoy_secret(), MaybeScalar::from(42));
+
+        let bundles: Vec<DecoyShares> = (1..=3)
+            .map(|i| dealing.issue_shares(MaybeScalar::from(i)))
+            .collect();
+
+        for bundle in bundles.iter() {
+            assert_eq!(bundle.real.input, bundle.decoy.input);
+        }
+
+        let real_secret =
+            InterpolatedSecretPolynomial::new(bundles.iter().map(|b| b.real).collect())
+                .evaluate(MaybeScalar::Zero);
+        let decoy_secret =
+            InterpolatedSecretPolynomial::new(bundles.iter().map(|b| b.decoy).collect())
+                .evaluate(MaybeScalar::Zero);
+
+        assert_eq!(real_secret, MaybeScalar::from(1234));
+        assert_eq!(decoy_secret, MaybeScalar::from(42));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decoy_dealing_degree_mismatch() {
+        DecoyDealing::new(
+            SecretSharingPolynomial::new(vec![MaybeScalar::from(1), MaybeScalar::from(2)]),
+            SecretSharingPolynomial::new(vec![MaybeScalar::from(1)]),
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! Share encodings which are indistinguishable from uniformly random bytes, so that
+//! holding a share cannot be proven from the encoded blob alone.
+//!
+//! A [`SecretShare`] is already encoded by [`SecretShare::to_bytes`] as two raw
+//! scalars with no magic bytes or structure. Scalars are uniform over `[0, n)`, and
+//! `n` is within `2^-128` of `2^256`, so the encoding is uniform as long as the share
+//! input is, such as an input derived by hashing the shareholder's identity. Small
+//! sequential inputs like `1, 2, 3` give themselves away.
+//!
+//! A compressed point is easily recognised: only about half of all 32-byte strings
+//! are valid x-coordinates. [`PointShare::to_uniform_bytes`] instead encodes the
+//! share output with an [ElligatorSwift](https://eprint.iacr.org/2022/759) encoding
+//! in the style of [BIP-324](https://github.com/bitcoin/bips/blob/master/bip-0324.mediawiki),
+//! as a pair of field elements `(u, t)` which are uniformly distributed. Every
+//! 64-byte string decodes to some point. Unlike BIP-324, which only encodes an
+//! x-coordinate, the parity of `t` carries the parity of the point's y-coordinate.
+
+use crate::{tagged_hash, Evaluation, InvalidShareBytes, PointShare};
+use k256::FieldElement;
+use secp::{errors::InfinityPointError, MaybeScalar, Point};
+
+/// A field element which is always fully normalized, so that comparisons, parity
+/// checks and encodings of the underlying [`FieldElement`] are well defined.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Fe(FieldElement);
+
+impl Fe {
+    const ZERO: Fe = Fe(FieldElement::ZERO);
+    const ONE: Fe = Fe(FieldElement::ONE);
+
+    fn from_u64(n: u64) -> Fe {
+        Fe(FieldElement::from_u64(n).normalize())
+    }
+
+    /// Parse 32 big-endian bytes, reducing modulo `p`.
+    fn from_bytes_reduced(bytes: &[u8; 32]) -> Fe {
+        let field_bytes = (*bytes).into();
+        match Option::<FieldElement>::from(FieldElement::from_bytes(&field_bytes)) {
+            Some(x) => Fe(x.normalize()),
+            // The value is in [p, 2^256), so subtracting p is the same as adding
+            // 2^256 - p = 2^32 + 977 and discarding the carry.
+            None => {
+                let mut reduced = *bytes;
+                let mut carry = 0x1000003D1u64;
+                for byte in reduced.iter_mut().rev() {
+                    let sum = *byte as u64 + (carry & 0xFF);
+                    *byte = sum as u8;
+                    carry = (carry >> 8) + (sum >> 8);
+                }
+                Fe(FieldElement::from_bytes(&reduced.into())
+                    .unwrap()
+                    .normalize())
+            }
+        }
+    }
+
+    fn to_bytes(self) -> [u8; 32] {
+        self.0.to_bytes().into()
+    }
+
+    fn is_zero(self) -> bool {
+        self.0.is_zero().into()
+    }
+
+    fn is_odd(self) -> bool {
+        self.0.is_odd().into()
+    }
+
+    fn square(self) -> Fe {
+        Fe(self.0.square().normalize())
+    }
+
+    fn invert(self) -> Option<Fe> {
+        Option::<FieldElement>::from(self.0.invert()).map(|x| Fe(x.normalize()))
+    }
+
+    fn sqrt(self) -> Option<Fe> {
+        Option::<FieldElement>::from(self.0.sqrt()).map(|x| Fe(x.normalize()))
+    }
+
+    /// Returns `x^3 + 7`, the right-hand side of the curve equation.
+    fn curve_rhs(self) -> Fe {
+        self.square() * self + Fe::from_u64(7)
+    }
+
+    fn is_valid_x(self) -> bool {
+        self.curve_rhs().sqrt().is_some()
+    }
+}
+
+impl core::ops::Add for Fe {
+    type Output = Fe;
+    fn add(self, rhs: Fe) -> Fe {
+        Fe((self.0 + rhs.0).normalize())
+    }
+}
+
+impl core::ops::Sub for Fe {
+    type Output = Fe;
+    fn sub(self, rhs: Fe) -> Fe {
+        self + -rhs
+    }
+}
+
+impl core::ops::Mul for Fe {
+    type Output = Fe;
+    fn mul(self, rhs: Fe) -> Fe {
+        Fe((self.0 * rhs.0).normalize())
+    }
+}
+
+impl core::ops::Neg for Fe {
+    type Output = Fe;
+    fn neg(self) -> Fe {
+        Fe(self.0.negate(1).normalize())
+    }
+}
+
+/// Returns a fixed square root of `-3`.
+fn sqrt_minus_3() -> Fe {
+    (-Fe::from_u64(3)).sqrt().unwrap()
+}
+
+/// The SwiftEC map from a pair of field elements to a valid x-coordinate.
+fn xswiftec(u: Fe, t: Fe) -> Fe {
+    let u = if u.is_zero() { Fe::ONE } else { u };
+    let mut t = if t.is_zero() { Fe::ONE } else { t };
+    let seven = Fe::from_u64(7);
+    if (u.square() * u + t.square() + seven).is_zero() {
+        t = t + t;
+    }
+
+    let x = (u.square() * u + seven - t.square()) * (t + t).invert().unwrap();
+    let y = (x + t) * (sqrt_minus_3() * u).invert().unwrap();
+    let half = Fe::from_u64(2).invert().unwrap();
+    let x_over_y = x * y.invert().unwrap();
+
+    // At least one of these is always a valid x-coordinate.
+    [
+        u + Fe::from_u64(4) * y.square(),
+        (-x_over_y - u) * half,
+        (x_over_y - u) * half,
+    ]
+    .into_iter()
+    .find(|&x| x.is_valid_x())
+    .unwrap()
+}
+
+/// Returns every `t` such that `xswiftec(u, t) == x`, each exactly once. There are
+/// at most eight.
+fn xswiftec_preimages(x: Fe, u: Fe) -> ([Fe; 8], usize) {
+    let mut found = [Fe::ZERO; 8];
+    let mut count = 0;
+    if u.is_zero() {
+        return (found, count);
+    }
+
+    let seven = Fe::from_u64(7);
+    let u3_plus_7 = u.square() * u + seven;
+    let sqrt_m3 = sqrt_minus_3();
+    let half = Fe::from_u64(2).invert().unwrap();
+
+    // Candidate (s, v) pairs, one for each of the ways `x` can come out of the map.
+    let mut pairs: [Option<(Fe, Fe)>; 3] = [None; 3];
+    if let Some(inv) = (u.square() + u * x + x.square()).invert() {
+        pairs[0] = Some((-u3_plus_7 * inv, x));
+    }
+    let s = x - u;
+    if let (Some(s_inv), Some(r)) = (
+        s.invert(),
+        (-s * (Fe::from_u64(4) * u3_plus_7 + Fe::from_u64(3) * s * u.square())).sqrt(),
+    ) {
+        pairs[1] = Some((s, (r * s_inv - u) * half));
+        pairs[2] = Some((s, (-r * s_inv - u) * half));
+    }
+
+    for (s, v) in pairs.into_iter().flatten() {
+        let w = match s.sqrt() {
+            Some(w) => w,
+            None => continue,
+        };
+        for k in [(Fe::ONE - sqrt_m3) * half, (Fe::ONE + sqrt_m3) * half] {
+            for t in [w * (u * k + v), -(w * (u * k + v))] {
+                if count < found.len()
+                    && !t.is_zero()
+                    && !found[..count].contains(&t)
+                    && xswiftec(u, t) == x
+                {
+                    found[count] = t;
+                    count += 1;
+                }
+            }
+        }
+    }
+    (found, count)
+}
+
+/// Encode a point as 64 bytes `u || t` which are indistinguishable from uniformly
+/// random bytes.
+///
+/// The encoding is randomized, and deterministic given `entropy`, which must be
+/// uniformly random and secret: anyone who knows it can recognise the encoding.
+pub fn ellswift_encode(point: Point, entropy: &[u8; 32]) -> [u8; 64] {
+    let compressed = point.serialize();
+    let x = Fe::from_bytes_reduced(compressed[1..].try_into().unwrap());
+    let odd = compressed[0] == 0x03;
+
+    let mut preimage = [0u8; 32 + 33 + 4];
+    preimage[..32].copy_from_slice(entropy);
+    preimage[32..65].copy_from_slice(&compressed);
+    for counter in 0u32.. {
+        preimage[65..].copy_from_slice(&counter.to_be_bytes());
+        let u_bytes = tagged_hash(b"qudoku/ellswift/u", &preimage);
+        let u = Fe::from_bytes_reduced(&u_bytes);
+
+        // Of the up to eight preimages, at most four have `t` of the right parity.
+        // Picking one of four slots at random, and retrying with a new `u` if the slot
+        // is empty, selects every encoding of the point with equal probability.
+        let (candidates, count) = xswiftec_preimages(x, u);
+        let slot = (tagged_hash(b"qudoku/ellswift/case", &preimage)[0] % 4) as usize;
+        if let Some(t) = candidates[..count]
+            .iter()
+            .filter(|t| t.is_odd() == odd)
+            .nth(slot)
+        {
+            let mut out = [0u8; 64];
+            out[..32].copy_from_slice(&u.to_bytes());
+            out[32..].copy_from_slice(&t.to_bytes());
+            return out;
+        }
+    }
+    unreachable!("a point has encodings under almost every u")
+}
+
+/// Decode any 64 bytes `u || t` into a point. This is the inverse of
+/// [`ellswift_encode`].
+pub fn ellswift_decode(bytes: &[u8; 64]) -> Point {
+    let u = Fe::from_bytes_reduced(bytes[..32].try_into().unwrap());
+    let t = Fe::from_bytes_reduced(bytes[32..].try_into().unwrap());
+    let mut compressed = [0u8; 33];
+    compressed[0] = if t.is_odd() { 0x03 } else { 0x02 };
+    compressed[1..].copy_from_slice(&xswiftec(u, t).to_bytes());
+    Point::from_slice(&compressed).unwrap()
+}
+
+impl PointShare {
+    /// Encode the point share as 96 bytes `x || u || t`, where `x` is the share input
+    /// as a big-endian scalar and `u || t` is the [`ellswift_encode`] encoding of the
+    /// share output. As with secret shares, the encoding is only uniform if the share
+    /// input is.
+    ///
+    /// Returns an error if the share output is the point at infinity.
+    pub fn to_uniform_bytes(&self, entropy: &[u8; 32]) -> Result<[u8; 96], InfinityPointError> {
+        let output = self.output.not_inf()?;
+        let mut out = [0u8; 96];
+        out[..32].copy_from_slice(&self.input.serialize());
+        out[32..].copy_from_slice(&ellswift_encode(output, entropy));
+        Ok(out)
+    }
+
+    /// Encode the point share as in [`to_uniform_bytes`][Self::to_uniform_bytes],
+    /// with fresh random entropy.
+    #[cfg(feature = "rand")]
+    pub fn to_uniform_bytes_random<R: rand::RngCore + rand::CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<[u8; 96], InfinityPointError> {
+        let mut entropy = [0u8; 32];
+        rng.fill_bytes(&mut entropy);
+        self.to_uniform_bytes(&entropy)
+    }
+
+    /// Decode a point share from the encoding produced by
+    /// [`to_uniform_bytes`][Self::to_uniform_bytes]. Any 64 bytes decode to a valid
+    /// share output, so only the share input can be invalid.
+    pub fn from_uniform_bytes(bytes: &[u8; 96]) -> Result<PointShare, InvalidShareBytes> {
+        let input = match MaybeScalar::from_slice(&bytes[..32]) {
+            Ok(MaybeScalar::Valid(x)) => MaybeScalar::Valid(x),
+            _ => return Err(InvalidShareBytes::Input),
+        };
+        let output = ellswift_decode(bytes[32..].try_into().unwrap());
+        Ok(Evaluation::new(input, output.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hash_to_point_tagged, sha256};
+
+    #[test]
+    fn test_xswiftec_preimages() {
+        // Every (u, t) pair must be found again among the preimages of its image.
+        for i in 0..64u32 {
+            let u = Fe::from_bytes_reduced(&sha256(&[b'u', i as u8]));
+            let t = Fe::from_bytes_reduced(&sha256(&[b't', i as u8]));
+            let x = xswiftec(u, t);
+            let (candidates, count) = xswiftec_preimages(x, u);
+            assert!(candidates[..count].contains(&t));
+            assert!(candidates[..count].contains(&-t));
+        }
+    }
+
+    #[test]
+    fn test_ellswift_roundtrip() {
+        for i in 0..32u8 {
+            let point = hash_to_point_tagged(b"test", &[i]);
+            let encoded = ellswift_encode(point, &[i; 32]);
+            assert_eq!(ellswift_decode(&encoded), point);
+            assert_ne!(ellswift_encode(point, &[i ^ 0xFF; 32]), encoded);
+            assert_eq!(ellswift_decode(&ellswift_encode(-point, &[i; 32])), -point);
+        }
+
+        // Every string decodes, including ones with u, t >= p.
+        ellswift_decode(&[0xFF; 64]);
+        ellswift_decode(&[0; 64]);
+    }
+
+    #[test]
+    fn test_ellswift_bits_are_balanced() {
+        // A compressed x-coordinate is only valid half the time; an encoded point
+        // carries no such structure, so every bit is set about half the time. The
+        // points are multiples of G so that their y-coordinates have random parity.
+        let mut ones = [0u32; 512];
+        let n = 256u32;
+        for i in 0..n {
+            let point = secp::Scalar::reduce_from(&sha256(&i.to_le_bytes())) * secp::G;
+            let encoded = ellswift_encode(point, &sha256(&i.to_be_bytes()));
+            for (bit, count) in ones.iter_mut().enumerate() {
+                *count += ((encoded[bit / 8] >> (bit % 8)) & 1) as u32;
+            }
+        }
+        assert!(ones.iter().all(|&c| (64..=192).contains(&c)));
+    }
+
+    #[test]
+    fn test_uniform_point_share_bytes() {
+        let q = hash_to_point_tagged(b"test", b"q");
+        let share = crate::SecretShare::new(MaybeScalar::from(0xABC), MaybeScalar::from(99));
+        let point_share = share.to_point_share(q);
+
+        let bytes = point_share.to_uniform_bytes(&[7; 32]).unwrap();
+        assert_eq!(PointShare::from_uniform_bytes(&bytes), Ok(point_share));
+
+        let mut zero_input = bytes;
+        zero_input[..32].fill(0);
+        assert_eq!(
+            PointShare::from_uniform_bytes(&zero_input),
+            Err(InvalidShareBytes::Input)
+        );
+
+        let infinity = Evaluation::new(MaybeScalar::from(1), secp::MaybePoint::Infinity);
+        assert_eq!(infinity.to_uniform_bytes(&[7; 32]), Err(InfinityPointError));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{hash_to_point_tagged, tagged_hash};
+use alloc::vec::Vec;
+use secp::Point;
+
+/// Errors which can occur when combining the contributions to a distributed `Q` point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ContributionError {
+    /// The number of reveals does not match the number of commitments.
+    CountMismatch,
+
+    /// The reveal at `index` does not match its commitment.
+    InvalidReveal { index: usize },
+}
+
+impl core::fmt::Display for ContributionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ContributionError::CountMismatch => {
+                write!(f, "number of reveals does not match number of commitments")
+            }
+            ContributionError::InvalidReveal { index } => {
+                write!(f, "reveal {} does not match its commitment", index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ContributionError {}
+
+/// One participant's contribution of randomness towards a nested `Q` point which no
+/// single party controls, so that nobody, including the dealer, can predict or bias
+/// the nested secret before the group agrees on `Q`.
+///
+/// The protocol runs in two rounds:
+///
+/// 1. Every participant samples a random nonce and broadcasts its
+///    [`commitment`][Self::commitment].
+/// 2. Once all commitments are received, every participant broadcasts its
+///    [`reveal`][Self::reveal], and everyone computes `Q` with [`combine_q_contributions`].
+///
+/// `Q` is a hash of all the nonces, so it is unpredictable as long as one participant
+/// is honest, and its discrete log is unknown even if all of them collude. Simply adding
+/// up contributed points `Q_i` would instead let the last participant choose `Q`.
+/// Participants must not reveal before all commitments are in, or a later participant
+/// could grind their nonce to bias `Q`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QContribution {
+    nonce: [u8; 32],
+}
+
+impl QContribution {
+    /// Construct a contribution from a nonce, which must be uniformly random.
+    pub fn new(nonce: [u8; 32]) -> Self {
+        QContribution { nonce }
+    }
+
+    /// Sample a random contribution.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        let mut nonce = [0u8; 32];
+        rng.fill_bytes(&mut nonce);
+        QContribution::new(nonce)
+    }
+
+    /// Returns the commitment to the nonce, to broadcast in the first round.
+    pub fn commitment(&self) -> [u8; 32] {
+        contribution_commitment(&self.nonce)
+    }
+
+    /// Returns the nonce, to broadcast in the second round.
+    pub fn reveal(&self) -> [u8; 32] {
+        self.nonce
+    }
+}
+
+fn contribution_commitment(nonce: &[u8; 32]) -> [u8; 32] {
+    tagged_hash(b"qudoku/distributed_q/commitment", nonce)
+}
+
+/// Check every participant's reveal against their commitment, and combine the reveals
+/// into the nested `Q` point for the group with the given public key and label.
+///
+/// The commitments and reveals must be given in the same, agreed-upon order of
+/// participants, so that everyone computes the same `Q`.
+pub fn combine_q_contributions(
+    group_pubkey: Point,
+    label: &[u8],
+    commitments: &[[u8; 32]],
+    reveals: &[[u8; 32]],
+) -> Result<Point, ContributionError> {
+    if commitments.len() != reveals.len() {
+        return Err(ContributionError::CountMismatch);
+    }
+    if let Some(index) = commitments
+        .iter()
+        .zip(reveals)
+        .position(|(commitment, reveal)| contribution_commitment(reveal) != *commitment)
+    {
+        return Err(ContributionError::InvalidReveal { index });
+    }
+
+    let mut preimage = Vec::with_capacity(33 + 4 + 32 * reveals.len() + label.len());
+    preimage.extend_from_slice(&group_pubkey.serialize());
+    preimage.extend_from_slice(&(reveals.len() as u32).to_be_bytes());
+    for reveal in reveals {
+        preimage.extend_from_slice(reveal);
+    }
+    preimage.extend_from_slice(label);
+    Ok(hash_to_point_tagged(b"qudoku/distributed_q", &preimage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash_to_point_tagged;
+
+    #[test]
+    fn test_distributed_q() {
+        let pubkey = hash_to_point_tagged(b"qudoku/test", b"group");
+        let contributions: Vec<QContribution> =
+            (1..=3).map(|i| QContribution::new([i; 32])).collect();
+        let commitments: Vec<[u8; 32]> = contributions.iter().map(|c| c.commitment()).collect();
+        let reveals: Vec<[u8; 32]> = contributions.iter().map(|c| c.reveal()).collect();
+
+        let q = combine_q_contributions(pubkey, b"vault", &commitments, &reveals).unwrap();
+        assert_eq!(
+            combine_q_contributions(pubkey, b"vault", &commitments, &reveals),
+            Ok(q)
+        );
+        assert_ne!(
+            combine_q_contributions(pubkey, b"other", &commitments, &reveals),
+            Ok(q)
+        );
+
+        let mut swapped = reveals.clone();
+        swapped[1] = [9; 32];
+        assert_eq!(
+            combine_q_contributions(pubkey, b"vault", &commitments, &swapped),
+            Err(ContributionError::InvalidReveal { index: 1 })
+        );
+        assert_eq!(
+            combine_q_contributions(pubkey, b"vault", &commitments, &reveals[..2]),
+            Err(ContributionError::CountMismatch)
+        );
+    }
+}
@@ -0,0 +1,1023 @@
+//! A dealerless [distributed key generation](https://en.wikipedia.org/wiki/Distributed_key_generation)
+//! (DKG) protocol in the style of Pedersen and Gennaro et al.
+//!
+//! Every participant deals their own random secret-sharing polynomial, and the group's
+//! polynomial is the sum of all of them. No single party ever learns the group secret.
+//!
+//! 1. Each participant constructs a [`Round1`], broadcasts its [`Round1Package`]
+//!    (a Feldman commitment plus a proof of knowledge of its constant term), and
+//!    privately sends each other participant the share [`Round1::share_for`] them.
+//! 2. After receiving every participant's package, each participant calls
+//!    [`Round1::into_round2`], which checks the proofs of knowledge.
+//! 3. After receiving a share from every participant, each participant calls
+//!    [`Round2::finalize`], which checks each share against its sender's commitment
+//!    and produces a [`Finalize`] holding the participant's joint [`SecretShare`] and
+//!    the group's commitment.
+//!
+//! Transport and encryption of messages are left to the caller. To authenticate
+//! messages, participants agree on a [`DkgSession`] registering each participant's
+//! public key, and exchange [`SignedRound1Package`]s and [`SignedShare`]s, which are
+//! signed over the session's transcript hash and rejected if their signature does not
+//! verify under the sender's registered key.
+//!
+//! If the DKG may be interrupted, a participant can save a [`Round1`] or [`Round2`]
+//! with [`checkpoint`][Round1::checkpoint] and later continue where it stopped with
+//! [`resume`][Round1::resume].
+
+use crate::{
+    check_distinct, hmac_sha256, sign_schnorr, tagged_hash, verify_schnorr, Error, Evaluation,
+    LimitExceeded, Limits, PointSharingPolynomial, SecretShare, SecretSharingPolynomial,
+    VssCommitment,
+};
+use alloc::{vec, vec::Vec};
+use secp::{errors::ZeroScalarError, MaybePoint, MaybeScalar, Point, Scalar, G};
+use subtle::ConstantTimeEq as _;
+
+/// Errors which can occur during the DKG. Each variant identifies the input of
+/// the participant at fault.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DkgError {
+    /// A participant's proof of knowledge of their constant term is invalid.
+    InvalidProof(MaybeScalar),
+
+    /// A participant's commitment has the wrong number of coefficients.
+    WrongThreshold(MaybeScalar),
+
+    /// A participant sent a share which does not match their commitment.
+    InvalidShare(MaybeScalar),
+
+    /// No package or share was received from a participant.
+    Missing(MaybeScalar),
+
+    /// A package or share was received from someone who is not a participant,
+    /// or more than once from the same participant.
+    Unexpected(MaybeScalar),
+
+    /// A message's signature does not verify under the sender's registered key for
+    /// this session, so it was forged, tampered with, or replayed from another
+    /// session, round or recipient.
+    Unauthenticated(MaybeScalar),
+}
+
+impl core::fmt::Display for DkgError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (what, x) = match self {
+            DkgError::InvalidProof(x) => ("invalid proof of knowledge", x),
+            DkgError::WrongThreshold(x) => ("commitment has wrong threshold", x),
+            DkgError::InvalidShare(x) => ("invalid share", x),
+            DkgError::Missing(x) => ("missing message", x),
+            DkgError::Unexpected(x) => ("unexpected message", x),
+            DkgError::Unauthenticated(x) => ("unauthenticated message", x),
+        };
+        write!(f, "{} from participant {:?}", what, x)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DkgError {}
+
+/// Errors which can occur when resuming a round of the DKG from a checkpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckpointError {
+    /// The authentication tag does not match, so the checkpoint was corrupted,
+    /// tampered with, or sealed with a different key.
+    InvalidTag,
+
+    /// The checkpoint is malformed, or is not a checkpoint of the expected round.
+    InvalidEncoding,
+
+    /// The checkpoint is longer than the default [`Limits`] allow.
+    LimitExceeded(LimitExceeded),
+}
+
+impl core::fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CheckpointError::InvalidTag => write!(f, "invalid checkpoint authentication tag"),
+            CheckpointError::InvalidEncoding => write!(f, "invalid checkpoint encoding"),
+            CheckpointError::LimitExceeded(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CheckpointError {}
+
+const ROUND1_CHECKPOINT_TAG: &[u8] = b"qudoku/dkg/checkpoint/round1";
+const ROUND2_CHECKPOINT_TAG: &[u8] = b"qudoku/dkg/checkpoint/round2";
+
+/// Append an HMAC-SHA256 tag over `domain || body`, keyed with `key`.
+fn seal_checkpoint(domain: &[u8], key: &[u8], mut body: Vec<u8>) -> Vec<u8> {
+    let mut message = domain.to_vec();
+    message.extend_from_slice(&body);
+    body.extend_from_slice(&hmac_sha256(key, &message));
+    body
+}
+
+/// Check the tag appended by [`seal_checkpoint`] in constant time, and return the body.
+fn open_checkpoint<'a>(
+    domain: &[u8],
+    key: &[u8],
+    checkpoint: &'a [u8],
+) -> Result<&'a [u8], CheckpointError> {
+    Limits::default()
+        .check_message_size(checkpoint.len())
+        .map_err(CheckpointError::LimitExceeded)?;
+    if checkpoint.len() < 32 {
+        return Err(CheckpointError::InvalidEncoding);
+    }
+    let (body, tag) = checkpoint.split_at(checkpoint.len() - 32);
+    let mut message = domain.to_vec();
+    message.extend_from_slice(body);
+    if !bool::from(hmac_sha256(key, &message)[..].ct_eq(tag)) {
+        return Err(CheckpointError::InvalidTag);
+    }
+    Ok(body)
+}
+
+fn take<'a>(bytes: &mut &'a [u8], n: usize) -> Result<&'a [u8], CheckpointError> {
+    if bytes.len() < n {
+        return Err(CheckpointError::InvalidEncoding);
+    }
+    let (head, tail) = bytes.split_at(n);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn take_count(bytes: &mut &[u8]) -> Result<usize, CheckpointError> {
+    Ok(u32::from_be_bytes(take(bytes, 4)?.try_into().unwrap()) as usize)
+}
+
+fn take_scalar(bytes: &mut &[u8]) -> Result<MaybeScalar, CheckpointError> {
+    MaybeScalar::from_slice(take(bytes, 32)?).map_err(|_| CheckpointError::InvalidEncoding)
+}
+
+fn take_point(bytes: &mut &[u8]) -> Result<MaybePoint, CheckpointError> {
+    MaybePoint::from_slice(take(bytes, 33)?).map_err(|_| CheckpointError::InvalidEncoding)
+}
+
+fn pok_challenge(sender: MaybeScalar, public: MaybePoint, nonce_point: MaybePoint) -> MaybeScalar {
+    let mut preimage = [0u8; 32 + 33 + 33];
+    preimage[..32].copy_from_slice(&sender.serialize());
+    preimage[32..65].copy_from_slice(&public.serialize());
+    preimage[65..].copy_from_slice(&nonce_point.serialize());
+    MaybeScalar::reduce_from(&tagged_hash(b"qudoku/dkg/pok", &preimage))
+}
+
+/// A Schnorr proof of knowledge of the discrete log of a participant's constant term
+/// commitment `a_0 * G`, bound to the participant's input to prevent replay.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProofOfKnowledge {
+    pub nonce_point: MaybePoint,
+    pub response: MaybeScalar,
+}
+
+/// The message each participant broadcasts to all others in the first round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Round1Package {
+    /// The input `x` of the sending participant.
+    pub sender: MaybeScalar,
+
+    /// The Feldman commitment to the sender's polynomial.
+    pub commitment: VssCommitment,
+
+    /// The proof of knowledge of the sender's constant term.
+    pub proof: ProofOfKnowledge,
+}
+
+impl Round1Package {
+    fn verify_proof(&self) -> bool {
+        let public = self.commitment.public_key();
+        let challenge = pok_challenge(self.sender, public, self.proof.nonce_point);
+        self.proof.response * G == self.proof.nonce_point + challenge * public
+    }
+}
+
+/// The first round of the DKG, holding the participant's own polynomial.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Round1 {
+    input: MaybeScalar,
+    participants: Vec<MaybeScalar>,
+    polynomial: SecretSharingPolynomial,
+}
+
+/// Omits the secret polynomial, showing only its threshold.
+impl core::fmt::Debug for Round1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Round1")
+            .field("input", &self.input)
+            .field("participants", &self.participants)
+            .field("threshold", &self.polynomial.coefficients.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Round1 {
+    /// Begin the DKG as the participant with the given non-zero `input`, dealing the
+    /// given polynomial. `participants` must list the inputs of every participant,
+    /// including this one. The group threshold is the number of coefficients in
+    /// `polynomial`, which every participant must agree on.
+    ///
+    /// The polynomial must be sampled randomly and kept secret.
+    ///
+    /// Returns [`Error::ZeroInput`] or [`Error::DuplicateInput`] if the participant
+    /// inputs are not distinct and non-zero, [`Error::UnknownInput`] if `input` is not
+    /// in `participants`, [`Error::Empty`] if the polynomial has no coefficients, or
+    /// [`Error::NotEnoughShares`] if the threshold is greater than the number of
+    /// participants.
+    pub fn new(
+        input: MaybeScalar,
+        participants: Vec<MaybeScalar>,
+        polynomial: SecretSharingPolynomial,
+    ) -> Result<Self, Error> {
+        if let Some(index) = participants.iter().position(|x| x.is_zero()) {
+            return Err(Error::ZeroInput { index });
+        }
+        check_distinct(&participants)?;
+        if !participants.contains(&input) {
+            return Err(Error::UnknownInput);
+        }
+        if polynomial.coefficients.is_empty() {
+            return Err(Error::Empty);
+        }
+        if polynomial.coefficients.len() > participants.len() {
+            return Err(Error::NotEnoughShares {
+                threshold: polynomial.coefficients.len(),
+                count: participants.len(),
+            });
+        }
+        Ok(Round1 {
+            input,
+            participants,
+            polynomial,
+        })
+    }
+
+    /// Begin the DKG with a randomly sampled polynomial of the given threshold.
+    ///
+    /// Returns an error in the same cases as [`new`][Self::new], including
+    /// [`Error::Empty`] if `threshold` is zero.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(
+        input: MaybeScalar,
+        participants: Vec<MaybeScalar>,
+        threshold: usize,
+        rng: &mut R,
+    ) -> Result<Self, Error> {
+        let polynomial = SecretSharingPolynomial::new(crate::random_coefficients(rng, threshold));
+        Round1::new(input, participants, polynomial)
+    }
+
+    /// Returns the package to broadcast to every other participant.
+    pub fn package(&self) -> Round1Package {
+        let secret = self.polynomial.coefficients[0];
+        let public = secret * G;
+
+        let mut nonce_preimage = [0u8; 64];
+        nonce_preimage[..32].copy_from_slice(&secret.serialize());
+        nonce_preimage[32..].copy_from_slice(&self.input.serialize());
+        let nonce = MaybeScalar::reduce_from(&tagged_hash(b"qudoku/dkg/nonce", &nonce_preimage));
+        let nonce_point = nonce * G;
+
+        let challenge = pok_challenge(self.input, public, nonce_point);
+        Round1Package {
+            sender: self.input,
+            commitment: self.polynomial.commitment(),
+            proof: ProofOfKnowledge {
+                nonce_point,
+                response: nonce + challenge * secret,
+            },
+        }
+    }
+
+    /// Returns the share to send privately to the participant with the given input.
+    pub fn share_for(&self, recipient: MaybeScalar) -> SecretShare {
+        self.polynomial.issue_share(recipient)
+    }
+
+    /// Save this round so that it can be continued later with [`resume`][Self::resume],
+    /// for instance after a restart while waiting for other participants' packages.
+    ///
+    /// The checkpoint is authenticated with HMAC-SHA256 under `key`, so any
+    /// modification is detected on resumption. It is **not encrypted**: it contains
+    /// this participant's secret polynomial, and must be stored as confidentially as
+    /// the participant's eventual share.
+    pub fn checkpoint(&self, key: &[u8]) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&self.input.serialize());
+        body.extend_from_slice(&(self.participants.len() as u32).to_be_bytes());
+        for x in self.participants.iter() {
+            body.extend_from_slice(&x.serialize());
+        }
+        body.extend_from_slice(&(self.polynomial.coefficients.len() as u32).to_be_bytes());
+        for c in self.polynomial.coefficients.iter() {
+            body.extend_from_slice(&c.serialize());
+        }
+        seal_checkpoint(ROUND1_CHECKPOINT_TAG, key, body)
+    }
+
+    /// Resume a round saved with [`checkpoint`][Self::checkpoint] under the same `key`.
+    ///
+    /// Returns [`CheckpointError::InvalidTag`] if the checkpoint was modified or sealed
+    /// with a different key, and [`CheckpointError::InvalidEncoding`] if it is not a
+    /// valid first-round checkpoint.
+    pub fn resume(checkpoint: &[u8], key: &[u8]) -> Result<Self, CheckpointError> {
+        let mut rest = open_checkpoint(ROUND1_CHECKPOINT_TAG, key, checkpoint)?;
+        let input = take_scalar(&mut rest)?;
+        let participants = (0..take_count(&mut rest)?)
+            .map(|_| take_scalar(&mut rest))
+            .collect::<Result<Vec<_>, _>>()?;
+        let coefficients = (0..take_count(&mut rest)?)
+            .map(|_| take_scalar(&mut rest))
+            .collect::<Result<Vec<_>, _>>()?;
+        if !rest.is_empty() || !participants.contains(&input) {
+            return Err(CheckpointError::InvalidEncoding);
+        }
+        Round1::new(
+            input,
+            participants,
+            SecretSharingPolynomial::new(coefficients),
+        )
+        .map_err(|_| CheckpointError::InvalidEncoding)
+    }
+
+    /// Proceed to the second round after receiving the packages of every other
+    /// participant. This participant's own package may be included or omitted.
+    pub fn into_round2(self, packages: Vec<Round1Package>) -> Result<Round2, DkgError> {
+        let threshold = self.polynomial.coefficients.len();
+        let mut received: Vec<Round1Package> = Vec::with_capacity(self.participants.len());
+        received.push(self.package());
+
+        for package in packages {
+            if package.sender == self.input {
+                continue;
+            }
+            if !self.participants.contains(&package.sender)
+                || received.iter().any(|p| p.sender == package.sender)
+            {
+                return Err(DkgError::Unexpected(package.sender));
+            }
+            if package.commitment.threshold() != threshold {
+                return Err(DkgError::WrongThreshold(package.sender));
+            }
+            if !package.verify_proof() {
+                return Err(DkgError::InvalidProof(package.sender));
+            }
+            received.push(package);
+        }
+
+        if let Some(&missing) = self
+            .participants
+            .iter()
+            .find(|&&x| !received.iter().any(|p| p.sender == x))
+        {
+            return Err(DkgError::Missing(missing));
+        }
+
+        Ok(Round2 {
+            own_share: self.share_for(self.input),
+            input: self.input,
+            packages: received,
+        })
+    }
+}
+
+/// The second round of the DKG, holding every participant's verified package.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Round2 {
+    input: MaybeScalar,
+    own_share: SecretShare,
+    packages: Vec<Round1Package>,
+}
+
+/// Omits the participant's share of their own polynomial.
+impl core::fmt::Debug for Round2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Round2")
+            .field("input", &self.input)
+            .field("packages", &self.packages)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Round2 {
+    /// Save this round so that it can be continued later with [`resume`][Self::resume],
+    /// for instance while waiting days for an offline participant's share.
+    ///
+    /// As with [`Round1::checkpoint`], the checkpoint is authenticated under `key` but
+    /// **not encrypted**: it contains this participant's share of their own polynomial.
+    pub fn checkpoint(&self, key: &[u8]) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&self.input.serialize());
+        body.extend_from_slice(&self.own_share.output.serialize());
+        body.extend_from_slice(&(self.packages.len() as u32).to_be_bytes());
+        for package in self.packages.iter() {
+            body.extend_from_slice(&package.sender.serialize());
+            body.extend_from_slice(&(package.commitment.threshold() as u32).to_be_bytes());
+            for c in package.commitment.coefficients() {
+                body.extend_from_slice(&c.serialize());
+            }
+            body.extend_from_slice(&package.proof.nonce_point.serialize());
+            body.extend_from_slice(&package.proof.response.serialize());
+        }
+        seal_checkpoint(ROUND2_CHECKPOINT_TAG, key, body)
+    }
+
+    /// Resume a round saved with [`checkpoint`][Self::checkpoint] under the same `key`.
+    ///
+    /// Returns [`CheckpointError::InvalidTag`] if the checkpoint was modified or sealed
+    /// with a different key, and [`CheckpointError::InvalidEncoding`] if it is not a
+    /// valid second-round checkpoint.
+    pub fn resume(checkpoint: &[u8], key: &[u8]) -> Result<Self, CheckpointError> {
+        let mut rest = open_checkpoint(ROUND2_CHECKPOINT_TAG, key, checkpoint)?;
+        let input = take_scalar(&mut rest)?;
+        let own_share = Evaluation::new(input, take_scalar(&mut rest)?);
+
+        let mut packages: Vec<Round1Package> = Vec::new();
+        for _ in 0..take_count(&mut rest)? {
+            let sender = take_scalar(&mut rest)?;
+            let coefficients = (0..take_count(&mut rest)?)
+                .map(|_| take_point(&mut rest))
+                .collect::<Result<Vec<_>, _>>()?;
+            let proof = ProofOfKnowledge {
+                nonce_point: take_point(&mut rest)?,
+                response: take_scalar(&mut rest)?,
+            };
+            packages.push(Round1Package {
+                sender,
+                commitment: VssCommitment::new(PointSharingPolynomial::new(coefficients)),
+                proof,
+            });
+        }
+
+        // The packages were verified before the checkpoint was taken, so these checks
+        // only guard the invariants which `finalize` relies on.
+        let senders: Vec<MaybeScalar> = packages.iter().map(|p| p.sender).collect();
+        let threshold = packages.first().map(|p| p.commitment.threshold());
+        if !rest.is_empty()
+            || input.is_zero()
+            || !senders.contains(&input)
+            || check_distinct(&senders).is_err()
+            || threshold == Some(0)
+            || packages
+                .iter()
+                .any(|p| Some(p.commitment.threshold()) != threshold)
+        {
+            return Err(CheckpointError::InvalidEncoding);
+        }
+
+        Ok(Round2 {
+            input,
+            own_share,
+            packages,
+        })
+    }
+
+    /// Finalize the DKG after receiving a share from every other participant, given
+    /// as pairs of `(sender, share)`.
+    pub fn finalize(self, shares: Vec<(MaybeScalar, SecretShare)>) -> Result<Finalize, DkgError> {
+        let mut received: Vec<MaybeScalar> = vec![self.input];
+        let mut output = self.own_share.output;
+
+        for (sender, share) in shares {
+            let package = match self.packages.iter().find(|p| p.sender == sender) {
+                Some(package) if !received.contains(&sender) => package,
+                _ => return Err(DkgError::Unexpected(sender)),
+            };
+            if share.input != self.input || !package.commitment.verify_share(&share) {
+                return Err(DkgError::InvalidShare(sender));
+            }
+            received.push(sender);
+            output += share.output;
+        }
+
+        if let Some(package) = self.packages.iter().find(|p| !received.contains(&p.sender)) {
+            return Err(DkgError::Missing(package.sender));
+        }
+
+        let threshold = self.packages[0].commitment.threshold();
+        let coefficients = (0..threshold)
+            .map(|i| {
+                self.packages.iter().fold(MaybePoint::Infinity, |sum, p| {
+                    sum + p.commitment.coefficients()[i]
+                })
+            })
+            .collect();
+
+        Ok(Finalize {
+            share: Evaluation::new(self.input, output),
+            group_commitment: VssCommitment::new(crate::PointSharingPolynomial::new(coefficients)),
+        })
+    }
+}
+
+/// The output of the DKG for a single participant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finalize {
+    /// The participant's share of the joint secret-sharing polynomial.
+    pub share: SecretShare,
+
+    /// The commitment to the joint polynomial, which every participant computes identically.
+    pub group_commitment: VssCommitment,
+}
+
+impl Finalize {
+    /// Returns the group public key `f(0) * G`.
+    pub fn public_key(&self) -> MaybePoint {
+        self.group_commitment.public_key()
+    }
+}
+
+const PACKAGE_MESSAGE: u8 = 1;
+const SHARE_MESSAGE: u8 = 2;
+
+/// The public parameters of one run of the DKG, which every participant must agree
+/// on before the first round: a unique session identifier, and the input and
+/// registered public key of each participant.
+///
+/// Every message is signed over the session's [transcript hash][Self::transcript_hash],
+/// so messages from one session cannot be replayed in another. Each signature also
+/// covers the round and, for shares, the recipient and the sender's commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DkgSession {
+    keys: Vec<(MaybeScalar, Point)>,
+    transcript_hash: [u8; 32],
+}
+
+impl DkgSession {
+    /// Construct a session from a unique `session_id`, such as a random nonce agreed
+    /// on by the participants, and each participant's input and registered key.
+    ///
+    /// Returns [`Error::ZeroInput`] or [`Error::DuplicateInput`] if the participant
+    /// inputs are not distinct and non-zero.
+    pub fn new(session_id: &[u8], keys: Vec<(MaybeScalar, Point)>) -> Result<Self, Error> {
+        let inputs: Vec<MaybeScalar> = keys.iter().map(|&(x, _)| x).collect();
+        if let Some(index) = inputs.iter().position(|x| x.is_zero()) {
+            return Err(Error::ZeroInput { index });
+        }
+        check_distinct(&inputs)?;
+
+        let mut preimage = Vec::with_capacity(8 + session_id.len() + 65 * keys.len());
+        preimage.extend_from_slice(&(session_id.len() as u64).to_be_bytes());
+        preimage.extend_from_slice(session_id);
+        for (x, key) in keys.iter() {
+            preimage.extend_from_slice(&x.serialize());
+            preimage.extend_from_slice(&key.serialize());
+        }
+        Ok(DkgSession {
+            keys,
+            transcript_hash: tagged_hash(b"qudoku/dkg/transcript", &preimage),
+        })
+    }
+
+    /// Returns the hash binding the session identifier and every participant's key.
+    pub fn transcript_hash(&self) -> [u8; 32] {
+        self.transcript_hash
+    }
+
+    /// Returns the inputs of every participant, in registration order.
+    pub fn participants(&self) -> Vec<MaybeScalar> {
+        self.keys.iter().map(|&(x, _)| x).collect()
+    }
+
+    fn key_of(&self, input: MaybeScalar) -> Option<Point> {
+        self.keys
+            .iter()
+            .find(|(x, _)| *x == input)
+            .map(|&(_, key)| key)
+    }
+
+    fn digest(&self, kind: u8, sender: MaybeScalar, body: &[u8]) -> [u8; 32] {
+        let mut preimage = Vec::with_capacity(32 + 1 + 32 + body.len());
+        preimage.extend_from_slice(&self.transcript_hash);
+        preimage.push(kind);
+        preimage.extend_from_slice(&sender.serialize());
+        preimage.extend_from_slice(body);
+        tagged_hash(b"qudoku/dkg/message", &preimage)
+    }
+
+    fn package_digest(&self, package: &Round1Package) -> [u8; 32] {
+        let mut body = Vec::new();
+        for c in package.commitment.coefficients() {
+            body.extend_from_slice(&c.serialize());
+        }
+        body.extend_from_slice(&package.proof.nonce_point.serialize());
+        body.extend_from_slice(&package.proof.response.serialize());
+        self.digest(PACKAGE_MESSAGE, package.sender, &body)
+    }
+
+    fn share_digest(
+        &self,
+        sender: MaybeScalar,
+        commitment: &VssCommitment,
+        share: &SecretShare,
+    ) -> [u8; 32] {
+        let mut body = Vec::with_capacity(32 + 32 + 32);
+        body.extend_from_slice(&commitment.fingerprint());
+        body.extend_from_slice(&share.input.serialize());
+        body.extend_from_slice(&share.output.serialize());
+        self.digest(SHARE_MESSAGE, sender, &body)
+    }
+
+    fn verify(&self, sender: MaybeScalar, digest: &[u8; 32], signature: &[u8; 64]) -> bool {
+        self.key_of(sender)
+            .is_some_and(|key| verify_schnorr(key, digest, signature))
+    }
+}
+
+/// A [`Round1Package`] signed by its sender's registered key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedRound1Package {
+    pub package: Round1Package,
+    pub signature: [u8; 64],
+}
+
+/// A share sent privately from `sender` to its recipient, signed by the sender's
+/// registered key. Like an unsigned share, it must be encrypted in transit.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SignedShare {
+    pub sender: MaybeScalar,
+    pub share: SecretShare,
+    pub signature: [u8; 64],
+}
+
+/// Omits the share output.
+impl core::fmt::Debug for SignedShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SignedShare")
+            .field("sender", &self.sender)
+            .field("recipient", &self.share.input)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Round1 {
+    /// Returns the package to broadcast, signed with this participant's registered
+    /// secret key `seckey` over the session transcript.
+    pub fn signed_package(
+        &self,
+        session: &DkgSession,
+        seckey: Scalar,
+    ) -> Result<SignedRound1Package, ZeroScalarError> {
+        let package = self.package();
+        let signature = sign_schnorr(seckey, &session.package_digest(&package))?;
+        Ok(SignedRound1Package { package, signature })
+    }
+
+    /// Returns the share for the given recipient, signed with this participant's
+    /// registered secret key `seckey`. The signature binds the share to the session,
+    /// the recipient, and this participant's commitment.
+    pub fn signed_share_for(
+        &self,
+        session: &DkgSession,
+        recipient: MaybeScalar,
+        seckey: Scalar,
+    ) -> Result<SignedShare, ZeroScalarError> {
+        let share = self.share_for(recipient);
+        let digest = session.share_digest(self.input, &self.polynomial.commitment(), &share);
+        Ok(SignedShare {
+            sender: self.input,
+            share,
+            signature: sign_schnorr(seckey, &digest)?,
+        })
+    }
+
+    /// Like [`into_round2`][Self::into_round2], but first checks that every package
+    /// is signed by its sender's key registered in `session`.
+    ///
+    /// Returns [`DkgError::Unauthenticated`] for the first package whose signature
+    /// does not verify, including packages from senders with no registered key.
+    pub fn into_round2_authenticated(
+        self,
+        session: &DkgSession,
+        packages: Vec<SignedRound1Package>,
+    ) -> Result<Round2, DkgError> {
+        let packages = packages
+            .into_iter()
+            .map(|signed| {
+                let sender = signed.package.sender;
+                if session.verify(
+                    sender,
+                    &session.package_digest(&signed.package),
+                    &signed.signature,
+                ) {
+                    Ok(signed.package)
+                } else {
+                    Err(DkgError::Unauthenticated(sender))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.into_round2(packages)
+    }
+}
+
+impl Round2 {
+    /// Like [`finalize`][Self::finalize], but first checks that every share is signed
+    /// by its sender's key registered in `session`, for this recipient and over the
+    /// sender's verified commitment.
+    ///
+    /// Returns [`DkgError::Unexpected`] for a share from a sender with no package, and
+    /// [`DkgError::Unauthenticated`] for the first share whose signature does not verify.
+    pub fn finalize_authenticated(
+        self,
+        session: &DkgSession,
+        shares: Vec<SignedShare>,
+    ) -> Result<Finalize, DkgError> {
+        let shares = shares
+            .into_iter()
+            .map(|signed| {
+                let package = self
+                    .packages
+                    .iter()
+                    .find(|p| p.sender == signed.sender)
+                    .ok_or(DkgError::Unexpected(signed.sender))?;
+                let digest =
+                    session.share_digest(signed.sender, &package.commitment, &signed.share);
+                if signed.share.input != self.input
+                    || !session.verify(signed.sender, &digest, &signed.signature)
+                {
+                    return Err(DkgError::Unauthenticated(signed.sender));
+                }
+                Ok((signed.sender, signed.share))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.finalize(shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InterpolatedSecretPolynomial, Polynomial};
+
+    fn run_dkg(rounds: Vec<Round1>) -> Result<Vec<Finalize>, DkgError> {
+        let packages: Vec<Round1Package> = rounds.iter().map(Round1::package).collect();
+        let inputs: Vec<MaybeScalar> = packages.iter().map(|p| p.sender).collect();
+
+        let shares: Vec<Vec<(MaybeScalar, SecretShare)>> = inputs
+            .iter()
+            .map(|&recipient| {
+                rounds
+                    .iter()
+                    .zip(&inputs)
+                    .filter(|(_, &sender)| sender != recipient)
+                    .map(|(round, &sender)| (sender, round.share_for(recipient)))
+                    .collect()
+            })
+            .collect();
+
+        rounds
+            .into_iter()
+            .zip(shares)
+            .map(|(round, shares)| round.into_round2(packages.clone())?.finalize(shares))
+            .collect()
+    }
+
+    fn round1s(threshold: usize) -> Vec<Round1> {
+        let participants: Vec<MaybeScalar> = (1..=4).map(MaybeScalar::from).collect();
+        participants
+            .iter()
+            .map(|&x| {
+                let coefficients = (0..threshold)
+                    .map(|j| MaybeScalar::from(1000 * j as u128 + 7) * x + MaybeScalar::from(3))
+                    .collect();
+                Round1::new(
+                    x,
+                    participants.clone(),
+                    SecretSharingPolynomial::new(coefficients),
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dkg() {
+        let rounds = round1s(3);
+        let group_secret = rounds.iter().fold(MaybeScalar::Zero, |sum, r| {
+            sum + r.polynomial.coefficients[0]
+        });
+
+        let outputs = run_dkg(rounds).unwrap();
+        let public_key = outputs[0].public_key();
+        assert_eq!(public_key, group_secret * G);
+
+        for output in outputs.iter() {
+            assert_eq!(output.group_commitment, outputs[0].group_commitment);
+            assert!(output.group_commitment.verify_share(&output.share));
+        }
+
+        let shares: Vec<SecretShare> = outputs[1..].iter().map(|o| o.share).collect();
+        let recovered = InterpolatedSecretPolynomial::new(shares).evaluate(MaybeScalar::Zero);
+        assert_eq!(recovered, group_secret);
+    }
+
+    #[test]
+    fn test_dkg_detects_misbehaviour() {
+        let rounds = round1s(2);
+        let mut packages: Vec<Round1Package> = rounds.iter().map(Round1::package).collect();
+
+        // Tampered proof of knowledge.
+        packages[1].proof.response += MaybeScalar::one();
+        assert_eq!(
+            rounds[0].clone().into_round2(packages.clone()),
+            Err(DkgError::InvalidProof(MaybeScalar::from(2)))
+        );
+
+        // Missing package.
+        let packages: Vec<Round1Package> = rounds.iter().map(Round1::package).collect();
+        assert_eq!(
+            rounds[0].clone().into_round2(packages[..3].to_vec()),
+            Err(DkgError::Missing(MaybeScalar::from(4)))
+        );
+
+        // Bad share.
+        let round2 = rounds[0].clone().into_round2(packages).unwrap();
+        let x = MaybeScalar::from(1);
+        let mut shares: Vec<(MaybeScalar, SecretShare)> = rounds[1..]
+            .iter()
+            .map(|r| (r.input, r.share_for(x)))
+            .collect();
+        shares[2].1.output += MaybeScalar::one();
+        assert_eq!(
+            round2.finalize(shares),
+            Err(DkgError::InvalidShare(MaybeScalar::from(4)))
+        );
+    }
+
+    #[test]
+    fn test_round1_rejects_invalid_participants() {
+        let polynomial = SecretSharingPolynomial::new(vec![MaybeScalar::from(5); 2]);
+        let x = MaybeScalar::from(1);
+        let participants = |xs: &[u128]| -> Vec<MaybeScalar> {
+            xs.iter().map(|&i| MaybeScalar::from(i)).collect()
+        };
+
+        assert_eq!(
+            Round1::new(x, participants(&[1, 2, 2]), polynomial.clone()),
+            Err(Error::DuplicateInput {
+                first: 1,
+                second: 2
+            })
+        );
+        assert_eq!(
+            Round1::new(x, participants(&[1, 0, 3]), polynomial.clone()),
+            Err(Error::ZeroInput { index: 1 })
+        );
+        assert_eq!(
+            Round1::new(
+                x,
+                participants(&[1, 2]),
+                SecretSharingPolynomial::new(vec![])
+            ),
+            Err(Error::Empty)
+        );
+        assert_eq!(
+            Round1::new(x, participants(&[2, 3]), polynomial.clone()),
+            Err(Error::UnknownInput)
+        );
+        assert_eq!(
+            Round1::new(x, participants(&[1]), polynomial.clone()),
+            Err(Error::NotEnoughShares {
+                threshold: 2,
+                count: 1
+            })
+        );
+        assert!(Round1::new(x, participants(&[1, 2]), polynomial).is_ok());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_round1_random_zero_threshold() {
+        let participants = vec![MaybeScalar::from(1), MaybeScalar::from(2)];
+        assert_eq!(
+            Round1::random(
+                MaybeScalar::from(1),
+                participants,
+                0,
+                &mut rand::rngs::OsRng
+            ),
+            Err(Error::Empty)
+        );
+    }
+
+    #[test]
+    fn test_round_debug_redacts_secrets() {
+        let rounds = round1s(2);
+        let debug = format!("{:?}", rounds[0]);
+        assert!(debug.contains("threshold: 2"));
+        assert!(!debug.contains("polynomial"));
+
+        let packages: Vec<Round1Package> = rounds.iter().map(Round1::package).collect();
+        let round2 = rounds[0].clone().into_round2(packages).unwrap();
+        assert!(!format!("{:?}", round2).contains("own_share"));
+    }
+
+    #[test]
+    fn test_dkg_checkpoints() {
+        let key = b"checkpoint key";
+        let rounds = round1s(3);
+
+        let checkpoint = rounds[0].checkpoint(key);
+        let resumed = Round1::resume(&checkpoint, key).unwrap();
+        assert_eq!(resumed, rounds[0]);
+        assert_eq!(
+            Round1::resume(&checkpoint, b"wrong key"),
+            Err(CheckpointError::InvalidTag)
+        );
+        let mut tampered = checkpoint.clone();
+        tampered[40] ^= 1;
+        assert_eq!(
+            Round1::resume(&tampered, key),
+            Err(CheckpointError::InvalidTag)
+        );
+
+        let packages: Vec<Round1Package> = rounds.iter().map(Round1::package).collect();
+        let round2 = resumed.into_round2(packages).unwrap();
+
+        // A first-round checkpoint cannot be resumed as the second round.
+        assert_eq!(
+            Round2::resume(&checkpoint, key),
+            Err(CheckpointError::InvalidTag)
+        );
+
+        let resumed = Round2::resume(&round2.checkpoint(key), key).unwrap();
+        assert_eq!(resumed, round2);
+
+        let x = MaybeScalar::from(1);
+        let shares = rounds[1..]
+            .iter()
+            .map(|r| (r.input, r.share_for(x)))
+            .collect::<Vec<_>>();
+        assert_eq!(resumed.finalize(shares.clone()), round2.finalize(shares));
+    }
+
+    #[test]
+    fn test_authenticated_dkg() {
+        let rounds = round1s(2);
+        let seckeys: Vec<Scalar> = (1..=4u128)
+            .map(|i| MaybeScalar::from(9000 + i).unwrap())
+            .collect();
+        let keys = rounds
+            .iter()
+            .zip(&seckeys)
+            .map(|(r, &k)| (r.input, k * G))
+            .collect();
+        let session = DkgSession::new(b"session 1", keys).unwrap();
+        assert_eq!(session.participants(), rounds[0].participants);
+
+        let packages: Vec<SignedRound1Package> = rounds
+            .iter()
+            .zip(&seckeys)
+            .map(|(r, &k)| r.signed_package(&session, k).unwrap())
+            .collect();
+
+        // A package signed by the wrong key is rejected.
+        let mut forged = packages.clone();
+        forged[1] = rounds[1].signed_package(&session, seckeys[2]).unwrap();
+        assert_eq!(
+            rounds[0]
+                .clone()
+                .into_round2_authenticated(&session, forged),
+            Err(DkgError::Unauthenticated(MaybeScalar::from(2)))
+        );
+
+        // Packages from another session cannot be replayed.
+        let other = DkgSession::new(b"session 2", session.keys.clone()).unwrap();
+        assert_ne!(other.transcript_hash(), session.transcript_hash());
+        assert_eq!(
+            rounds[0]
+                .clone()
+                .into_round2_authenticated(&other, packages.clone()),
+            Err(DkgError::Unauthenticated(MaybeScalar::from(1)))
+        );
+
+        let x = MaybeScalar::from(1);
+        let round2 = rounds[0]
+            .clone()
+            .into_round2_authenticated(&session, packages)
+            .unwrap();
+        let shares: Vec<SignedShare> = rounds[1..]
+            .iter()
+            .zip(&seckeys[1..])
+            .map(|(r, &k)| r.signed_share_for(&session, x, k).unwrap())
+            .collect();
+
+        // A share addressed to another participant cannot be replayed to this one.
+        let mut replayed = shares.clone();
+        replayed[0] = rounds[1]
+            .signed_share_for(&session, MaybeScalar::from(3), seckeys[1])
+            .unwrap();
+        replayed[0].share.input = x;
+        assert_eq!(
+            round2.clone().finalize_authenticated(&session, replayed),
+            Err(DkgError::Unauthenticated(MaybeScalar::from(2)))
+        );
+
+        let expected = round2
+            .clone()
+            .finalize(shares.iter().map(|s| (s.sender, s.share)).collect());
+        assert_eq!(round2.finalize_authenticated(&session, shares), expected);
+        assert!(expected.is_ok());
+    }
+}
@@ -0,0 +1,114 @@
+//! Helpers for running a shareholder group as a threshold
+//! [Discreet Log Contract](https://github.com/discreetlogcontracts/dlcspecs) oracle.
+//!
+//! Each possible outcome of an event is assigned its own `Q` point, derived by
+//! hashing the event ID and outcome. The attestation scalar for an outcome is the
+//! nested secret derived from that `Q` point, reduced to a scalar. The dealer can
+//! compute and publish every attestation point ahead of time. When the event
+//! resolves, a quorum of shareholders issues point shares for the `Q` point of the
+//! true outcome only, which reveals that outcome's attestation scalar and nothing else.
+
+use crate::{
+    hash_to_point_tagged, InterpolatedPointPolynomial, PointShare, SecretShare,
+    SecretSharingPolynomial,
+};
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar, Point, G};
+
+/// Derive the `Q` point for a given event ID and outcome.
+///
+/// The event ID is length-prefixed so that distinct `(event_id, outcome)` pairs
+/// can never hash to the same point.
+pub fn outcome_point(event_id: &[u8], outcome: &[u8]) -> Point {
+    let mut preimage = Vec::with_capacity(8 + event_id.len() + outcome.len());
+    preimage.extend_from_slice(&(event_id.len() as u64).to_be_bytes());
+    preimage.extend_from_slice(event_id);
+    preimage.extend_from_slice(outcome);
+    hash_to_point_tagged(b"qudoku/dlc", &preimage)
+}
+
+/// The tag under which attestation secrets are derived.
+const ATTESTATION_TAG: &[u8] = b"qudoku/dlc/attestation";
+
+/// Convert a 32-byte derived secret into an attestation scalar.
+fn attestation_scalar_from_secret(secret: [u8; 32]) -> MaybeScalar {
+    MaybeScalar::reduce_from(&secret)
+}
+
+/// Compute the attestation point `s * G` for each outcome of an event.
+///
+/// Only the dealer can run this, since it requires the full secret-sharing
+/// polynomial. The output points are safe to publish, and are used by DLC
+/// participants as the adaptor points for each outcome.
+pub fn attestation_points<O: AsRef<[u8]>>(
+    secret_polynomial: &SecretSharingPolynomial,
+    event_id: &[u8],
+    outcomes: &[O],
+) -> Vec<MaybePoint> {
+    outcomes
+        .iter()
+        .map(|outcome| {
+            let q = outcome_point(event_id, outcome.as_ref());
+            let secret =
+                (q * secret_polynomial).derive_secret_tagged(ATTESTATION_TAG, MaybeScalar::Zero);
+            attestation_scalar_from_secret(secret) * G
+        })
+        .collect()
+}
+
+/// Compute a shareholder's point share towards attesting to the given outcome.
+///
+/// Shareholders should only issue this for the outcome which actually occurred,
+/// because a quorum of these shares reveals the attestation scalar.
+pub fn attestation_share(share: &SecretShare, event_id: &[u8], outcome: &[u8]) -> PointShare {
+    share.to_point_share(outcome_point(event_id, outcome))
+}
+
+/// Recover the attestation scalar for an outcome from a quorum of point shares,
+/// issued using [`attestation_share`].
+pub fn recover_attestation(point_shares: Vec<PointShare>) -> MaybeScalar {
+    let secret = InterpolatedPointPolynomial::new(point_shares)
+        .derive_secret_tagged(ATTESTATION_TAG, MaybeScalar::Zero);
+    attestation_scalar_from_secret(secret)
+}
+
+/// Check whether an attestation scalar matches a previously published attestation point.
+pub fn verify_attestation(attestation: MaybeScalar, attestation_point: MaybePoint) -> bool {
+    attestation * G == attestation_point
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threshold_dlc_attestation() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(0xDEADBEEF),
+            MaybeScalar::from(7),
+            MaybeScalar::from(100),
+        ]);
+        let shares: Vec<SecretShare> = (1..=5)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        let event_id = b"btcusd-2030-01-01";
+        let outcomes = ["above", "below"];
+        let points = attestation_points(&f, event_id, &outcomes);
+        assert_ne!(points[0], points[1]);
+
+        let point_shares = shares[1..4]
+            .iter()
+            .map(|share| attestation_share(share, event_id, b"below"))
+            .collect();
+        let attestation = recover_attestation(point_shares);
+
+        assert!(verify_attestation(attestation, points[1]));
+        assert!(!verify_attestation(attestation, points[0]));
+    }
+
+    #[test]
+    fn test_outcome_point_domain_separation() {
+        assert_ne!(outcome_point(b"ab", b"c"), outcome_point(b"a", b"bc"));
+    }
+}
@@ -0,0 +1,202 @@
+//! Non-interactive [Chaum-Pedersen](https://link.springer.com/chapter/10.1007/3-540-48071-4_7)
+//! proofs of discrete log equality (DLEQ).
+//!
+//! During nested-secret recovery, each shareholder publishes a point share
+//! `Z_i = f(x_i) * Q`. A combiner cannot tell whether `Z_i` is honest by looking at it,
+//! so a shareholder can attach a [`DleqProof`] showing that `Z_i` was computed from the
+//! same scalar as their verification share `f(x_i) * G`, which the combiner knows from
+//! the dealer's [`VssCommitment`][crate::VssCommitment].
+
+use crate::{tagged_hash, PointShare, SecretShare};
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar, Point};
+
+/// Serialize a sequence of points and compute a tagged hash of them, reduced to a scalar.
+fn hash_points_to_scalar(tag: &[u8], points: &[MaybePoint]) -> MaybeScalar {
+    let mut preimage = Vec::with_capacity(points.len() * 33);
+    for point in points {
+        preimage.extend_from_slice(&point.serialize());
+    }
+    MaybeScalar::reduce_from(&tagged_hash(tag, &preimage))
+}
+
+/// A non-interactive Chaum-Pedersen proof that two points `P1 = k * B1` and
+/// `P2 = k * B2` share the same discrete log `k` relative to two bases `B1` and `B2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DleqProof {
+    /// The Fiat-Shamir challenge `c`.
+    pub challenge: MaybeScalar,
+
+    /// The response `s = r + c * k`.
+    pub response: MaybeScalar,
+}
+
+impl DleqProof {
+    /// Prove that `secret * base1` and `secret * base2` share the same discrete log.
+    ///
+    /// The nonce is derived deterministically from the secret and the statement.
+    pub fn prove(secret: MaybeScalar, base1: MaybePoint, base2: MaybePoint) -> Self {
+        let public1 = secret * base1;
+        let public2 = secret * base2;
+
+        let mut nonce_preimage = Vec::with_capacity(32 + 33 * 2);
+        nonce_preimage.extend_from_slice(&secret.serialize());
+        nonce_preimage.extend_from_slice(&base1.serialize());
+        nonce_preimage.extend_from_slice(&base2.serialize());
+        let nonce = MaybeScalar::reduce_from(&tagged_hash(b"qudoku/dleq/nonce", &nonce_preimage));
+
+        let challenge = hash_points_to_scalar(
+            b"qudoku/dleq/challenge",
+            &[base1, public1, base2, public2, nonce * base1, nonce * base2],
+        );
+
+        DleqProof {
+            challenge,
+            response: nonce + challenge * secret,
+        }
+    }
+
+    /// Verify that `public1` and `public2` share the same discrete log relative to
+    /// `base1` and `base2` respectively.
+    pub fn verify(
+        &self,
+        base1: MaybePoint,
+        public1: MaybePoint,
+        base2: MaybePoint,
+        public2: MaybePoint,
+    ) -> bool {
+        let nonce_point1 = self.response * base1 - self.challenge * public1;
+        let nonce_point2 = self.response * base2 - self.challenge * public2;
+
+        let challenge = hash_points_to_scalar(
+            b"qudoku/dleq/challenge",
+            &[base1, public1, base2, public2, nonce_point1, nonce_point2],
+        );
+        challenge == self.challenge
+    }
+}
+
+/// Compute a shareholder's point share `f(x_i) * Q`, along with a proof that it uses
+/// the same scalar as their verification share `f(x_i) * G`.
+pub fn prove_point_share(share: &SecretShare, q: Point) -> (PointShare, DleqProof) {
+    let proof = DleqProof::prove(
+        share.output,
+        MaybePoint::from(Point::generator()),
+        MaybePoint::from(q),
+    );
+    (share.to_point_share(q), proof)
+}
+
+/// Verify that a point share `f(x_i) * Q` was computed from the same scalar as the
+/// verification share `f(x_i) * G` issued at the same input.
+pub fn verify_point_share(
+    verification_share: &PointShare,
+    point_share: &PointShare,
+    q: Point,
+    proof: &DleqProof,
+) -> bool {
+    verification_share.input == point_share.input
+        && proof.verify(
+            MaybePoint::from(Point::generator()),
+            verification_share.output,
+            MaybePoint::from(q),
+            point_share.output,
+        )
+}
+
+/// Verify several point shares for the same `Q` point against their verification
+/// shares and proofs, given as parallel slices.
+///
+/// This is not batch verification: each proof is checked in turn with
+/// [`verify_point_share`], so the cost grows linearly with the number of shares.
+/// Proofs carry a challenge rather than nonce points, so they cannot be folded
+/// into a single random linear combination.
+///
+/// Returns `Err(i)` with the index of the first point share which fails verification,
+/// so that a combiner can identify and exclude the misbehaving shareholder. If the
+/// slices differ in length, the index of the first missing element is returned.
+pub fn verify_point_shares(
+    verification_shares: &[PointShare],
+    point_shares: &[PointShare],
+    q: Point,
+    proofs: &[DleqProof],
+) -> Result<(), usize> {
+    let n = verification_shares.len();
+    for i in 0..n.max(point_shares.len()).max(proofs.len()) {
+        match (
+            verification_shares.get(i),
+            point_shares.get(i),
+            proofs.get(i),
+        ) {
+            (Some(v), Some(z), Some(proof)) if verify_point_share(v, z, q, proof) => {}
+            _ => return Err(i),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dleq_proof() {
+        let secret = MaybeScalar::from(0xBEEF);
+        let base1 = MaybePoint::from(Point::generator());
+        let base2 = MaybePoint::from(crate::hash_to_point_tagged(b"qudoku/test", b"base2"));
+
+        let proof = DleqProof::prove(secret, base1, base2);
+        assert!(proof.verify(base1, secret * base1, base2, secret * base2));
+
+        let other = MaybeScalar::from(0xBEEE);
+        assert!(!proof.verify(base1, secret * base1, base2, other * base2));
+        assert!(!proof.verify(base1, other * base1, base2, secret * base2));
+        assert!(!proof.verify(base2, secret * base2, base1, secret * base1));
+    }
+
+    #[test]
+    fn test_point_share_proofs() {
+        let f =
+            crate::SecretSharingPolynomial::new(vec![MaybeScalar::from(42), MaybeScalar::from(43)]);
+        let q = crate::hash_to_point_tagged(b"qudoku/test", b"nested");
+        let shares: Vec<SecretShare> = (1..=4)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+        let verification_shares = SecretShare::verification_shares(&shares);
+
+        let (mut point_shares, proofs): (Vec<PointShare>, Vec<DleqProof>) = shares
+            .iter()
+            .map(|share| prove_point_share(share, q))
+            .unzip();
+        assert_eq!(point_shares[0], shares[0] * q);
+        assert!(verify_point_share(
+            &verification_shares[0],
+            &point_shares[0],
+            q,
+            &proofs[0]
+        ));
+        assert_eq!(
+            verify_point_shares(&verification_shares, &point_shares, q, &proofs),
+            Ok(())
+        );
+        assert_eq!(
+            verify_point_shares(&verification_shares, &point_shares[..3], q, &proofs),
+            Err(3)
+        );
+
+        // A bogus point share is identified.
+        point_shares[2].output = MaybeScalar::from(7) * q;
+        assert_eq!(
+            verify_point_shares(&verification_shares, &point_shares, q, &proofs),
+            Err(2)
+        );
+
+        let other_q = crate::hash_to_point_tagged(b"qudoku/test", b"other");
+        assert!(!verify_point_share(
+            &verification_shares[0],
+            &point_shares[0],
+            other_q,
+            &proofs[0]
+        ));
+    }
+}
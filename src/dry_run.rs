@@ -0,0 +1,153 @@
+use crate::{parallel::par_map, SecretShare, VssCommitment};
+use alloc::vec::Vec;
+
+/// The outcome of checking one candidate share in a [`DryRunReport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShareCheck {
+    /// The share is consistent with the commitment and can be used for recovery.
+    Valid,
+
+    /// The share is not consistent with the commitment.
+    Invalid,
+
+    /// The share has a zero input, which is not a valid share.
+    ZeroInput,
+
+    /// The share is valid, but has the same input as the valid share at `first`,
+    /// so it adds nothing to recovery.
+    DuplicateInput { first: usize },
+}
+
+/// A report produced by [`VssCommitment::dry_run`] on whether a set of candidate
+/// shares can recover the committed secret. The report never contains the secret
+/// or any share outputs, so it can be shared with support staff planning a recovery.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DryRunReport {
+    /// The number of valid shares needed to recover the secret.
+    pub threshold: usize,
+
+    /// The outcome for each candidate share, in the order they were given.
+    pub checks: Vec<ShareCheck>,
+}
+
+impl DryRunReport {
+    /// Returns the positions of the shares which can be used for recovery.
+    pub fn valid_shares(&self) -> Vec<usize> {
+        self.positions(|check| check == ShareCheck::Valid)
+    }
+
+    /// Returns the positions of the shares which cannot be used for recovery,
+    /// for any reason.
+    pub fn failed_shares(&self) -> Vec<usize> {
+        self.positions(|check| check != ShareCheck::Valid)
+    }
+
+    /// Returns true if the valid shares are enough to recover the secret, i.e. if
+    /// passing them to [`recover_secret`][crate::recover_secret] with the
+    /// commitment's threshold would return the committed secret.
+    pub fn would_succeed(&self) -> bool {
+        self.threshold > 0 && self.valid_shares().len() >= self.threshold
+    }
+
+    fn positions(&self, predicate: impl Fn(ShareCheck) -> bool) -> Vec<usize> {
+        (0..self.checks.len())
+            .filter(|&i| predicate(self.checks[i]))
+            .collect()
+    }
+}
+
+impl VssCommitment {
+    /// Check a candidate set of shares for a recovery ceremony without recovering
+    /// the secret. Each share is verified against the commitment, as with
+    /// [`verify_shares`][Self::verify_shares], and shares which recovery would
+    /// reject are reported as failed.
+    pub fn dry_run(&self, shares: &[SecretShare]) -> DryRunReport {
+        let verified = par_map(shares, |share| self.verify_share(share));
+
+        let mut checks: Vec<ShareCheck> = Vec::with_capacity(shares.len());
+        for (i, share) in shares.iter().enumerate() {
+            let check = if share.input.is_zero() {
+                ShareCheck::ZeroInput
+            } else if !verified[i] {
+                ShareCheck::Invalid
+            } else if let Some(first) =
+                (0..i).find(|&j| checks[j] == ShareCheck::Valid && shares[j].input == share.input)
+            {
+                ShareCheck::DuplicateInput { first }
+            } else {
+                ShareCheck::Valid
+            };
+            checks.push(check);
+        }
+
+        DryRunReport {
+            threshold: self.threshold(),
+            checks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{recover_secret, SecretSharingPolynomial};
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_dry_run() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(1234),
+            MaybeScalar::from(5),
+            MaybeScalar::from(6),
+        ]);
+        let commitment = f.commitment();
+        let shares: Vec<SecretShare> = (1..=3)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        let report = commitment.dry_run(&shares);
+        assert_eq!(report.checks, vec![ShareCheck::Valid; 3]);
+        assert!(report.would_succeed());
+        assert!(report.failed_shares().is_empty());
+
+        let mut forged = shares[1];
+        forged.output += MaybeScalar::one();
+        let candidates = [
+            shares[0],
+            forged,
+            f.issue_share(MaybeScalar::Zero),
+            shares[0],
+            shares[2],
+        ];
+        let report = commitment.dry_run(&candidates);
+        assert_eq!(
+            report.checks,
+            vec![
+                ShareCheck::Valid,
+                ShareCheck::Invalid,
+                ShareCheck::ZeroInput,
+                ShareCheck::DuplicateInput { first: 0 },
+                ShareCheck::Valid,
+            ]
+        );
+        assert_eq!(report.valid_shares(), vec![0, 4]);
+        assert_eq!(report.failed_shares(), vec![1, 2, 3]);
+        assert!(!report.would_succeed());
+
+        // A valid share after an invalid one at the same input still counts.
+        let candidates = [forged, shares[1], shares[0], shares[2]];
+        let report = commitment.dry_run(&candidates);
+        assert_eq!(report.valid_shares(), vec![1, 2, 3]);
+        assert!(report.would_succeed());
+
+        let valid: Vec<SecretShare> = report
+            .valid_shares()
+            .into_iter()
+            .map(|i| candidates[i])
+            .collect();
+        assert_eq!(
+            recover_secret(&valid, report.threshold),
+            Ok(MaybeScalar::from(1234))
+        );
+    }
+}
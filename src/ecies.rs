@@ -0,0 +1,156 @@
+//! Encryption of shares to a shareholder's secp256k1 public key.
+//!
+//! Each message is encrypted with an ephemeral ECDH key exchange against the recipient's
+//! public key, followed by ChaCha20-Poly1305. The output is a self-contained blob,
+//! `E || ciphertext`, where `E` is the 33-byte compressed ephemeral public key. The
+//! symmetric key is a tagged hash of `E`, the recipient's public key, and the shared
+//! point, so it is unique to each ephemeral key and a fixed nonce can be used.
+//!
+//! A dealer can use [`encrypt_share`] to distribute each issued [`SecretShare`] over
+//! an untrusted channel.
+
+use crate::aead::{open, seal, TAG_LEN};
+use crate::{tagged_hash, InvalidShareBytes, SecretShare};
+use alloc::vec::Vec;
+use secp::{MaybePoint, Point, Scalar, G};
+
+/// Errors which can occur when decrypting a blob.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EciesError {
+    /// The blob is too short, or a decrypted share has the wrong length.
+    InvalidLength,
+
+    /// The ephemeral public key is not a valid point.
+    InvalidEphemeralKey,
+
+    /// The ciphertext failed authentication, either because it was tampered with or
+    /// because it was encrypted to a different key.
+    DecryptionFailed,
+
+    /// The decrypted bytes are not a valid share.
+    InvalidShare(InvalidShareBytes),
+}
+
+impl core::fmt::Display for EciesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EciesError::InvalidLength => write!(f, "invalid ciphertext length"),
+            EciesError::InvalidEphemeralKey => write!(f, "invalid ephemeral public key"),
+            EciesError::DecryptionFailed => write!(f, "decryption failed"),
+            EciesError::InvalidShare(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EciesError {}
+
+const NONCE: [u8; 12] = [0; 12];
+
+fn derive_key(ephemeral: &Point, recipient: &Point, shared: &MaybePoint) -> [u8; 32] {
+    let mut preimage = [0u8; 33 * 3];
+    preimage[..33].copy_from_slice(&ephemeral.serialize());
+    preimage[33..66].copy_from_slice(&recipient.serialize());
+    preimage[66..].copy_from_slice(&shared.serialize());
+    tagged_hash(b"qudoku/ecies", &preimage)
+}
+
+/// Encrypt a plaintext to the recipient's public key, using the given ephemeral
+/// secret key. The ephemeral key must be uniformly random and never reused.
+pub fn encrypt(recipient: Point, ephemeral_secret: Scalar, plaintext: &[u8]) -> Vec<u8> {
+    let ephemeral = ephemeral_secret * G;
+    let key = derive_key(
+        &ephemeral,
+        &recipient,
+        &(ephemeral_secret * recipient).into(),
+    );
+
+    let mut blob = Vec::with_capacity(33 + plaintext.len() + TAG_LEN);
+    blob.extend_from_slice(&ephemeral.serialize());
+    blob.extend_from_slice(&seal(&key, &NONCE, &[], plaintext));
+    blob
+}
+
+/// Encrypt a plaintext to the recipient's public key with a random ephemeral key.
+#[cfg(feature = "rand")]
+pub fn encrypt_random<R: rand::RngCore + rand::CryptoRng>(
+    recipient: Point,
+    plaintext: &[u8],
+    rng: &mut R,
+) -> Vec<u8> {
+    encrypt(recipient, Scalar::random(rng), plaintext)
+}
+
+/// Decrypt a blob produced by [`encrypt`] with the recipient's secret key.
+pub fn decrypt(secret: Scalar, blob: &[u8]) -> Result<Vec<u8>, EciesError> {
+    if blob.len() < 33 + TAG_LEN {
+        return Err(EciesError::InvalidLength);
+    }
+    let ephemeral = Point::from_slice(&blob[..33]).map_err(|_| EciesError::InvalidEphemeralKey)?;
+    let key = derive_key(&ephemeral, &(secret * G), &(secret * ephemeral).into());
+    open(&key, &NONCE, &[], &blob[33..]).ok_or(EciesError::DecryptionFailed)
+}
+
+/// Encrypt a secret share to the recipient's public key, using the given ephemeral
+/// secret key. The ephemeral key must be uniformly random and never reused.
+pub fn encrypt_share(share: &SecretShare, recipient: Point, ephemeral_secret: Scalar) -> Vec<u8> {
+    encrypt(recipient, ephemeral_secret, &share.to_bytes())
+}
+
+/// Encrypt a secret share to the recipient's public key with a random ephemeral key.
+#[cfg(feature = "rand")]
+pub fn encrypt_share_random<R: rand::RngCore + rand::CryptoRng>(
+    share: &SecretShare,
+    recipient: Point,
+    rng: &mut R,
+) -> Vec<u8> {
+    encrypt_share(share, recipient, Scalar::random(rng))
+}
+
+/// Decrypt a secret share from a blob produced by [`encrypt_share`], with the
+/// recipient's secret key.
+pub fn decrypt_share(secret: Scalar, blob: &[u8]) -> Result<SecretShare, EciesError> {
+    let bytes: [u8; 64] = decrypt(secret, blob)?
+        .try_into()
+        .map_err(|_| EciesError::InvalidLength)?;
+    SecretShare::from_bytes(&bytes).map_err(EciesError::InvalidShare)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_encrypt_share() {
+        let share = SecretShare::new(MaybeScalar::from(7), MaybeScalar::from(0xC0FFEE));
+        let secret = Scalar::try_from(0x1234).unwrap();
+        let ephemeral = Scalar::try_from(0x5678).unwrap();
+
+        let blob = encrypt_share(&share, secret * G, ephemeral);
+        assert_eq!(blob.len(), 33 + 64 + TAG_LEN);
+        assert_eq!(decrypt_share(secret, &blob), Ok(share));
+
+        let wrong_secret = Scalar::try_from(0x4321).unwrap();
+        assert_eq!(
+            decrypt_share(wrong_secret, &blob),
+            Err(EciesError::DecryptionFailed)
+        );
+
+        let mut tampered = blob.clone();
+        tampered[40] ^= 1;
+        assert_eq!(
+            decrypt_share(secret, &tampered),
+            Err(EciesError::DecryptionFailed)
+        );
+
+        assert_eq!(
+            decrypt_share(secret, &blob[..40]),
+            Err(EciesError::InvalidLength)
+        );
+        assert_eq!(
+            decrypt_share(secret, &encrypt(secret * G, ephemeral, b"short")),
+            Err(EciesError::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    check_distinct, check_duplicate_inputs, try_interpolate, Error, LagrangePolynomial,
+    SecretShare, SecretSharingPolynomial, StandardFormPolynomial,
+};
+use alloc::{vec, vec::Vec};
+use secp::MaybeScalar;
+
+/// A secret-sharing polynomial which passes through several caller-chosen secrets
+/// `(x_j, s_j)` at once, generalizing the single secret `f(0)`.
+///
+/// The polynomial is `f(x) = S(x) + Z(x) * r(x)`, where `S` interpolates the secrets,
+/// `Z(x) = ∏ (x - x_j)` vanishes on every secret slot, and `r` holds the padding
+/// coefficients. Any `threshold` shares recover every secret by interpolating at its
+/// slot with [`recover_embedded_secret`]. Shares are never issued at a secret slot,
+/// since such a share would be the secret itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmbeddedSecrets {
+    polynomial: SecretSharingPolynomial,
+    secrets: Vec<SecretShare>,
+}
+
+impl EmbeddedSecrets {
+    /// Construct the polynomial passing through the given secrets, using `padding` as
+    /// the coefficients of `r(x)`. The threshold is the number of secrets plus the
+    /// number of padding coefficients. The padding must be sampled randomly and kept
+    /// secret, or else fewer shares reveal information about the secrets.
+    ///
+    /// Returns [`Error::Empty`] if there are no secrets, or [`Error::DuplicateInput`]
+    /// if two secrets share a slot.
+    pub fn new(secrets: Vec<SecretShare>, padding: Vec<MaybeScalar>) -> Result<Self, Error> {
+        if secrets.is_empty() {
+            return Err(Error::Empty);
+        }
+        check_duplicate_inputs(&secrets)?;
+
+        let mut vanishing = StandardFormPolynomial::new(vec![MaybeScalar::one()]);
+        for secret in secrets.iter() {
+            vanishing =
+                vanishing * StandardFormPolynomial::new(vec![-secret.input, MaybeScalar::one()]);
+        }
+
+        let threshold = secrets.len() + padding.len();
+        let mut polynomial = LagrangePolynomial::new(secrets.clone()).to_standard_form();
+        if !padding.is_empty() {
+            polynomial = polynomial + vanishing * StandardFormPolynomial::new(padding);
+        }
+        polynomial.coefficients.resize(threshold, MaybeScalar::Zero);
+
+        Ok(EmbeddedSecrets {
+            polynomial,
+            secrets,
+        })
+    }
+
+    /// Embed the given secrets in a polynomial with random padding, such that
+    /// `threshold` shares are needed to recover them.
+    ///
+    /// Returns [`Error::NotEnoughShares`] if `threshold` is less than the number of
+    /// secrets, or an error in the same cases as [`new`][Self::new].
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(
+        secrets: Vec<SecretShare>,
+        threshold: usize,
+        rng: &mut R,
+    ) -> Result<Self, Error> {
+        if threshold < secrets.len() {
+            return Err(Error::NotEnoughShares {
+                threshold: secrets.len(),
+                count: threshold,
+            });
+        }
+        let padding = crate::random_coefficients(rng, threshold - secrets.len());
+        EmbeddedSecrets::new(secrets, padding)
+    }
+
+    /// Returns the secret-sharing polynomial.
+    pub fn polynomial(&self) -> &SecretSharingPolynomial {
+        &self.polynomial
+    }
+
+    /// Returns the embedded secrets, with their slots as inputs.
+    pub fn secrets(&self) -> &[SecretShare] {
+        &self.secrets
+    }
+
+    /// Returns the number of shares needed to recover the secrets.
+    pub fn threshold(&self) -> usize {
+        self.polynomial.coefficients.len()
+    }
+
+    /// Returns true if `x` is the slot of an embedded secret.
+    pub fn is_reserved(&self, x: MaybeScalar) -> bool {
+        self.secrets.iter().any(|secret| secret.input == x)
+    }
+
+    /// Issue shares at each of the given inputs, in order.
+    ///
+    /// Returns [`Error::ReservedInput`] if any input is the slot of an embedded secret,
+    /// or [`Error::DuplicateInput`] if `xs` contains duplicates.
+    pub fn issue_shares(&self, xs: &[MaybeScalar]) -> Result<Vec<SecretShare>, Error> {
+        if let Some(index) = xs.iter().position(|&x| self.is_reserved(x)) {
+            return Err(Error::ReservedInput { index });
+        }
+        check_distinct(xs)?;
+        self.polynomial.try_issue_shares(xs)
+    }
+}
+
+/// Recover the secret embedded at `slot` from a set of shares, given the
+/// `threshold` of the [`EmbeddedSecrets`] polynomial which issued them.
+///
+/// Returns an error if fewer than `threshold` shares are given, or if two shares
+/// have the same input.
+pub fn recover_embedded_secret(
+    shares: &[SecretShare],
+    threshold: usize,
+    slot: MaybeScalar,
+) -> Result<MaybeScalar, Error> {
+    if shares.len() < threshold {
+        return Err(Error::NotEnoughShares {
+            threshold,
+            count: shares.len(),
+        });
+    }
+    check_duplicate_inputs(shares)?;
+    try_interpolate(&shares[..threshold], slot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Evaluation, Polynomial};
+
+    #[test]
+    fn test_embedded_secrets() {
+        let secrets = vec![
+            Evaluation::new(MaybeScalar::Zero, MaybeScalar::from(1111)),
+            Evaluation::new(MaybeScalar::from(1000), MaybeScalar::from(2222)),
+            Evaluation::new(MaybeScalar::from(2000), MaybeScalar::from(3333)),
+        ];
+        let padding = vec![MaybeScalar::from(77), MaybeScalar::from(88)];
+        let embedded = EmbeddedSecrets::new(secrets.clone(), padding).unwrap();
+        assert_eq!(embedded.threshold(), 5);
+        for secret in secrets.iter() {
+            assert_eq!(embedded.polynomial().evaluate(secret.input), secret.output);
+        }
+
+        let xs: Vec<MaybeScalar> = (1..=7).map(MaybeScalar::from).collect();
+        let shares = embedded.issue_shares(&xs).unwrap();
+        for secret in secrets.iter() {
+            assert_eq!(
+                recover_embedded_secret(&shares[2..], 5, secret.input),
+                Ok(secret.output)
+            );
+        }
+        assert_eq!(
+            recover_embedded_secret(&shares[..4], 5, MaybeScalar::Zero),
+            Err(Error::NotEnoughShares {
+                threshold: 5,
+                count: 4
+            })
+        );
+
+        assert_eq!(
+            embedded.issue_shares(&[MaybeScalar::from(3), MaybeScalar::from(1000)]),
+            Err(Error::ReservedInput { index: 1 })
+        );
+        assert_eq!(
+            EmbeddedSecrets::new(vec![secrets[0], secrets[0]], vec![]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 1
+            })
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_embedded_secrets_random_threshold() {
+        let secrets = vec![
+            Evaluation::new(MaybeScalar::Zero, MaybeScalar::from(1111)),
+            Evaluation::new(MaybeScalar::from(1000), MaybeScalar::from(2222)),
+        ];
+        assert_eq!(
+            EmbeddedSecrets::random(secrets.clone(), 1, &mut rand::rngs::OsRng),
+            Err(Error::NotEnoughShares {
+                threshold: 2,
+                count: 1
+            })
+        );
+        let embedded = EmbeddedSecrets::random(secrets, 3, &mut rand::rngs::OsRng).unwrap();
+        assert_eq!(embedded.threshold(), 3);
+    }
+}
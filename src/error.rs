@@ -0,0 +1,77 @@
+/// Errors returned by the fallible counterparts of operations which would otherwise
+/// panic on malformed input, such as [`try_interpolate`][crate::try_interpolate].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The evaluations at indices `first` and `second` have the same input, so no
+    /// unique polynomial passes through them.
+    DuplicateInput { first: usize, second: usize },
+
+    /// The evaluation at `index` has a zero input, which is not a valid share.
+    ZeroInput { index: usize },
+
+    /// The evaluation at `index` has an output of a different length from the others.
+    LengthMismatch { index: usize },
+
+    /// Only `count` evaluations were given, but `threshold` are needed.
+    NotEnoughShares { threshold: usize, count: usize },
+
+    /// The input at `index` is reserved, such as for an embedded secret or for the
+    /// share being repaired, so it cannot be used there.
+    ReservedInput { index: usize },
+
+    /// `count` values were given, but exactly `expected` are needed.
+    WrongCount { expected: usize, count: usize },
+
+    /// An input which must belong to one of the given participants does not.
+    UnknownInput,
+
+    /// A division by zero was attempted.
+    DivisionByZero,
+
+    /// A polynomial has no coefficients or no evaluations.
+    Empty,
+
+    /// An input exceeded the default [`Limits`][crate::Limits], or the limits given
+    /// to a `_limited` function.
+    LimitExceeded(crate::LimitExceeded),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::DuplicateInput { first, second } => write!(
+                f,
+                "evaluations {} and {} have the same input",
+                first, second
+            ),
+            Error::ZeroInput { index } => write!(f, "evaluation {} has a zero input", index),
+            Error::LengthMismatch { index } => write!(
+                f,
+                "evaluation {} has an output of a different length",
+                index
+            ),
+            Error::NotEnoughShares { threshold, count } => write!(
+                f,
+                "{} shares are needed, but only {} were given",
+                threshold, count
+            ),
+            Error::ReservedInput { index } => {
+                write!(f, "input {} is reserved", index)
+            }
+            Error::WrongCount { expected, count } => {
+                write!(
+                    f,
+                    "{} values are needed, but {} were given",
+                    expected, count
+                )
+            }
+            Error::UnknownInput => write!(f, "input is not one of the participants"),
+            Error::DivisionByZero => write!(f, "division by zero"),
+            Error::Empty => write!(f, "polynomial is empty"),
+            Error::LimitExceeded(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
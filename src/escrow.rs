@@ -0,0 +1,180 @@
+//! Escrow shares: an extra share of a group's secret, issued to a third-party escrow
+//! agent and encrypted to the agent's public key.
+//!
+//! An escrow share is an ordinary share at an input reserved for the agent, so the
+//! agent cannot use it alone unless the threshold is one. Alongside the ciphertext,
+//! the dealer publishes a binding point `s_e * A`, where `s_e` is the escrow share
+//! output and `A` the agent's public key, with a [`DleqProof`] that it uses the same
+//! scalar as the verification share `s_e * G` derived from the group's
+//! [`VssCommitment`]. Anyone can check that proof with [`EscrowShare::verify`], and
+//! the agent checks after decrypting with [`EscrowShare::open`] that the share
+//! matches both the commitment and the binding point, so neither side has to trust
+//! the other blindly.
+
+use crate::dleq::DleqProof;
+use crate::ecies::{decrypt_share, encrypt_share, EciesError};
+use crate::{SecretShare, SecretSharingPolynomial, VssCommitment};
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar, Point, Scalar};
+
+/// Errors which can occur when opening an [`EscrowShare`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowError {
+    /// The ciphertext could not be decrypted with the agent's key.
+    Decryption(EciesError),
+
+    /// The decrypted share is issued at a different input from the escrow share.
+    WrongInput,
+
+    /// The decrypted share is not consistent with the group commitment, or with the
+    /// published binding point.
+    Inconsistent,
+}
+
+impl core::fmt::Display for EscrowError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EscrowError::Decryption(e) => e.fmt(f),
+            EscrowError::WrongInput => write!(f, "escrow share has the wrong input"),
+            EscrowError::Inconsistent => {
+                write!(
+                    f,
+                    "escrow share is not consistent with the group commitment"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EscrowError {}
+
+/// A share encrypted to an escrow agent, with a public proof of its consistency with
+/// the group commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowShare {
+    /// The input reserved for the escrow agent.
+    pub input: MaybeScalar,
+
+    /// The escrow agent's public key `A`.
+    pub agent: Point,
+
+    /// The share, encrypted to the agent with [`encrypt_share`].
+    pub ciphertext: Vec<u8>,
+
+    /// The binding point `s_e * A`.
+    pub binding: MaybePoint,
+
+    /// A proof that the binding point and the verification share `s_e * G` share
+    /// the same discrete log.
+    pub proof: DleqProof,
+}
+
+impl EscrowShare {
+    /// Issue the share of `polynomial` at `input` to the escrow agent with public key
+    /// `agent`. The ephemeral key must be uniformly random and never reused.
+    ///
+    /// The input must not be given to any other shareholder.
+    pub fn issue(
+        polynomial: &SecretSharingPolynomial,
+        input: MaybeScalar,
+        agent: Point,
+        ephemeral_secret: Scalar,
+    ) -> Self {
+        let share = polynomial.issue_share(input);
+        let agent_point = MaybePoint::from(agent);
+        EscrowShare {
+            input,
+            agent,
+            ciphertext: encrypt_share(&share, agent, ephemeral_secret),
+            binding: share.output * agent_point,
+            proof: DleqProof::prove(
+                share.output,
+                MaybePoint::from(Point::generator()),
+                agent_point,
+            ),
+        }
+    }
+
+    /// Check, without decrypting, that the escrow share is bound to the agent's key
+    /// and to a share of the group with the given commitment.
+    pub fn verify(&self, commitment: &VssCommitment) -> bool {
+        !self.input.is_zero()
+            && self.proof.verify(
+                MaybePoint::from(Point::generator()),
+                commitment.verification_share(self.input).output,
+                MaybePoint::from(self.agent),
+                self.binding,
+            )
+    }
+
+    /// Decrypt the escrow share with the agent's secret key, and check that it is the
+    /// share at the escrow input, consistent with `commitment` and the binding point.
+    pub fn open(
+        &self,
+        agent_secret: Scalar,
+        commitment: &VssCommitment,
+    ) -> Result<SecretShare, EscrowError> {
+        let share =
+            decrypt_share(agent_secret, &self.ciphertext).map_err(EscrowError::Decryption)?;
+        if share.input != self.input {
+            return Err(EscrowError::WrongInput);
+        }
+        if !commitment.verify_share(&share) || share.output * self.agent != self.binding {
+            return Err(EscrowError::Inconsistent);
+        }
+        Ok(share)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secp::G;
+
+    #[test]
+    fn test_escrow_share() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(31), MaybeScalar::from(4)]);
+        let commitment = f.commitment();
+        let agent_secret = Scalar::try_from(0xA6E7).unwrap();
+        let ephemeral = Scalar::try_from(0xE7E7).unwrap();
+        let input = MaybeScalar::from(100);
+
+        let escrow = EscrowShare::issue(&f, input, agent_secret * G, ephemeral);
+        assert!(escrow.verify(&commitment));
+        assert_eq!(
+            escrow.open(agent_secret, &commitment),
+            Ok(f.issue_share(input))
+        );
+
+        let other = SecretSharingPolynomial::new(vec![MaybeScalar::from(31), MaybeScalar::from(5)]);
+        assert!(!escrow.verify(&other.commitment()));
+        assert_eq!(
+            escrow.open(agent_secret, &other.commitment()),
+            Err(EscrowError::Inconsistent)
+        );
+
+        let wrong_secret = Scalar::try_from(0xBAD).unwrap();
+        assert_eq!(
+            escrow.open(wrong_secret, &commitment),
+            Err(EscrowError::Decryption(EciesError::DecryptionFailed))
+        );
+
+        // A dealer cannot encrypt a bogus share behind an honest proof.
+        let mut swapped = escrow.clone();
+        swapped.ciphertext = encrypt_share(&other.issue_share(input), agent_secret * G, ephemeral);
+        assert!(swapped.verify(&commitment));
+        assert_eq!(
+            swapped.open(agent_secret, &commitment),
+            Err(EscrowError::Inconsistent)
+        );
+
+        let mut moved = escrow.clone();
+        moved.input = MaybeScalar::from(101);
+        assert!(!moved.verify(&commitment));
+        assert_eq!(
+            moved.open(agent_secret, &commitment),
+            Err(EscrowError::WrongInput)
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Conversions to and from the key material of the
+//! [`frost-secp256k1`](https://docs.rs/frost-secp256k1) crate.
+//!
+//! A qudoku group and a FROST group are the same thing: shares of a polynomial `f(x)`
+//! over the secp256k1 scalar field, with a Feldman commitment to `f(x) * G`. FROST
+//! identifiers are the share inputs `x_i`, and the FROST group verifying key is the
+//! group public key `f(0) * G`. These conversions let one t-of-n group be used both
+//! for FROST signing and for qudoku nested secrets.
+//!
+//! FROST does not permit zero identifiers or zero shares, so conversions from qudoku
+//! types are fallible.
+
+use crate::{Evaluation, PointShare, PointSharingPolynomial, SecretShare, VssCommitment};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar};
+
+use frost_secp256k1::keys::{
+    KeyPackage, PublicKeyPackage, SigningShare, VerifiableSecretSharingCommitment, VerifyingShare,
+};
+use frost_secp256k1::{Error, Identifier, VerifyingKey};
+
+fn to_identifier(x: MaybeScalar) -> Result<Identifier, Error> {
+    Identifier::deserialize(&x.serialize())
+}
+
+fn from_identifier(identifier: &Identifier) -> MaybeScalar {
+    MaybeScalar::from_slice(&identifier.serialize())
+        .expect("FROST identifiers are always valid scalars")
+}
+
+fn to_verifying_share(point: MaybePoint) -> Result<VerifyingShare, Error> {
+    VerifyingShare::deserialize(&point.serialize())
+}
+
+fn to_commitment(commitment: &VssCommitment) -> Result<VerifiableSecretSharingCommitment, Error> {
+    VerifiableSecretSharingCommitment::deserialize(
+        commitment.coefficients().iter().map(|c| c.serialize()),
+    )
+}
+
+fn from_commitment(commitment: &VerifiableSecretSharingCommitment) -> VssCommitment {
+    let coefficients = commitment
+        .serialize()
+        .expect("FROST commitments never contain the identity point")
+        .iter()
+        .map(|c| {
+            MaybePoint::from_slice(c).expect("FROST commitments contain valid compressed points")
+        })
+        .collect();
+    VssCommitment::new(PointSharingPolynomial::new(coefficients))
+}
+
+/// Convert a secret share and the dealer's commitment into a FROST secret share,
+/// as would be sent to the shareholder by a FROST trusted dealer.
+pub fn to_frost_secret_share(
+    share: &SecretShare,
+    commitment: &VssCommitment,
+) -> Result<frost_secp256k1::keys::SecretShare, Error> {
+    Ok(frost_secp256k1::keys::SecretShare::new(
+        to_identifier(share.input)?,
+        SigningShare::deserialize(&share.output.serialize())?,
+        to_commitment(commitment)?,
+    ))
+}
+
+/// Convert a FROST secret share into a secret share and the dealer's commitment.
+pub fn from_frost_secret_share(
+    share: &frost_secp256k1::keys::SecretShare,
+) -> (SecretShare, VssCommitment) {
+    let output = MaybeScalar::from_slice(&share.signing_share().serialize())
+        .expect("FROST signing shares are always valid scalars");
+    let share_out = Evaluation::new(from_identifier(share.identifier()), output);
+    (share_out, from_commitment(share.commitment()))
+}
+
+/// Convert a secret share into a FROST key package, which a shareholder uses to sign.
+/// The share is verified against the commitment first.
+pub fn to_frost_key_package(
+    share: &SecretShare,
+    commitment: &VssCommitment,
+) -> Result<KeyPackage, Error> {
+    KeyPackage::try_from(to_frost_secret_share(share, commitment)?)
+}
+
+/// Convert a FROST key package into a secret share.
+pub fn from_frost_key_package(key_package: &KeyPackage) -> SecretShare {
+    let output = MaybeScalar::from_slice(&key_package.signing_share().serialize())
+        .expect("FROST signing shares are always valid scalars");
+    Evaluation::new(from_identifier(key_package.identifier()), output)
+}
+
+/// Build the FROST public key package for the shareholders at the given inputs, from the
+/// dealer's commitment.
+pub fn to_frost_public_key_package(
+    commitment: &VssCommitment,
+    inputs: &[MaybeScalar],
+) -> Result<PublicKeyPackage, Error> {
+    let verifying_shares = inputs
+        .iter()
+        .map(|&x| {
+            let verification_share = commitment.verification_share(x);
+            Ok((
+                to_identifier(x)?,
+                to_verifying_share(verification_share.output)?,
+            ))
+        })
+        .collect::<Result<BTreeMap<_, _>, Error>>()?;
+    let verifying_key = VerifyingKey::deserialize(&commitment.public_key().serialize())?;
+    Ok(PublicKeyPackage::new(verifying_shares, verifying_key))
+}
+
+/// Convert a FROST public key package into the group public key and the verification
+/// share of each shareholder.
+pub fn from_frost_public_key_package(
+    public_key_package: &PublicKeyPackage,
+) -> (MaybePoint, Vec<PointShare>) {
+    let public_key = public_key_package
+        .verifying_key()
+        .serialize()
+        .ok()
+        .and_then(|bytes| MaybePoint::from_slice(&bytes).ok())
+        .expect("FROST verifying keys are valid points");
+    let verification_shares = public_key_package
+        .verifying_shares()
+        .iter()
+        .map(|(identifier, share)| {
+            let point = share
+                .serialize()
+                .ok()
+                .and_then(|bytes| MaybePoint::from_slice(&bytes).ok())
+                .expect("FROST verifying shares are valid points");
+            Evaluation::new(from_identifier(identifier), point)
+        })
+        .collect();
+    (public_key, verification_shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+    use secp::G;
+
+    #[test]
+    fn test_frost_conversion() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(1234),
+            MaybeScalar::from(5678),
+            MaybeScalar::from(91011),
+        ]);
+        let commitment = f.commitment();
+        let inputs: Vec<MaybeScalar> = (1..=4).map(MaybeScalar::from).collect();
+
+        for &x in &inputs {
+            let share = f.issue_share(x);
+            let frost_share = to_frost_secret_share(&share, &commitment).unwrap();
+            assert_eq!(
+                from_frost_secret_share(&frost_share),
+                (share, commitment.clone())
+            );
+
+            let key_package = to_frost_key_package(&share, &commitment).unwrap();
+            assert_eq!(*key_package.min_signers(), 3);
+            assert_eq!(from_frost_key_package(&key_package), share);
+        }
+
+        let bad_share = Evaluation::new(MaybeScalar::from(1), MaybeScalar::from(1));
+        assert!(to_frost_key_package(&bad_share, &commitment).is_err());
+
+        let public_key_package = to_frost_public_key_package(&commitment, &inputs).unwrap();
+        let (public_key, verification_shares) = from_frost_public_key_package(&public_key_package);
+        assert_eq!(public_key, MaybeScalar::from(1234) * G);
+        for share in verification_shares {
+            assert_eq!(share.output, f.issue_share(share.input).output * G);
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{
+    tagged_hash, Evaluation, InputSequence, LagrangePolynomial, Polynomial, StandardFormPolynomial,
+    UnsafeDiv,
+};
+use core::ops::{Add, Mul, Neg, Sub};
+use ff::{Field, PrimeField};
+use group::{Group, GroupEncoding};
+
+/// An element of an arbitrary prime-order scalar field implementing [`ff::Field`].
+///
+/// This wrapper lets the polynomial types in this crate be used over any curve
+/// backend, in place of the secp256k1 [`MaybeScalar`][secp::MaybeScalar].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FieldScalar<F>(pub F);
+
+/// An element of an arbitrary prime-order group implementing [`group::Group`].
+///
+/// This wrapper lets the polynomial types in this crate be used over any curve
+/// backend, in place of the secp256k1 [`MaybePoint`][secp::MaybePoint].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GroupPoint<G>(pub G);
+
+/// A secret share over an arbitrary scalar field.
+pub type GenericSecretShare<F> = Evaluation<FieldScalar<F>, FieldScalar<F>>;
+
+/// A point share over an arbitrary group.
+pub type GenericPointShare<G> = Evaluation<FieldScalar<<G as Group>::Scalar>, GroupPoint<G>>;
+
+/// A secret sharing polynomial over an arbitrary scalar field.
+pub type GenericSecretSharingPolynomial<F> = StandardFormPolynomial<FieldScalar<F>>;
+
+/// A point sharing polynomial over an arbitrary group.
+pub type GenericPointSharingPolynomial<G> = StandardFormPolynomial<GroupPoint<G>>;
+
+/// A secret polynomial interpolated from shares over an arbitrary scalar field.
+pub type GenericInterpolatedSecretPolynomial<F> =
+    LagrangePolynomial<FieldScalar<F>, FieldScalar<F>>;
+
+/// A point polynomial interpolated from point shares over an arbitrary group.
+pub type GenericInterpolatedPointPolynomial<G> =
+    LagrangePolynomial<FieldScalar<<G as Group>::Scalar>, GroupPoint<G>>;
+
+impl<F: Field> Add for FieldScalar<F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        FieldScalar(self.0 + rhs.0)
+    }
+}
+
+impl<F: Field> Sub for FieldScalar<F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        FieldScalar(self.0 - rhs.0)
+    }
+}
+
+impl<F: Field> Mul for FieldScalar<F> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        FieldScalar(self.0 * rhs.0)
+    }
+}
+
+impl<F: Field> Neg for FieldScalar<F> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        FieldScalar(-self.0)
+    }
+}
+
+impl<F: Field> num_traits::Zero for FieldScalar<F> {
+    fn zero() -> Self {
+        FieldScalar(F::ZERO)
+    }
+    fn is_zero(&self) -> bool {
+        self.0.is_zero_vartime()
+    }
+}
+
+impl<F: Field> num_traits::One for FieldScalar<F> {
+    fn one() -> Self {
+        FieldScalar(F::ONE)
+    }
+}
+
+/// Enumerates the integers `0, 1, 2, ...` as field elements. Fields with a modulus
+/// of 64 bits or fewer end once the integers would wrap around.
+impl<F: PrimeField> InputSequence for FieldScalar<F> {
+    fn nth_input(n: usize) -> Option<Self> {
+        let n = u64::try_from(n).ok()?;
+        if F::NUM_BITS <= 64 && n >> (F::NUM_BITS - 1) != 0 {
+            return None;
+        }
+        Some(FieldScalar(F::from(n)))
+    }
+}
+
+impl<F: Field> UnsafeDiv<FieldScalar<F>> for FieldScalar<F> {
+    type Output = Self;
+    const FIELD: bool = true;
+
+    fn unsafe_div(num: Self, denom: Self) -> Self {
+        let inverse = Option::<F>::from(denom.0.invert()).expect("divided by zero scalar");
+        FieldScalar(num.0 * inverse)
+    }
+}
+
+impl<G: Group> Add for GroupPoint<G> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        GroupPoint(self.0 + rhs.0)
+    }
+}
+
+impl<G: Group> Sub for GroupPoint<G> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        GroupPoint(self.0 - rhs.0)
+    }
+}
+
+impl<G: Group> Mul<FieldScalar<G::Scalar>> for GroupPoint<G> {
+    type Output = Self;
+    fn mul(self, rhs: FieldScalar<G::Scalar>) -> Self {
+        GroupPoint(self.0 * rhs.0)
+    }
+}
+
+impl<G: Group> num_traits::Zero for GroupPoint<G> {
+    fn zero() -> Self {
+        GroupPoint(G::identity())
+    }
+    fn is_zero(&self) -> bool {
+        self.0.is_identity().into()
+    }
+}
+
+/// Allows multiplying a secret sharing polynomial by a fixed point `Q`, producing the
+/// point sharing polynomial `Q * f(x)`.
+impl<G: Group> Mul<&GenericSecretSharingPolynomial<G::Scalar>> for GroupPoint<G> {
+    type Output = GenericPointSharingPolynomial<G>;
+
+    fn mul(self, rhs: &GenericSecretSharingPolynomial<G::Scalar>) -> Self::Output {
+        StandardFormPolynomial::new(rhs.coefficients.iter().map(|&c| self * c).collect())
+    }
+}
+
+/// Allows multiplying a secret share by a fixed point `Q`, producing a point share.
+impl<G: Group> Mul<&GenericSecretShare<G::Scalar>> for GroupPoint<G> {
+    type Output = GenericPointShare<G>;
+
+    fn mul(self, rhs: &GenericSecretShare<G::Scalar>) -> Self::Output {
+        Evaluation::new(rhs.input, self * rhs.output)
+    }
+}
+
+impl<F: Field> GenericSecretSharingPolynomial<F> {
+    /// Issue a share at the given input `x`.
+    pub fn issue_share(&self, x: FieldScalar<F>) -> GenericSecretShare<F> {
+        Evaluation::new(x, self.evaluate(x))
+    }
+}
+
+macro_rules! impl_derive_generic_secret {
+    ( $t:ty ) => {
+        impl<G: Group + GroupEncoding> $t {
+            /// Derive a secret `c` by computing a [`tagged_hash`] of the encoded output
+            /// point produced by evaluating the polynomial on `x`, under the given domain
+            /// or application `tag`.
+            pub fn derive_secret_tagged(&self, tag: &[u8], x: FieldScalar<G::Scalar>) -> [u8; 32] {
+                tagged_hash(tag, self.evaluate(x).0.to_bytes().as_ref())
+            }
+        }
+    };
+}
+
+impl_derive_generic_secret! { GenericPointSharingPolynomial<G> }
+impl_derive_generic_secret! { GenericInterpolatedPointPolynomial<G> }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::{ProjectivePoint, Scalar};
+
+    #[test]
+    fn test_generic_nested_secret() {
+        let f = GenericSecretSharingPolynomial::new(vec![
+            FieldScalar(Scalar::from(1234u64)),
+            FieldScalar(Scalar::from(5678u64)),
+        ]);
+        let q = GroupPoint(ProjectivePoint::GENERATOR * Scalar::from(99u64));
+        let z = q * &f;
+
+        let shares: Vec<GenericPointShare<ProjectivePoint>> = (1..=2u64)
+            .map(|i| q * &f.issue_share(FieldScalar(Scalar::from(i))))
+            .collect();
+        let interpolated = GenericInterpolatedPointPolynomial::new(shares);
+
+        let x = FieldScalar(Scalar::from(7u64));
+        assert_eq!(interpolated.evaluate(x), z.evaluate(x));
+        assert_eq!(
+            interpolated.derive_secret_tagged(b"test", x),
+            z.derive_secret_tagged(b"test", x)
+        );
+
+        let secret: GenericInterpolatedSecretPolynomial<Scalar> = LagrangePolynomial::new(
+            (5..=6u64)
+                .map(|i| f.issue_share(FieldScalar(Scalar::from(i))))
+                .collect(),
+        );
+        assert_eq!(
+            secret.evaluate(FieldScalar(Scalar::ZERO)),
+            FieldScalar(Scalar::from(1234u64))
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{InputSequence, UnsafeDiv};
+use core::ops::{Add, Mul, Sub};
+
+/// An element of the finite field `GF(2^8)`, using the AES reduction polynomial
+/// `x^8 + x^4 + x^3 + x + 1`.
+///
+/// Addition and subtraction are both XOR. Multiplication and inversion are
+/// performed without secret-dependent branches or table lookups.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Gf256(pub u8);
+
+impl Gf256 {
+    /// Compute the multiplicative inverse of this element, as `self^254`.
+    /// The inverse of zero is zero.
+    pub fn invert(self) -> Gf256 {
+        // 254 = 0b11111110
+        let mut out = Gf256(1);
+        let mut base = self;
+        for i in 0..8 {
+            let squared = base * base;
+            if i > 0 {
+                out = out * base;
+            }
+            base = squared;
+        }
+        out
+    }
+}
+
+impl Add for Gf256 {
+    type Output = Gf256;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Gf256) -> Gf256 {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+impl Sub for Gf256 {
+    type Output = Gf256;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Gf256) -> Gf256 {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+impl Mul for Gf256 {
+    type Output = Gf256;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Gf256) -> Gf256 {
+        let mut a = self.0;
+        let mut b = rhs.0;
+        let mut product = 0u8;
+        for _ in 0..8 {
+            product ^= a & (b & 1).wrapping_neg();
+            let carry = a >> 7;
+            a = (a << 1) ^ (0x1B & carry.wrapping_neg());
+            b >>= 1;
+        }
+        Gf256(product)
+    }
+}
+
+impl num_traits::Zero for Gf256 {
+    fn zero() -> Self {
+        Gf256(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl num_traits::One for Gf256 {
+    fn one() -> Self {
+        Gf256(1)
+    }
+}
+
+/// Enumerates the elements of the field by their byte representation.
+impl InputSequence for Gf256 {
+    fn nth_input(n: usize) -> Option<Self> {
+        u8::try_from(n).ok().map(Gf256)
+    }
+}
+
+impl UnsafeDiv<Gf256> for Gf256 {
+    type Output = Gf256;
+    const FIELD: bool = true;
+
+    fn unsafe_div(num: Gf256, denom: Gf256) -> Self::Output {
+        if denom.0 == 0 {
+            unreachable!("divided by zero field element");
+        }
+        num * denom.invert()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf256_arithmetic() {
+        // Worked example from FIPS-197, section 4.2.
+        assert_eq!(Gf256(0x57) * Gf256(0x83), Gf256(0xC1));
+        assert_eq!(Gf256(0x57) + Gf256(0x83), Gf256(0xD4));
+
+        assert_eq!(Gf256(0).invert(), Gf256(0));
+        for a in 1..=255u8 {
+            assert_eq!(Gf256(a) * Gf256(a).invert(), Gf256(1));
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Handing the dealer role over to a new dealer.
+//!
+//! The old dealer encrypts its polynomial to the new dealer's public key in a
+//! [`DealerHandoff`], which names the group's published [`VssCommitment`]. Each
+//! shareholder checks that the handoff is for the published commitment and
+//! acknowledges it. The new dealer [accepts][DealerHandoff::accept] the handoff only
+//! once every active roster entry has acknowledged it, and only if the decrypted
+//! polynomial is equivalent to the published commitment, i.e. it commits to exactly
+//! the same coefficients. Shares issued by the new dealer are then interchangeable
+//! with those issued by the old one.
+
+use crate::ecies::{decrypt, encrypt, EciesError};
+use crate::{
+    sha256, tagged_hash, Acknowledgement, CeremonyError, Roster, SecretSharingPolynomial,
+    VssCommitment,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use secp::{MaybeScalar, Point, Scalar};
+
+/// Errors which can occur when accepting a [`DealerHandoff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HandoffError {
+    /// The handoff names a different commitment from the published one.
+    WrongCommitment,
+
+    /// The shareholders have not all acknowledged the handoff.
+    Acknowledgement(CeremonyError),
+
+    /// The polynomial could not be decrypted with the new dealer's key.
+    Decryption(EciesError),
+
+    /// The decrypted polynomial is malformed, or does not match the published commitment.
+    NotEquivalent,
+}
+
+impl core::fmt::Display for HandoffError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HandoffError::WrongCommitment => {
+                write!(f, "handoff is not for the published commitment")
+            }
+            HandoffError::Acknowledgement(e) => e.fmt(f),
+            HandoffError::Decryption(e) => e.fmt(f),
+            HandoffError::NotEquivalent => {
+                write!(f, "polynomial does not match the published commitment")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HandoffError {}
+
+/// The old dealer's polynomial, encrypted to the new dealer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DealerHandoff {
+    /// The group's published commitment.
+    pub commitment: VssCommitment,
+
+    /// The new dealer's public key.
+    pub new_dealer: Point,
+
+    /// The polynomial's coefficients, encrypted to the new dealer.
+    pub ciphertext: Vec<u8>,
+}
+
+impl DealerHandoff {
+    /// Encrypt `polynomial` to the new dealer's public key. The ephemeral key must be
+    /// uniformly random and never reused.
+    pub fn new(
+        polynomial: &SecretSharingPolynomial,
+        new_dealer: Point,
+        ephemeral_secret: Scalar,
+    ) -> Self {
+        let mut plaintext = Vec::with_capacity(32 * polynomial.coefficients.len());
+        for c in polynomial.coefficients.iter() {
+            plaintext.extend_from_slice(&c.serialize());
+        }
+        DealerHandoff {
+            commitment: polynomial.commitment(),
+            new_dealer,
+            ciphertext: encrypt(new_dealer, ephemeral_secret, &plaintext),
+        }
+    }
+
+    /// Returns a digest binding the commitment, the new dealer's key and the
+    /// ciphertext, which shareholders acknowledge.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut preimage = [0u8; 32 + 33 + 32];
+        preimage[..32].copy_from_slice(&self.commitment.fingerprint());
+        preimage[32..65].copy_from_slice(&self.new_dealer.serialize());
+        preimage[65..].copy_from_slice(&sha256(&self.ciphertext));
+        tagged_hash(b"qudoku/handoff", &preimage)
+    }
+
+    /// Acknowledge the handoff as the shareholder `identity`, after checking that it
+    /// names the published commitment and the expected new dealer.
+    pub fn acknowledge(&self, identity: &str) -> Acknowledgement {
+        Acknowledgement {
+            identity: identity.to_string(),
+            digest: self.digest(),
+        }
+    }
+
+    /// Accept the handoff as the new dealer, returning the old dealer's polynomial.
+    ///
+    /// Checks, in order, that the handoff names the `published` commitment, that
+    /// every active entry of `roster` has acknowledged it, and that the decrypted
+    /// polynomial commits to exactly the published coefficients.
+    pub fn accept(
+        &self,
+        secret: Scalar,
+        published: &VssCommitment,
+        roster: &Roster,
+        acks: &[Acknowledgement],
+    ) -> Result<SecretSharingPolynomial, HandoffError> {
+        if &self.commitment != published {
+            return Err(HandoffError::WrongCommitment);
+        }
+
+        let digest = self.digest();
+        if let Some(ack) = acks.iter().find(|ack| ack.digest != digest) {
+            return Err(HandoffError::Acknowledgement(CeremonyError::WrongDigest(
+                ack.identity.clone(),
+            )));
+        }
+        let pending: Vec<String> = roster
+            .active()
+            .filter(|entry| !acks.iter().any(|ack| ack.identity == entry.identity))
+            .map(|entry| entry.identity.clone())
+            .collect();
+        if !pending.is_empty() {
+            return Err(HandoffError::Acknowledgement(
+                CeremonyError::MissingAcknowledgements(pending),
+            ));
+        }
+
+        let plaintext = decrypt(secret, &self.ciphertext).map_err(HandoffError::Decryption)?;
+        if plaintext.len() != 32 * published.threshold() {
+            return Err(HandoffError::NotEquivalent);
+        }
+        let coefficients = plaintext
+            .chunks_exact(32)
+            .map(MaybeScalar::from_slice)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| HandoffError::NotEquivalent)?;
+        let polynomial = SecretSharingPolynomial::new(coefficients);
+        if &polynomial.commitment() != published {
+            return Err(HandoffError::NotEquivalent);
+        }
+        Ok(polynomial)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secp::G;
+
+    #[test]
+    fn test_dealer_handoff() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(61), MaybeScalar::from(2)]);
+        let published = f.commitment();
+        let mut roster = Roster::new();
+        for name in ["alice", "bob"] {
+            roster.register(name).unwrap();
+        }
+        let new_dealer = Scalar::try_from(0xD1).unwrap();
+        let ephemeral = Scalar::try_from(0xE1).unwrap();
+
+        let handoff = DealerHandoff::new(&f, new_dealer * G, ephemeral);
+        let alice = handoff.acknowledge("alice");
+        let bob = handoff.acknowledge("bob");
+
+        assert_eq!(
+            handoff.accept(
+                new_dealer,
+                &published,
+                &roster,
+                core::slice::from_ref(&alice)
+            ),
+            Err(HandoffError::Acknowledgement(
+                CeremonyError::MissingAcknowledgements(vec!["bob".to_string()])
+            ))
+        );
+        let acks = [alice, bob];
+        assert_eq!(
+            handoff.accept(new_dealer, &published, &roster, &acks),
+            Ok(f.clone())
+        );
+
+        let other = SecretSharingPolynomial::new(vec![MaybeScalar::from(61), MaybeScalar::from(3)]);
+        assert_eq!(
+            handoff.accept(new_dealer, &other.commitment(), &roster, &acks),
+            Err(HandoffError::WrongCommitment)
+        );
+
+        // An old dealer who encrypts a different polynomial is caught, and the
+        // shareholders' acknowledgements do not carry over to the new ciphertext.
+        let mut swapped = handoff.clone();
+        swapped.ciphertext = DealerHandoff::new(&other, new_dealer * G, ephemeral).ciphertext;
+        assert_eq!(
+            swapped.accept(new_dealer, &published, &roster, &acks),
+            Err(HandoffError::Acknowledgement(CeremonyError::WrongDigest(
+                "alice".to_string()
+            )))
+        );
+        let acks = [swapped.acknowledge("alice"), swapped.acknowledge("bob")];
+        assert_eq!(
+            swapped.accept(new_dealer, &published, &roster, &acks),
+            Err(HandoffError::NotEquivalent)
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use secp::Point;
+use alloc::vec::Vec;
+use secp::{MaybeScalar, Point};
 use sha2::Digest as _;
 
 /// Compute the SHA256 hash of some input data.
@@ -6,9 +7,103 @@ pub(crate) fn sha256(input: &[u8]) -> [u8; 32] {
     sha2::Sha256::new().chain_update(input).finalize().into()
 }
 
+/// Compute the SHA256 hash of some input data, writing it into a caller-provided buffer.
+pub(crate) fn sha256_into(input: &[u8], out: &mut [u8; 32]) {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(input);
+    hasher.finalize_into(out.into());
+}
+
+/// Compute a [BIP-340] tagged hash of some input data: `sha256(sha256(tag) || sha256(tag) || input)`.
+///
+/// The tag separates hashes computed by different applications or protocols, so
+/// that a hash computed in one context can never collide with one from another.
+///
+/// [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+pub fn tagged_hash(tag: &[u8], input: &[u8]) -> [u8; 32] {
+    let tag_hash = sha256(tag);
+    sha2::Sha256::new()
+        .chain_update(tag_hash)
+        .chain_update(tag_hash)
+        .chain_update(input)
+        .finalize()
+        .into()
+}
+
+/// The value of `2^256 mod n`, where `n` is the secp256k1 curve order.
+const TWO_POW_256_MOD_N: [u8; 32] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0x45, 0x51, 0x23, 0x19, 0x50, 0xB7, 0x5F, 0xC4, 0x40, 0x2D, 0xA1, 0x73, 0x2F, 0xC9, 0xBE, 0xBF,
+];
+
+/// Interpret 64 bytes as a big-endian integer and reduce it modulo the curve order.
+/// Because the input is much wider than the order, the result is nearly unbiased.
+pub(crate) fn scalar_from_wide(wide: &[u8; 64]) -> MaybeScalar {
+    let mut hi = [0u8; 32];
+    let mut lo = [0u8; 32];
+    hi.copy_from_slice(&wide[..32]);
+    lo.copy_from_slice(&wide[32..]);
+
+    // hi * 2^256 + lo (mod n)
+    MaybeScalar::reduce_from(&hi) * MaybeScalar::reduce_from(&TWO_POW_256_MOD_N)
+        + MaybeScalar::reduce_from(&lo)
+}
+
+/// Hash some input data to a scalar, by reducing its 64-byte SHA512 hash modulo the
+/// curve order. Unlike reducing a 32-byte hash, the result is statistically
+/// indistinguishable from a uniformly random scalar.
+pub fn hash_to_scalar(input: &[u8]) -> MaybeScalar {
+    scalar_from_wide(&sha2::Sha512::new().chain_update(input).finalize().into())
+}
+
+/// Compute HMAC-SHA256 as specified in [RFC 2104](https://www.rfc-editor.org/rfc/rfc2104).
+pub(crate) fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    if key.len() > 64 {
+        block[..32].copy_from_slice(&sha256(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let ipad = block.map(|b| b ^ 0x36);
+    let opad = block.map(|b| b ^ 0x5C);
+    let inner = sha2::Sha256::new()
+        .chain_update(ipad)
+        .chain_update(message)
+        .finalize();
+    sha2::Sha256::new()
+        .chain_update(opad)
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+/// Derive `out_len` bytes of key material with HKDF-SHA256, as specified in
+/// [RFC 5869](https://www.rfc-editor.org/rfc/rfc5869).
+///
+/// Panics if `out_len` is greater than `255 * 32`.
+pub(crate) fn hkdf_sha256(ikm: &[u8], salt: &[u8], info: &[u8], out_len: usize) -> Vec<u8> {
+    assert!(out_len <= 255 * 32, "HKDF output length is too large");
+    let prk = hmac_sha256(salt, ikm);
+
+    let mut okm = Vec::with_capacity(out_len);
+    let mut t: Vec<u8> = Vec::new();
+    let mut counter = 1u8;
+    while okm.len() < out_len {
+        t.extend_from_slice(info);
+        t.push(counter);
+        let block = hmac_sha256(&prk, &t);
+        let take = (out_len - okm.len()).min(32);
+        okm.extend_from_slice(&block[..take]);
+        t = block.to_vec();
+        counter = counter.wrapping_add(1);
+    }
+    okm
+}
+
 /// Recursively increments a slice of bytes as if it were a big-endian integer.
 fn inc_slice_be(slice: &mut [u8]) {
-    if slice.len() == 0 {
+    if slice.is_empty() {
         return;
     }
     let last = slice.len() - 1;
@@ -23,9 +118,55 @@ fn inc_slice_be(slice: &mut [u8]) {
 /// Implements a secure hash-to-curve function **in non-constant time.**
 /// The output [`Point`] has no known discrete log relative to [`G`][secp::G],
 /// and its Y coordinate always has even-parity.
+///
+/// The number of iterations depends on the input, so its running time can leak
+/// information about it. Do not use this with secret-dependent inputs; use
+/// [`hash_to_point_rfc9380`] instead.
+///
+/// The input is not domain-separated. New applications should prefer
+/// [`hash_to_point_tagged`], so that their `Q` points can never coincide
+/// with those of another application.
+#[deprecated(note = "use hash_to_point_tagged")]
 pub fn hash_to_point(input: &[u8]) -> Point {
-    let mut h = sha256(input);
+    lift_hash_to_point(sha256(input))
+}
 
+/// Implements a secure hash-to-curve function **in non-constant time**, like
+/// [`hash_to_point`], but hashes the input with a [`tagged_hash`] under the given
+/// domain or application `tag`.
+pub fn hash_to_point_tagged(tag: &[u8], input: &[u8]) -> Point {
+    lift_hash_to_point(tagged_hash(tag, input))
+}
+
+/// Derive a nested `Q` point for a group from its public key `f(0) * G` and a
+/// label describing the point's purpose, using [`hash_to_point_tagged`].
+///
+/// Every participant can compute the same `Q` independently, and nobody knows its
+/// discrete log, so a dealer cannot choose a `Q` for which they can compute the
+/// nested secret without the group. Different groups and labels yield unrelated points.
+pub fn derive_q(group_pubkey: Point, label: &[u8]) -> Point {
+    let mut preimage = Vec::with_capacity(33 + label.len());
+    preimage.extend_from_slice(&group_pubkey.serialize());
+    preimage.extend_from_slice(label);
+    hash_to_point_tagged(b"qudoku/derive_q", &preimage)
+}
+
+/// Derive the nested `Q` point for `label` after `epoch` rotations. Epoch zero gives
+/// [`derive_q`] itself, and every later epoch gives an unrelated point, so a group can
+/// replace a nested secret without reissuing any shares.
+pub fn derive_rotated_q(group_pubkey: Point, label: &[u8], epoch: u32) -> Point {
+    if epoch == 0 {
+        return derive_q(group_pubkey, label);
+    }
+    let mut preimage = Vec::with_capacity(33 + 4 + label.len());
+    preimage.extend_from_slice(&group_pubkey.serialize());
+    preimage.extend_from_slice(&epoch.to_be_bytes());
+    preimage.extend_from_slice(label);
+    hash_to_point_tagged(b"qudoku/derive_q/rotated", &preimage)
+}
+
+/// Find the first valid X coordinate at or after `h`, and lift it to a point.
+fn lift_hash_to_point(mut h: [u8; 32]) -> Point {
     loop {
         if let Ok(point) = Point::lift_x(&h) {
             return point;
@@ -55,4 +196,55 @@ mod tests {
             assert_eq!(&s, &output);
         }
     }
+
+    #[test]
+    fn test_hash_to_scalar() {
+        assert_eq!(
+            hex::encode(hash_to_scalar(b"").serialize()),
+            "2212ebe6b10493a445ef09e6931a18558e359eab4ef00c7432738cd8e18efd59"
+        );
+        assert_eq!(
+            hex::encode(hash_to_scalar(b"hello world").serialize()),
+            "6d5941d66f8233e04b36b859d6b4af43cb7bf24fe1ca998692c4241a40cf3804"
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_hash_to_point_tagged() {
+        let q = hash_to_point_tagged(b"app/one", b"input");
+        assert_eq!(q, hash_to_point_tagged(b"app/one", b"input"));
+        assert_ne!(q, hash_to_point_tagged(b"app/two", b"input"));
+        assert_ne!(q, hash_to_point(b"input"));
+        assert!(q.has_even_y());
+    }
+
+    #[test]
+    fn test_derive_q() {
+        let pubkey = hash_to_point_tagged(b"qudoku/test", b"group");
+        let q = derive_q(pubkey, b"backup");
+        assert_eq!(q, derive_q(pubkey, b"backup"));
+        assert_ne!(q, derive_q(pubkey, b"payroll"));
+        assert_ne!(q, derive_q(Point::generator(), b"backup"));
+
+        assert_eq!(derive_rotated_q(pubkey, b"backup", 0), q);
+        let rotated = derive_rotated_q(pubkey, b"backup", 1);
+        assert_ne!(rotated, q);
+        assert_ne!(rotated, derive_rotated_q(pubkey, b"backup", 2));
+    }
+
+    #[test]
+    fn test_hkdf_sha256() {
+        // Test case 1 from RFC 5869.
+        let okm = hkdf_sha256(
+            &[0x0B; 22],
+            &hex::decode("000102030405060708090a0b0c").unwrap(),
+            &hex::decode("f0f1f2f3f4f5f6f7f8f9").unwrap(),
+            42,
+        );
+        assert_eq!(
+            hex::encode(okm),
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+        );
+    }
 }
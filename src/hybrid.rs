@@ -0,0 +1,163 @@
+use crate::{
+    horner_poly_evaluate, Evaluation, Gf256, InterpolatedSecretPolynomial, LagrangePolynomial,
+    Polynomial, SecretShare, SecretSharingPolynomial,
+};
+use alloc::{vec, vec::Vec};
+use secp::{errors::InvalidScalarBytes, MaybeScalar};
+use subtle::{ConstantTimeEq, CtOption};
+
+/// A share of the serialized primary secret `f(0)`, split bytewise using Shamir's
+/// scheme over `GF(2^8)`. The output holds one share byte for each of the 32 secret bytes.
+///
+/// Unlike [`SecretShare`]s, backup shares are information-theoretically secure and do
+/// not rely on the hardness of the discrete log problem.
+pub type BackupShare = Evaluation<Gf256, [u8; 32]>;
+
+/// A curve-based share and a backup share issued to a single shareholder at the same index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HybridShare {
+    /// The share of the secret-sharing polynomial, at input `x`.
+    pub share: SecretShare,
+
+    /// The symmetric backup share of the primary secret, at input `x`.
+    pub backup: BackupShare,
+}
+
+/// A secret-sharing polynomial dealt alongside a purely symmetric `GF(2^8)` sharing
+/// of the same primary secret, as a hedge against future breaks of the discrete log
+/// problem on secp256k1.
+///
+/// The backup sharing only covers the primary secret `f(0)`. Nested secrets derived
+/// from point shares are not recoverable through the backup path, but can be
+/// recomputed from the primary secret once it is recovered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HybridDealing {
+    pub polynomial: SecretSharingPolynomial,
+
+    /// The non-constant coefficients of the backup polynomials, in ascending order
+    /// of degree. Byte `j` of each coefficient belongs to the polynomial sharing
+    /// byte `j` of the serialized primary secret.
+    pub backup_coefficients: Vec<[u8; 32]>,
+}
+
+impl HybridDealing {
+    /// Construct a hybrid dealing from a secret-sharing polynomial and the random
+    /// higher-order coefficients of the backup polynomials.
+    ///
+    /// Panics if the number of backup coefficients does not match the number of
+    /// non-constant coefficients in `polynomial`, since both paths must have the
+    /// same threshold.
+    pub fn new(polynomial: SecretSharingPolynomial, backup_coefficients: Vec<[u8; 32]>) -> Self {
+        assert_eq!(
+            backup_coefficients.len() + 1,
+            polynomial.coefficients.len(),
+            "backup polynomials must have the same threshold as the secret-sharing polynomial"
+        );
+        HybridDealing {
+            polynomial,
+            backup_coefficients,
+        }
+    }
+
+    /// Sample random backup coefficients to accompany the given polynomial.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(
+        polynomial: SecretSharingPolynomial,
+        rng: &mut R,
+    ) -> Self {
+        let backup_coefficients = (1..polynomial.coefficients.len())
+            .map(|_| {
+                let mut coefficient = [0u8; 32];
+                rng.fill_bytes(&mut coefficient);
+                coefficient
+            })
+            .collect();
+        HybridDealing::new(polynomial, backup_coefficients)
+    }
+
+    /// Issue a hybrid share at the given non-zero index `x`.
+    ///
+    /// Panics if `x` is zero, as this would reveal the primary secret.
+    pub fn issue_share(&self, x: u8) -> HybridShare {
+        assert_ne!(x, 0, "cannot issue a hybrid share at index zero");
+
+        let secret = self.polynomial.coefficients[0].serialize();
+        let mut output = [0u8; 32];
+        let mut coefficients = vec![Gf256(0); self.backup_coefficients.len() + 1];
+        for j in 0..32 {
+            coefficients[0] = Gf256(secret[j]);
+            for (k, c) in self.backup_coefficients.iter().enumerate() {
+                coefficients[k + 1] = Gf256(c[j]);
+            }
+            output[j] = horner_poly_evaluate::<_, _, Gf256>(Gf256(x), &coefficients).0;
+        }
+
+        HybridShare {
+            share: self.polynomial.issue_share(MaybeScalar::from(x as u128)),
+            backup: Evaluation::new(Gf256(x), output),
+        }
+    }
+}
+
+/// Recover the primary secret from a quorum of backup shares, without relying on
+/// any curve arithmetic.
+///
+/// Returns an error if the recovered bytes are not a valid scalar, which indicates
+/// that the shares were corrupted or too few were given.
+pub fn recover_from_backup(backups: &[BackupShare]) -> Result<MaybeScalar, InvalidScalarBytes> {
+    let basis = LagrangePolynomial::new(backups.to_vec()).basis_at(Gf256(0));
+
+    let mut secret = [0u8; 32];
+    for (backup, l) in backups.iter().zip(basis) {
+        for (byte, &share_byte) in secret.iter_mut().zip(backup.output.iter()) {
+            *byte ^= (Gf256(share_byte) * l).0;
+        }
+    }
+    MaybeScalar::from_slice(&secret)
+}
+
+/// Recover the primary secret from a quorum of hybrid shares, requiring that the
+/// curve-based and backup paths agree. Returns `None` if they do not.
+pub fn recover_hybrid(shares: &[HybridShare]) -> Option<MaybeScalar> {
+    let backups: Vec<BackupShare> = shares.iter().map(|share| share.backup).collect();
+    let from_backup = recover_from_backup(&backups).ok()?;
+
+    let from_curve = InterpolatedSecretPolynomial::new(shares.iter().map(|s| s.share).collect())
+        .evaluate(MaybeScalar::Zero);
+
+    CtOption::new(from_curve, from_curve.ct_eq(&from_backup)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hybrid_dealing() {
+        let secret = MaybeScalar::from(0xDEADBEEF);
+        let polynomial = SecretSharingPolynomial::new(vec![
+            secret,
+            MaybeScalar::from(11),
+            MaybeScalar::from(22),
+        ]);
+        let dealing = HybridDealing::new(polynomial, vec![[0x5A; 32], [0xC3; 32]]);
+
+        let shares: Vec<HybridShare> = (1..=5).map(|i| dealing.issue_share(i)).collect();
+        for share in shares.iter() {
+            assert_eq!(
+                share.share.input,
+                MaybeScalar::from(share.backup.input.0 as u128)
+            );
+        }
+
+        let backups: Vec<BackupShare> = shares.iter().map(|s| s.backup).collect();
+        assert_eq!(recover_from_backup(&backups[..3]), Ok(secret));
+        assert_eq!(recover_from_backup(&backups[2..]), Ok(secret));
+        assert_eq!(recover_hybrid(&shares[1..4]), Some(secret));
+
+        // A corrupted backup share causes the two paths to disagree.
+        let mut corrupted = shares[..3].to_vec();
+        corrupted[0].backup.output[31] ^= 1;
+        assert_eq!(recover_hybrid(&corrupted), None);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{tagged_hash, InvalidShareString, LimitExceeded, Limits, SecretShare, VssCommitment};
+use alloc::string::String;
+
+/// Errors returned by [`ShareImporter`], each pointing at the first step of the
+/// import pipeline which rejected the share.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShareImportError {
+    /// The input contained nothing but whitespace.
+    Empty,
+
+    /// The raw input is longer than the default [`Limits`] allow.
+    LimitExceeded(LimitExceeded),
+
+    /// The input is not a canonical share string: it is not valid bech32m, has the
+    /// wrong prefix or checksum, or encodes a zero input or a scalar which is not
+    /// reduced modulo the curve order.
+    Malformed(InvalidShareString),
+
+    /// The group commitment does not have the fingerprint the importer was pinned to.
+    FingerprintMismatch { expected: [u8; 32], found: [u8; 32] },
+
+    /// The share is well-formed, but is not consistent with the group commitment,
+    /// so it belongs to a different group or has been corrupted.
+    Inconsistent,
+}
+
+impl core::fmt::Display for ShareImportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ShareImportError::Empty => write!(f, "share string is empty"),
+            ShareImportError::LimitExceeded(e) => e.fmt(f),
+            ShareImportError::Malformed(e) => write!(f, "malformed share string: {}", e),
+            ShareImportError::FingerprintMismatch { .. } => {
+                write!(
+                    f,
+                    "group commitment does not match the expected fingerprint"
+                )
+            }
+            ShareImportError::Inconsistent => {
+                write!(f, "share is not consistent with the group commitment")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShareImportError {}
+
+impl VssCommitment {
+    /// Returns a 32-byte fingerprint of the commitment, which identifies the group
+    /// and its threshold. Shareholders and coordinators can compare fingerprints out
+    /// of band to check that they hold the same commitment.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut preimage = alloc::vec::Vec::with_capacity(33 * self.threshold());
+        for c in self.coefficients() {
+            preimage.extend_from_slice(&c.serialize());
+        }
+        tagged_hash(b"qudoku/group/fingerprint", &preimage)
+    }
+}
+
+/// A share which has passed every step of a [`ShareImporter`], together with the
+/// fingerprint of the group commitment it was verified against.
+///
+/// The [`Display`][core::fmt::Display] form is the canonical lower-case bech32m
+/// encoding of the share, so re-exporting an imported share always gives the same
+/// string regardless of how it was transcribed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ShareEnvelope {
+    share: SecretShare,
+    fingerprint: [u8; 32],
+}
+
+impl ShareEnvelope {
+    /// Returns the verified share.
+    pub fn share(&self) -> &SecretShare {
+        &self.share
+    }
+
+    /// Returns the fingerprint of the group commitment the share was verified against.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.fingerprint
+    }
+}
+
+impl core::fmt::Debug for ShareEnvelope {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ShareEnvelope")
+            .field("input", &self.share.input)
+            .field("fingerprint", &self.fingerprint)
+            .finish_non_exhaustive()
+    }
+}
+
+impl core::fmt::Display for ShareEnvelope {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.share, f)
+    }
+}
+
+/// A guard for coordinators which accept share strings from shareholders, such as
+/// during a recovery ceremony.
+///
+/// Each share string is normalized by stripping all whitespace, so that shares
+/// transcribed in groups or across several lines are accepted, and case-folded, so
+/// that upper-case bech32m is accepted. Strings mixing cases are still rejected, as
+/// bech32m requires. The share is then decoded, which verifies the checksum and
+/// rejects non-canonical scalars, and finally verified against the group commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShareImporter {
+    commitment: VssCommitment,
+    fingerprint: [u8; 32],
+}
+
+impl ShareImporter {
+    /// Construct an importer which accepts shares of the group with the given commitment.
+    pub fn new(commitment: VssCommitment) -> Self {
+        let fingerprint = commitment.fingerprint();
+        ShareImporter {
+            commitment,
+            fingerprint,
+        }
+    }
+
+    /// Construct an importer like [`new`][Self::new], but first check that the
+    /// commitment has the expected fingerprint, such as one published by the dealer
+    /// or confirmed with the shareholders out of band.
+    pub fn pinned(commitment: VssCommitment, expected: [u8; 32]) -> Result<Self, ShareImportError> {
+        let importer = Self::new(commitment);
+        if importer.fingerprint != expected {
+            return Err(ShareImportError::FingerprintMismatch {
+                expected,
+                found: importer.fingerprint,
+            });
+        }
+        Ok(importer)
+    }
+
+    /// Returns the fingerprint of the group commitment.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.fingerprint
+    }
+
+    /// Run a share string through the import pipeline, returning the canonical
+    /// [`ShareEnvelope`] or the reason the share was rejected.
+    pub fn import(&self, s: &str) -> Result<ShareEnvelope, ShareImportError> {
+        Limits::default()
+            .check_message_size(s.len())
+            .map_err(ShareImportError::LimitExceeded)?;
+
+        let normalized: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if normalized.is_empty() {
+            return Err(ShareImportError::Empty);
+        }
+
+        let share: SecretShare = normalized.parse().map_err(ShareImportError::Malformed)?;
+        if !self.commitment.verify_share(&share) {
+            return Err(ShareImportError::Inconsistent);
+        }
+        Ok(ShareEnvelope {
+            share,
+            fingerprint: self.fingerprint,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bech32, Bech32Error, InvalidShareBytes, SecretSharingPolynomial};
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_share_import() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(42), MaybeScalar::from(7)]);
+        let commitment = f.commitment();
+        let share = f.issue_share(MaybeScalar::from(3));
+        let canonical = share.to_string();
+
+        let importer = ShareImporter::new(commitment.clone());
+        let envelope = importer.import(&canonical).unwrap();
+        assert_eq!(envelope.share(), &share);
+        assert_eq!(envelope.fingerprint(), commitment.fingerprint());
+        assert_eq!(envelope.to_string(), canonical);
+
+        // Whitespace anywhere and upper case are normalized away.
+        let (head, tail) = canonical.split_at(20);
+        let transcribed = format!("  {}\n {}\t", head.to_uppercase(), tail.to_uppercase());
+        assert_eq!(importer.import(&transcribed), Ok(envelope));
+        assert_eq!(importer.import(" \n"), Err(ShareImportError::Empty));
+
+        let mixed = format!("{}{}", head.to_uppercase(), tail);
+        assert_eq!(
+            importer.import(&mixed),
+            Err(ShareImportError::Malformed(InvalidShareString::Bech32(
+                Bech32Error::MixedCase
+            )))
+        );
+
+        let mut typo = canonical.clone().into_bytes();
+        let last = typo.len() - 1;
+        typo[last] = if typo[last] == b'q' { b'p' } else { b'q' };
+        assert_eq!(
+            importer.import(core::str::from_utf8(&typo).unwrap()),
+            Err(ShareImportError::Malformed(InvalidShareString::Bech32(
+                Bech32Error::InvalidChecksum
+            )))
+        );
+
+        // An output of n + 6 is not a canonical scalar encoding, even though it
+        // reduces to a valid one.
+        let mut bytes = share.to_bytes();
+        bytes[32..].copy_from_slice(&[0xFF; 32]);
+        assert_eq!(
+            importer.import(&bech32::encode(crate::SECRET_SHARE_HRP, &bytes)),
+            Err(ShareImportError::Malformed(InvalidShareString::Bytes(
+                InvalidShareBytes::Output
+            )))
+        );
+
+        let other = SecretSharingPolynomial::new(vec![MaybeScalar::from(42), MaybeScalar::from(8)]);
+        let foreign = other.issue_share(MaybeScalar::from(3)).to_string();
+        assert_eq!(
+            importer.import(&foreign),
+            Err(ShareImportError::Inconsistent)
+        );
+
+        assert!(ShareImporter::pinned(commitment.clone(), commitment.fingerprint()).is_ok());
+        assert_eq!(
+            ShareImporter::pinned(commitment.clone(), other.commitment().fingerprint()),
+            Err(ShareImportError::FingerprintMismatch {
+                expected: other.commitment().fingerprint(),
+                found: commitment.fingerprint(),
+            })
+        );
+    }
+}
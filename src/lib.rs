@@ -6,17 +6,121 @@
 //! See [the README](https://github.com/conduition/qudoku) for a general
 //! description of what this package does.
 #![doc = include_str!("../USAGE.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+#[cfg(feature = "aead")]
+mod aead;
+mod bech32;
+mod bytes;
+mod commit_reveal;
+#[cfg(feature = "ct-eq")]
+mod constant_time;
+mod dealer;
+mod decoy;
+mod deniable;
+mod distributed_q;
+mod dry_run;
+mod embedding;
+mod error;
+#[cfg(feature = "group")]
+mod generic;
+mod gf256;
+mod hash_to_curve;
 mod hashing;
+mod hybrid;
+mod import;
+mod limits;
+mod lint;
+mod merkle;
+mod nested;
 mod ops;
+mod parallel;
+mod policy;
 mod polynomials;
+#[cfg(feature = "bigint")]
+mod prime_field;
+mod repair;
+mod roster;
+mod rotation;
+mod scheme;
+mod secret;
+mod shareholder;
 mod sharing;
+mod signer;
+mod vault;
+mod vss;
+mod weighted;
+
+pub mod bridge;
+pub mod dkg;
+pub mod dlc;
+pub mod dleq;
+pub mod signing;
+pub mod silent_payments;
+pub mod vrf;
 
+#[cfg(feature = "aead")]
+pub mod board;
+#[cfg(feature = "aead")]
+pub mod cold_storage;
+#[cfg(feature = "aead")]
+pub mod ecies;
+#[cfg(feature = "aead")]
+pub mod escrow;
+#[cfg(feature = "frost")]
+pub mod frost;
+#[cfg(feature = "aead")]
+pub mod handoff;
+#[cfg(feature = "mlock")]
+mod memlock;
+#[cfg(feature = "zeroize")]
+mod zeroizing;
+
+pub use bech32::Bech32Error;
+pub use bytes::*;
+pub use commit_reveal::*;
+pub use dealer::*;
+pub use decoy::*;
+pub use deniable::*;
+pub use distributed_q::*;
+pub use dry_run::*;
+pub use embedding::*;
+pub use error::*;
+#[cfg(feature = "group")]
+pub use generic::*;
+pub use gf256::*;
+pub use hash_to_curve::*;
 pub use hashing::*;
+pub use hybrid::*;
+pub use import::*;
+pub use limits::*;
+pub use lint::*;
+pub use merkle::*;
+pub use nested::*;
+pub use policy::*;
 pub use polynomials::*;
+#[cfg(feature = "bigint")]
+pub use prime_field::*;
+pub use repair::*;
+pub use roster::*;
+pub use rotation::*;
+pub use scheme::*;
+pub use secret::*;
+pub use shareholder::*;
 pub use sharing::*;
+pub use signer::*;
+pub use vault::*;
+pub use vss::*;
+pub use weighted::*;
+
+#[cfg(feature = "mlock")]
+pub use memlock::*;
 
 // Re-Exports
+#[cfg(feature = "bigint")]
+pub use crypto_bigint;
 pub use secp;
 pub use sha2;
 
@@ -24,6 +128,6 @@ pub use sha2;
 pub fn random_coefficients<R: rand::RngCore + rand::CryptoRng>(
     rng: &mut R,
     n: usize,
-) -> Vec<secp::MaybeScalar> {
+) -> alloc::vec::Vec<secp::MaybeScalar> {
     (0..n).map(|_| secp::Scalar::random(rng).into()).collect()
 }
@@ -0,0 +1,375 @@
+use crate::{Evaluation, LagrangePolynomial, Roster, RosterError, StandardFormPolynomial};
+use alloc::vec::Vec;
+
+/// Bounds on the size of untrusted inputs, so that a service which accepts shares,
+/// polynomials, or messages from untrusted parties cannot be made to perform
+/// unbounded work. Interpolation is `O(n^2)` in the number of shares, so an
+/// unchecked set of shares is an easy denial-of-service vector.
+///
+/// The [`Default`] limits are generous enough for any realistic group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Limits {
+    /// The maximum degree of a polynomial, i.e. the maximum threshold minus one.
+    pub max_degree: usize,
+
+    /// The maximum number of shares in a single set, such as a set of evaluations
+    /// to interpolate or the entries of a [`Roster`].
+    pub max_shares: usize,
+
+    /// The maximum size in bytes of a message or encoded structure.
+    pub max_message_size: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_degree: 254,
+            max_shares: 255,
+            max_message_size: 1 << 16,
+        }
+    }
+}
+
+/// Returned when an input exceeds one of the configured [`Limits`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LimitExceeded {
+    /// A polynomial had a degree higher than `max_degree`.
+    Degree { degree: usize, max: usize },
+
+    /// A set contained more than `max_shares` shares.
+    Shares { count: usize, max: usize },
+
+    /// A message was longer than `max_message_size` bytes.
+    MessageSize { size: usize, max: usize },
+}
+
+impl core::fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LimitExceeded::Degree { degree, max } => {
+                write!(f, "polynomial degree {} exceeds limit of {}", degree, max)
+            }
+            LimitExceeded::Shares { count, max } => {
+                write!(f, "{} shares exceeds limit of {}", count, max)
+            }
+            LimitExceeded::MessageSize { size, max } => {
+                write!(f, "message of {} bytes exceeds limit of {}", size, max)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LimitExceeded {}
+
+impl Limits {
+    /// Check a polynomial degree against `max_degree`.
+    pub fn check_degree(&self, degree: usize) -> Result<(), LimitExceeded> {
+        if degree > self.max_degree {
+            return Err(LimitExceeded::Degree {
+                degree,
+                max: self.max_degree,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the size of a set of shares against `max_shares`.
+    pub fn check_shares(&self, count: usize) -> Result<(), LimitExceeded> {
+        if count > self.max_shares {
+            return Err(LimitExceeded::Shares {
+                count,
+                max: self.max_shares,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the size of a set of evaluations to interpolate against `max_shares`,
+    /// and the degree of the interpolated polynomial against `max_degree`.
+    pub fn check_evaluations(&self, count: usize) -> Result<(), LimitExceeded> {
+        self.check_shares(count)?;
+        self.check_degree(count.saturating_sub(1))
+    }
+
+    /// Check the length and declared entry count of a [`Roster`] encoding.
+    pub(crate) fn check_roster_encoding(&self, bytes: &[u8]) -> Result<(), LimitExceeded> {
+        self.check_message_size(bytes.len())?;
+        if bytes.len() >= 4 {
+            let count = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            self.check_shares(count as usize)?;
+        }
+        Ok(())
+    }
+
+    /// Check the length of a message against `max_message_size`.
+    pub fn check_message_size(&self, size: usize) -> Result<(), LimitExceeded> {
+        if size > self.max_message_size {
+            return Err(LimitExceeded::MessageSize {
+                size,
+                max: self.max_message_size,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<T> StandardFormPolynomial<T> {
+    /// Construct a polynomial like [`new`][Self::new], but first check that the
+    /// number of coefficients is within the given limits.
+    pub fn new_limited(coefficients: Vec<T>, limits: &Limits) -> Result<Self, LimitExceeded> {
+        limits.check_degree(coefficients.len().saturating_sub(1))?;
+        Ok(Self::new(coefficients))
+    }
+}
+
+impl<I, O> LagrangePolynomial<I, O> {
+    /// Construct a Lagrange polynomial like [`new`][Self::new], but first check that
+    /// the number of evaluations is within the given limits, both as a set of shares
+    /// and as the degree of the interpolated polynomial.
+    pub fn new_limited(
+        evaluations: Vec<Evaluation<I, O>>,
+        limits: &Limits,
+    ) -> Result<Self, LimitExceeded> {
+        limits.check_evaluations(evaluations.len())?;
+        Ok(LagrangePolynomial { evaluations })
+    }
+}
+
+/// An error from [`Roster::from_bytes_limited`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LimitedRosterError {
+    Limit(LimitExceeded),
+    Roster(RosterError),
+}
+
+impl core::fmt::Display for LimitedRosterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LimitedRosterError::Limit(e) => e.fmt(f),
+            LimitedRosterError::Roster(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LimitedRosterError {}
+
+impl Roster {
+    /// Parse a roster like [`from_bytes`][Self::from_bytes], but with the given
+    /// limits in place of the defaults. Encodings longer than `max_message_size` or
+    /// with more than `max_shares` entries are rejected before any further work.
+    pub fn from_bytes_limited(bytes: &[u8], limits: &Limits) -> Result<Self, LimitedRosterError> {
+        limits
+            .check_roster_encoding(bytes)
+            .map_err(LimitedRosterError::Limit)?;
+        Roster::parse(bytes).map_err(LimitedRosterError::Roster)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InterpolatedSecretPolynomial, SecretSharingPolynomial};
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_limits() {
+        let limits = Limits {
+            max_degree: 2,
+            max_shares: 3,
+            max_message_size: 64,
+        };
+
+        let coefficients = vec![MaybeScalar::from(1); 3];
+        assert!(SecretSharingPolynomial::new_limited(coefficients.clone(), &limits).is_ok());
+
+        let mut too_many = coefficients.clone();
+        too_many.push(MaybeScalar::from(1));
+        assert_eq!(
+            SecretSharingPolynomial::new_limited(too_many, &limits),
+            Err(LimitExceeded::Degree { degree: 3, max: 2 })
+        );
+
+        let f = SecretSharingPolynomial::new(coefficients);
+        let shares: Vec<_> = (1..=4)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+        assert!(InterpolatedSecretPolynomial::new_limited(shares[..3].to_vec(), &limits).is_ok());
+        assert_eq!(
+            InterpolatedSecretPolynomial::new_limited(shares, &limits).err(),
+            Some(LimitExceeded::Shares { count: 4, max: 3 })
+        );
+
+        let mut roster = Roster::new();
+        for name in ["a", "b", "c", "d"] {
+            roster.register(name).unwrap();
+        }
+        assert_eq!(
+            Roster::from_bytes_limited(&roster.serialize(), &limits),
+            Err(LimitedRosterError::Limit(LimitExceeded::MessageSize {
+                size: 4 + 4 * 36,
+                max: 64
+            }))
+        );
+
+        let roomy = Limits {
+            max_message_size: 1024,
+            ..limits
+        };
+        assert_eq!(
+            Roster::from_bytes_limited(&roster.serialize(), &roomy),
+            Err(LimitedRosterError::Limit(LimitExceeded::Shares {
+                count: 4,
+                max: 3
+            }))
+        );
+    }
+
+    #[test]
+    fn test_limited_interpolation() {
+        let limits = Limits {
+            max_degree: 2,
+            max_shares: 3,
+            ..Limits::default()
+        };
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(7), MaybeScalar::from(8)]);
+        let shares: Vec<_> = (1..=4)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+        let inputs: Vec<MaybeScalar> = shares.iter().map(|share| share.input).collect();
+        let exceeded = LimitExceeded::Shares { count: 4, max: 3 };
+        let secret = MaybeScalar::from(7);
+
+        assert_eq!(
+            crate::try_interpolate_limited(&shares[..3], MaybeScalar::Zero, &limits),
+            Ok(secret)
+        );
+        assert_eq!(
+            crate::try_interpolate_limited(&shares, MaybeScalar::Zero, &limits),
+            Err(crate::Error::LimitExceeded(exceeded))
+        );
+        assert_eq!(
+            InterpolatedSecretPolynomial::try_new_limited(shares.clone(), &limits).err(),
+            Some(crate::Error::LimitExceeded(exceeded))
+        );
+
+        assert_eq!(
+            crate::interpolate_at_zero_limited(&shares[..3], &limits),
+            Ok(secret)
+        );
+        assert_eq!(
+            crate::interpolate_at_zero_limited(&shares, &limits),
+            Err(crate::Error::LimitExceeded(exceeded))
+        );
+        assert_eq!(
+            crate::interpolate_at_zero_limited(&[shares[0], shares[1], shares[0]], &limits),
+            Err(crate::Error::DuplicateInput {
+                first: 0,
+                second: 2
+            })
+        );
+        let mut cache = crate::LagrangeCoefficientCache::new();
+        assert_eq!(
+            cache.interpolate_at_zero_limited(&shares, &limits),
+            Err(crate::Error::LimitExceeded(exceeded))
+        );
+        assert!(cache.is_empty());
+        assert_eq!(
+            cache.interpolate_at_zero_limited(&shares[1..], &limits),
+            Ok(secret)
+        );
+
+        assert_eq!(
+            crate::robust_interpolate_limited(&shares[..3], 2, &limits),
+            Ok(Some(f.clone()))
+        );
+        assert_eq!(
+            crate::robust_interpolate_limited(&shares, 2, &limits),
+            Err(exceeded)
+        );
+        assert_eq!(
+            crate::robust_interpolate_limited(&shares[..3], 4, &limits),
+            Err(LimitExceeded::Degree { degree: 3, max: 2 })
+        );
+        assert_eq!(
+            crate::interpolate_identifying_cheaters_limited(&shares, 2, &limits),
+            Err(crate::InconsistentShares::LimitExceeded(exceeded))
+        );
+
+        assert!(crate::LagrangeBasis::try_new_limited(inputs[..3].to_vec(), &limits).is_ok());
+        assert_eq!(
+            crate::LagrangeBasis::try_new_limited(inputs.clone(), &limits),
+            Err(crate::Error::LimitExceeded(exceeded))
+        );
+        assert!(matches!(
+            crate::BarycentricPolynomial::try_new_limited(shares.clone(), &limits),
+            Err(crate::Error::LimitExceeded(e)) if e == exceeded
+        ));
+        assert_eq!(
+            crate::SubproductTree::try_new_limited(inputs, &limits),
+            Err(crate::Error::LimitExceeded(exceeded))
+        );
+    }
+
+    #[test]
+    fn test_default_limits_enforced() {
+        let max = Limits::default().max_shares;
+        let shares: Vec<_> = (1..=max as u128 + 1)
+            .map(|i| Evaluation::new(MaybeScalar::from(i), MaybeScalar::from(i)))
+            .collect();
+        let exceeded = LimitExceeded::Shares {
+            count: max + 1,
+            max,
+        };
+
+        assert_eq!(
+            crate::try_interpolate(&shares, MaybeScalar::Zero),
+            Err(crate::Error::LimitExceeded(exceeded))
+        );
+        assert_eq!(
+            InterpolatedSecretPolynomial::try_new(shares.clone()).err(),
+            Some(crate::Error::LimitExceeded(exceeded))
+        );
+
+        // The unchecked constructor is used on trusted paths, so it must not panic.
+        assert_eq!(
+            InterpolatedSecretPolynomial::new(shares.clone()).evaluations,
+            shares
+        );
+
+        // Custom limits still allow larger sets.
+        let roomy = Limits {
+            max_shares: 1000,
+            max_degree: 1000,
+            ..Limits::default()
+        };
+        let many: Vec<_> = (1..=300u128)
+            .map(|i| Evaluation::new(MaybeScalar::from(i), MaybeScalar::from(i)))
+            .collect();
+        assert!(InterpolatedSecretPolynomial::new_limited(many, &roomy).is_ok());
+
+        let mut huge = 1000u32.to_be_bytes().to_vec();
+        huge.resize(Limits::default().max_message_size + 1, 0);
+        assert_eq!(
+            Roster::from_bytes(&huge),
+            Err(RosterError::LimitExceeded(LimitExceeded::MessageSize {
+                size: huge.len(),
+                max: Limits::default().max_message_size
+            }))
+        );
+        assert_eq!(
+            Roster::from_bytes(&1000u32.to_be_bytes()),
+            Err(RosterError::LimitExceeded(LimitExceeded::Shares {
+                count: 1000,
+                max
+            }))
+        );
+
+        let long = "q".repeat(Limits::default().max_message_size + 1);
+        assert!(matches!(
+            long.parse::<crate::SecretShare>(),
+            Err(crate::InvalidShareString::LimitExceeded(_))
+        ));
+    }
+}
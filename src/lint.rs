@@ -0,0 +1,212 @@
+use crate::{PointSharingPolynomial, SecretSharingPolynomial};
+use alloc::vec::Vec;
+use secp::{MaybeScalar, Point, G};
+
+/// A `Q` point used to derive a nested secret, along with the hash-to-curve input
+/// it was derived from, if one was recorded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QPointRecord {
+    /// The `Q` point.
+    pub point: Point,
+
+    /// The input to [`hash_to_point`][crate::hash_to_point] which produced `point`.
+    /// Without a recorded derivation, shareholders cannot check that nobody knows
+    /// the discrete log of `Q`, nor recompute `Q` if the record is lost.
+    pub derivation: Option<Vec<u8>>,
+}
+
+/// A suspicious property of a dealing, reported by [`lint_dealing`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DealingLint {
+    /// The primary secret `f(0)` is zero.
+    ZeroSecret,
+
+    /// A share is to be issued at input zero, which would reveal the primary secret.
+    ZeroInput,
+
+    /// A share is to be issued more than once at the same input.
+    DuplicateInput(MaybeScalar),
+
+    /// The polynomial's highest-order coefficients are zero, so fewer shares than
+    /// intended are enough to recover the secret.
+    ReducedThreshold {
+        /// The threshold implied by the number of coefficients.
+        declared: usize,
+        /// The number of shares actually needed to recover the secret.
+        effective: usize,
+    },
+
+    /// Fewer distinct shares are to be issued than are needed to recover the secret.
+    InsufficientShares {
+        /// The number of distinct non-zero inputs.
+        issued: usize,
+        /// The number of shares needed to recover the secret.
+        threshold: usize,
+    },
+
+    /// A `Q` point has no recorded derivation.
+    MissingQDerivation(Point),
+
+    /// A `Q` point does not match its recorded derivation.
+    MismatchedQDerivation(Point),
+
+    /// The published commitment is not `f(x) * G`.
+    InconsistentCommitment,
+}
+
+/// Check a dealing for suspicious configurations before any shares are distributed.
+///
+/// - `polynomial` is the dealer's secret-sharing polynomial.
+/// - `inputs` are the inputs at which shares will be issued.
+/// - `q_points` are the `Q` points used to derive nested secrets.
+/// - `commitment` is the point-sharing polynomial `f(x) * G`, if one will be published.
+///
+/// Returns every problem found. An empty list means no problems were detected.
+pub fn lint_dealing(
+    polynomial: &SecretSharingPolynomial,
+    inputs: &[MaybeScalar],
+    q_points: &[QPointRecord],
+    commitment: Option<&PointSharingPolynomial>,
+) -> Vec<DealingLint> {
+    let mut lints = Vec::new();
+
+    if matches!(
+        polynomial.coefficients.first(),
+        None | Some(MaybeScalar::Zero)
+    ) {
+        lints.push(DealingLint::ZeroSecret);
+    }
+
+    let mut distinct: Vec<MaybeScalar> = Vec::with_capacity(inputs.len());
+    for &x in inputs {
+        if x == MaybeScalar::Zero {
+            if !lints.contains(&DealingLint::ZeroInput) {
+                lints.push(DealingLint::ZeroInput);
+            }
+        } else if distinct.contains(&x) {
+            if !lints.contains(&DealingLint::DuplicateInput(x)) {
+                lints.push(DealingLint::DuplicateInput(x));
+            }
+        } else {
+            distinct.push(x);
+        }
+    }
+
+    let declared = polynomial.coefficients.len().max(1);
+    let effective = polynomial
+        .coefficients
+        .iter()
+        .rposition(|&c| c != MaybeScalar::Zero)
+        .map(|i| i + 1)
+        .unwrap_or(1);
+    if effective < declared {
+        lints.push(DealingLint::ReducedThreshold {
+            declared,
+            effective,
+        });
+    }
+    if distinct.len() < declared {
+        lints.push(DealingLint::InsufficientShares {
+            issued: distinct.len(),
+            threshold: declared,
+        });
+    }
+
+    for record in q_points {
+        match &record.derivation {
+            None => lints.push(DealingLint::MissingQDerivation(record.point)),
+            Some(input) => {
+                // Recorded derivations are inputs to the untagged hash, which existing
+                // Q points were derived with.
+                #[allow(deprecated)]
+                let derived = crate::hash_to_point(input);
+                if derived != record.point {
+                    lints.push(DealingLint::MismatchedQDerivation(record.point));
+                }
+            }
+        }
+    }
+
+    if let Some(commitment) = commitment {
+        if commitment != &(G * polynomial) {
+            lints.push(DealingLint::InconsistentCommitment);
+        }
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_lint_dealing() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(10),
+            MaybeScalar::from(20),
+            MaybeScalar::from(30),
+        ]);
+        let inputs: Vec<MaybeScalar> = (1..=5).map(MaybeScalar::from).collect();
+        let q = QPointRecord {
+            point: crate::hash_to_point(b"vault"),
+            derivation: Some(b"vault".to_vec()),
+        };
+        let commitment = G * &f;
+
+        assert_eq!(
+            lint_dealing(&f, &inputs, core::slice::from_ref(&q), Some(&commitment)),
+            vec![]
+        );
+
+        let bad_inputs = [
+            MaybeScalar::Zero,
+            MaybeScalar::from(1),
+            MaybeScalar::from(1),
+        ];
+        assert_eq!(
+            lint_dealing(&f, &bad_inputs, &[], None),
+            vec![
+                DealingLint::ZeroInput,
+                DealingLint::DuplicateInput(MaybeScalar::from(1)),
+                DealingLint::InsufficientShares {
+                    issued: 1,
+                    threshold: 3
+                },
+            ]
+        );
+
+        let reduced = SecretSharingPolynomial::new(vec![
+            MaybeScalar::Zero,
+            MaybeScalar::from(20),
+            MaybeScalar::Zero,
+        ]);
+        let missing = QPointRecord {
+            point: crate::hash_to_point(b"other"),
+            derivation: None,
+        };
+        let mismatched = QPointRecord {
+            point: crate::hash_to_point(b"other"),
+            derivation: Some(b"vault".to_vec()),
+        };
+        assert_eq!(
+            lint_dealing(
+                &reduced,
+                &inputs,
+                &[q, missing.clone(), mismatched.clone()],
+                Some(&commitment)
+            ),
+            vec![
+                DealingLint::ZeroSecret,
+                DealingLint::ReducedThreshold {
+                    declared: 3,
+                    effective: 2
+                },
+                DealingLint::MissingQDerivation(missing.point),
+                DealingLint::MismatchedQDerivation(mismatched.point),
+                DealingLint::InconsistentCommitment,
+            ]
+        );
+    }
+}
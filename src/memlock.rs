@@ -0,0 +1,311 @@
+use crate::{
+    horner_poly_evaluate, recover_secret, standard_form_degree, Error, Polynomial, SecretShare,
+};
+use secp::MaybeScalar;
+
+use std::alloc::{self, Layout};
+use std::ops::{Add, Deref, DerefMut, Mul};
+
+/// Returns the size of a virtual memory page on this system.
+#[cfg(unix)]
+fn page_size() -> usize {
+    // SAFETY: sysconf has no preconditions.
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if size <= 0 {
+        4096
+    } else {
+        size as usize
+    }
+}
+
+/// Returns the size of a virtual memory page on this system.
+#[cfg(windows)]
+fn page_size() -> usize {
+    use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+    // SAFETY: GetSystemInfo only writes into the struct we give it.
+    let mut info: SYSTEM_INFO = unsafe { std::mem::zeroed() };
+    unsafe { GetSystemInfo(&mut info) };
+    info.dwPageSize as usize
+}
+
+#[cfg(unix)]
+unsafe fn lock_pages(ptr: *mut u8, len: usize) -> std::io::Result<()> {
+    if libc::mlock(ptr as *const libc::c_void, len) == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(unix)]
+unsafe fn unlock_pages(ptr: *mut u8, len: usize) {
+    libc::munlock(ptr as *const libc::c_void, len);
+}
+
+#[cfg(windows)]
+unsafe fn lock_pages(ptr: *mut u8, len: usize) -> std::io::Result<()> {
+    use windows_sys::Win32::System::Memory::VirtualLock;
+    if VirtualLock(ptr as *const core::ffi::c_void, len) != 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+unsafe fn unlock_pages(ptr: *mut u8, len: usize) {
+    use windows_sys::Win32::System::Memory::VirtualUnlock;
+    VirtualUnlock(ptr as *const core::ffi::c_void, len);
+}
+
+/// Owns a locked allocation until a [`LockedBuffer`] takes it over.
+struct AllocationGuard {
+    raw: *mut u8,
+    layout: Layout,
+}
+
+impl Drop for AllocationGuard {
+    fn drop(&mut self) {
+        for i in 0..self.layout.size() {
+            // SAFETY: i is within the allocation.
+            unsafe { std::ptr::write_volatile(self.raw.add(i), 0) };
+        }
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+
+        unsafe {
+            unlock_pages(self.raw, self.layout.size());
+            alloc::dealloc(self.raw, self.layout);
+        }
+    }
+}
+
+/// A fixed-length heap buffer whose memory pages are locked into RAM using
+/// `mlock` (or `VirtualLock` on Windows), preventing the operating system from
+/// swapping its contents out to disk.
+///
+/// The buffer is allocated on its own page-aligned region of memory, so that
+/// unlocking it never unlocks memory belonging to another buffer. When dropped,
+/// every element is overwritten with `T::default()` before the pages are unlocked
+/// and freed.
+///
+/// This is intended for long-lived key material such as dealer coefficients and
+/// reconstructed secrets; see [`recover_secret_locked`] for the latter.
+pub struct LockedBuffer<T: Copy + Default> {
+    ptr: *mut T,
+    len: usize,
+    layout: Layout,
+}
+
+// SAFETY: LockedBuffer uniquely owns its allocation, just like a Box<[T]>.
+unsafe impl<T: Copy + Default + Send> Send for LockedBuffer<T> {}
+unsafe impl<T: Copy + Default + Sync> Sync for LockedBuffer<T> {}
+
+impl<T: Copy + Default> LockedBuffer<T> {
+    /// Allocate a locked buffer of `len` elements, each initialized to `T::default()`.
+    ///
+    /// Returns an error if the operating system refuses to lock the memory, for
+    /// instance because the process has exceeded `RLIMIT_MEMLOCK`.
+    pub fn new(len: usize) -> std::io::Result<Self> {
+        Self::from_fn(len, |_| T::default())
+    }
+
+    /// Allocate a locked buffer of `len` elements, initializing the element at
+    /// each index `i` to `f(i)`.
+    pub fn from_fn<F>(len: usize, mut f: F) -> std::io::Result<Self>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let page = page_size();
+        let byte_len = len
+            .checked_mul(std::mem::size_of::<T>())
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, "buffer length overflows")
+            })?
+            .max(1);
+        let alloc_len = byte_len.div_ceil(page) * page;
+        let layout = Layout::from_size_align(alloc_len, page.max(std::mem::align_of::<T>()))
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+
+        // SAFETY: layout has non-zero size.
+        let raw = unsafe { alloc::alloc_zeroed(layout) };
+        if raw.is_null() {
+            alloc::handle_alloc_error(layout);
+        }
+
+        // Lock the pages before any secret data is written to them.
+        if let Err(e) = unsafe { lock_pages(raw, alloc_len) } {
+            unsafe { alloc::dealloc(raw, layout) };
+            return Err(e);
+        }
+
+        // If `f` panics, the guard wipes whatever was written so far, then unlocks
+        // and frees the pages.
+        let guard = AllocationGuard { raw, layout };
+        let ptr = raw as *mut T;
+        for i in 0..len {
+            // SAFETY: i < len, and the allocation holds at least len elements.
+            unsafe { ptr.add(i).write(f(i)) };
+        }
+        std::mem::forget(guard);
+
+        Ok(LockedBuffer { ptr, len, layout })
+    }
+
+    /// Copy a slice of elements into a new locked buffer.
+    pub fn from_slice(values: &[T]) -> std::io::Result<Self> {
+        Self::from_fn(values.len(), |i| values[i])
+    }
+}
+
+impl<T: Copy + Default> Deref for LockedBuffer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: ptr points to len initialized elements which live as long as self.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<T: Copy + Default> DerefMut for LockedBuffer<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: ptr points to len initialized elements, and we hold a unique borrow.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl<T: Copy + Default> Drop for LockedBuffer<T> {
+    fn drop(&mut self) {
+        for i in 0..self.len {
+            // SAFETY: i < len. Volatile writes prevent the wipe being optimized out.
+            unsafe { std::ptr::write_volatile(self.ptr.add(i), T::default()) };
+        }
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+
+        unsafe {
+            unlock_pages(self.ptr as *mut u8, self.layout.size());
+            alloc::dealloc(self.ptr as *mut u8, self.layout);
+        }
+    }
+}
+
+impl<T: Copy + Default> std::fmt::Debug for LockedBuffer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LockedBuffer {{ len: {} }}", self.len)
+    }
+}
+
+/// A standard-form polynomial whose coefficients are stored in a [`LockedBuffer`].
+///
+/// The `coefficients` are presented in ascending order of degree, starting with
+/// the constant term `coefficients[0]`.
+#[derive(Debug)]
+pub struct LockedPolynomial<T: Copy + Default> {
+    pub coefficients: LockedBuffer<T>,
+}
+
+/// Represents a dealer's secret-sharing polynomial, with its coefficients held in
+/// locked memory.
+pub type LockedSecretPolynomial = LockedPolynomial<MaybeScalar>;
+
+impl<T: Copy + Default> LockedPolynomial<T> {
+    /// Construct a polynomial from a locked buffer of coefficients.
+    pub fn new(coefficients: LockedBuffer<T>) -> Self {
+        LockedPolynomial { coefficients }
+    }
+}
+
+impl LockedSecretPolynomial {
+    /// Sample a random secret-sharing polynomial with `n` coefficients, generating
+    /// each coefficient directly into locked memory.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(
+        rng: &mut R,
+        n: usize,
+    ) -> std::io::Result<Self> {
+        let coefficients = LockedBuffer::from_fn(n, |_| secp::Scalar::random(rng).into())?;
+        Ok(LockedPolynomial::new(coefficients))
+    }
+}
+
+impl<I, T> Polynomial<I, T> for LockedPolynomial<T>
+where
+    I: Copy,
+    T: Copy + Default + num_traits::Zero,
+    T: Mul<I, Output = T> + Add<T, Output = T>,
+{
+    fn evaluate(&self, x: I) -> T {
+        horner_poly_evaluate(x, &self.coefficients)
+    }
+
+    fn degree(&self) -> usize {
+        standard_form_degree(&self.coefficients)
+    }
+}
+
+/// Recover the secret `f(0)` from a set of shares, as with [`recover_secret`], and
+/// return it in a locked buffer of one element.
+///
+/// The locked buffer is allocated before interpolation, so that the secret is moved
+/// straight into it. Returns an error of kind [`InvalidInput`][std::io::ErrorKind::InvalidInput]
+/// wrapping the [`Error`] if the shares are rejected, or the operating system's
+/// error if the memory cannot be locked.
+pub fn recover_secret_locked(
+    shares: &[SecretShare],
+    threshold: usize,
+) -> std::io::Result<LockedBuffer<MaybeScalar>> {
+    let mut secret = LockedBuffer::new(1)?;
+    secret[0] = recover_secret(shares, threshold)
+        .map_err(|e: Error| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+
+    #[test]
+    fn test_locked_polynomial() {
+        let coefficients = [
+            MaybeScalar::from(4),
+            MaybeScalar::from(1),
+            MaybeScalar::from(8),
+        ];
+        let locked = LockedPolynomial::new(LockedBuffer::from_slice(&coefficients).unwrap());
+        let unlocked = SecretSharingPolynomial::new(coefficients.to_vec());
+
+        assert_eq!(Polynomial::<MaybeScalar, _>::degree(&locked), 2);
+        for i in 0..5 {
+            let x = MaybeScalar::from(i);
+            assert_eq!(locked.evaluate(x), unlocked.evaluate(x));
+            assert_eq!(locked.issue_share(x), unlocked.issue_share(x));
+        }
+    }
+
+    #[test]
+    fn test_locked_buffer_errors() {
+        let err = LockedBuffer::<MaybeScalar>::new(usize::MAX).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+        // A panicking initializer releases the locked pages instead of leaking them.
+        let result = std::panic::catch_unwind(|| {
+            LockedBuffer::<u8>::from_fn(8, |i| if i < 4 { 1 } else { panic!("boom") })
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_recover_secret_locked() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(42), MaybeScalar::from(7)]);
+        let shares: Vec<SecretShare> = (1..=3)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        let secret = recover_secret_locked(&shares, 2).unwrap();
+        assert_eq!(&*secret, &[MaybeScalar::from(42)]);
+
+        let err = recover_secret_locked(&shares[..1], 2).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{tagged_hash, PointShare};
+use alloc::{vec, vec::Vec};
+
+/// Hash a leaf of the tree, committing to the share's registered position.
+fn leaf_hash(index: usize, share: &PointShare) -> [u8; 32] {
+    let mut preimage = [0u8; 8 + 32 + 33];
+    preimage[..8].copy_from_slice(&(index as u64).to_be_bytes());
+    preimage[8..40].copy_from_slice(&share.input.serialize());
+    preimage[40..].copy_from_slice(&share.output.serialize());
+    tagged_hash(b"qudoku/merkle/leaf", &preimage)
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(left);
+    preimage[32..].copy_from_slice(right);
+    tagged_hash(b"qudoku/merkle/node", &preimage)
+}
+
+/// The root commits to the number of leaves as well as the tree itself, so that
+/// proofs cannot be replayed against a tree of a different size.
+fn root_hash(leaf_count: usize, tree_root: &[u8; 32]) -> [u8; 32] {
+    let mut preimage = [0u8; 8 + 32];
+    preimage[..8].copy_from_slice(&(leaf_count as u64).to_be_bytes());
+    preimage[8..].copy_from_slice(tree_root);
+    tagged_hash(b"qudoku/merkle/root", &preimage)
+}
+
+/// Combine one level of the tree into the next. An unpaired node at the end
+/// of a level is carried up unchanged.
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// A Merkle tree committing to an ordered list of issued verification shares
+/// (or any other point shares), such that each leaf is bound to its registered
+/// position in the list.
+///
+/// A coordinator holding only the [`root`][VerificationShareTree::root] can check
+/// that a contribution corresponds to a given registered index using a
+/// [`ShareMembershipProof`] of `O(log n)` size, instead of the full list of shares.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationShareTree {
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl VerificationShareTree {
+    /// Build a tree over the given shares. The position of each share in the
+    /// slice is its registered index.
+    pub fn new(shares: &[PointShare]) -> Self {
+        let leaves: Vec<[u8; 32]> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, share)| leaf_hash(i, share))
+            .collect();
+
+        let mut levels = vec![leaves];
+        while levels[levels.len() - 1].len() > 1 {
+            let next = next_level(&levels[levels.len() - 1]);
+            levels.push(next);
+        }
+        VerificationShareTree { levels }
+    }
+
+    /// Returns the number of shares committed to by the tree.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Returns true if the tree commits to no shares.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the root hash which commits to the full list of shares.
+    pub fn root(&self) -> [u8; 32] {
+        let tree_root = self.levels[self.levels.len() - 1]
+            .first()
+            .copied()
+            .unwrap_or([0; 32]);
+        root_hash(self.len(), &tree_root)
+    }
+
+    /// Construct a proof that the share at the given index is part of the tree.
+    /// Returns `None` if the index is out of bounds.
+    pub fn prove(&self, index: usize) -> Option<ShareMembershipProof> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut siblings = Vec::with_capacity(self.levels.len());
+        let mut position = index;
+        for level in self.levels[..self.levels.len() - 1].iter() {
+            if let Some(sibling) = level.get(position ^ 1) {
+                siblings.push(*sibling);
+            }
+            position /= 2;
+        }
+
+        Some(ShareMembershipProof {
+            index,
+            leaf_count: self.len(),
+            siblings,
+        })
+    }
+}
+
+/// A proof that a share was committed to at a specific index of a [`VerificationShareTree`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShareMembershipProof {
+    /// The registered index of the share.
+    pub index: usize,
+
+    /// The total number of shares in the tree.
+    pub leaf_count: usize,
+
+    /// The sibling hashes along the path from the leaf to the root.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl ShareMembershipProof {
+    /// Verify that `share` was committed to at index `self.index` in the tree
+    /// with the given root hash.
+    pub fn verify(&self, root: &[u8; 32], share: &PointShare) -> bool {
+        if self.index >= self.leaf_count {
+            return false;
+        }
+
+        let mut hash = leaf_hash(self.index, share);
+        let mut position = self.index;
+        let mut level_len = self.leaf_count;
+        let mut siblings = self.siblings.iter();
+
+        while level_len > 1 {
+            let sibling_position = position ^ 1;
+            if sibling_position < level_len {
+                let sibling = match siblings.next() {
+                    Some(sibling) => sibling,
+                    None => return false,
+                };
+                hash = if position & 1 == 0 {
+                    node_hash(&hash, sibling)
+                } else {
+                    node_hash(sibling, &hash)
+                };
+            }
+            position /= 2;
+            level_len = level_len.div_ceil(2);
+        }
+
+        siblings.next().is_none() && &root_hash(self.leaf_count, &hash) == root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+    use secp::{MaybeScalar, G};
+
+    #[test]
+    fn test_verification_share_tree() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(9),
+            MaybeScalar::from(8),
+            MaybeScalar::from(7),
+        ]);
+        let verification_polynomial = G * &f;
+
+        for n in 1..=9 {
+            let shares: Vec<PointShare> = (1..=n)
+                .map(|i| verification_polynomial.issue_share(MaybeScalar::from(i)))
+                .collect();
+
+            let tree = VerificationShareTree::new(&shares);
+            let root = tree.root();
+            assert_eq!(tree.len(), n as usize);
+
+            for (i, share) in shares.iter().enumerate() {
+                let proof = tree.prove(i).unwrap();
+                assert!(proof.verify(&root, share));
+
+                // Wrong position.
+                let mut wrong_index = proof.clone();
+                wrong_index.index = (i + 1) % shares.len();
+                if shares.len() > 1 {
+                    assert!(!wrong_index.verify(&root, share));
+                }
+
+                // Wrong share.
+                let other = verification_polynomial.issue_share(MaybeScalar::from(100));
+                assert!(!proof.verify(&root, &other));
+            }
+            assert_eq!(tree.prove(shares.len()), None);
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use crate::{
+    check_duplicate_inputs, derive_q, derive_rotated_q, Evaluation, InterpolatedPointPolynomial,
+    PointShare, PointSharingPolynomial, SecretShare, SecretSharingPolynomial,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+use secp::{MaybeScalar, Point};
+
+/// The tag under which nested secrets are derived.
+const NESTED_SECRET_TAG: &[u8] = b"qudoku/nested";
+
+/// A set of nested `Q` points for one qudoku group, each identified by a label
+/// describing its purpose, such as `b"backup-key"` or `b"payroll"`.
+///
+/// Every nested secret is derived from the same secret-sharing polynomial `f(x)`:
+/// the secret for `Q` is the tagged hash of `Q * f(0)`. Shareholders compute one point share
+/// per label from their single secret share, and any `t` shareholders can then derive
+/// each secret independently of the others.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NestedSecrets {
+    points: BTreeMap<Vec<u8>, Point>,
+}
+
+impl NestedSecrets {
+    /// Construct an empty set of nested points.
+    pub fn new() -> Self {
+        NestedSecrets::default()
+    }
+
+    /// Add the point `Q` under `label`, returning the point previously stored under
+    /// that label, if any.
+    pub fn insert(&mut self, label: &[u8], q: Point) -> Option<Point> {
+        self.points.insert(label.to_vec(), q)
+    }
+
+    /// Add the point [`derive_q(group_pubkey, label)`][crate::derive_q] under `label`,
+    /// returning the derived point. Every participant who knows the group's public key
+    /// computes the same point, without trusting the dealer's choice of `Q`.
+    pub fn insert_derived(&mut self, group_pubkey: Point, label: &[u8]) -> Point {
+        let q = derive_q(group_pubkey, label);
+        self.points.insert(label.to_vec(), q);
+        q
+    }
+
+    /// Rotate the nested secret under `label` to a new point `Q'`, returning the
+    /// previous point. Shareholders keep their secret shares, and only need to learn
+    /// `Q'` to derive the new secret. Returns `None` and changes nothing if there is no
+    /// point under `label`.
+    ///
+    /// Rotation cannot revoke the old secret: any `t` shareholders can still derive it
+    /// from the old point. It only ensures the new secret is unrelated to the old one.
+    pub fn rotate(&mut self, label: &[u8], q: Point) -> Option<Point> {
+        let current = self.points.get_mut(label)?;
+        Some(core::mem::replace(current, q))
+    }
+
+    /// Rotate the nested secret under `label` like [`rotate`][Self::rotate], to the point
+    /// [`derive_rotated_q(group_pubkey, label, epoch)`][crate::derive_rotated_q]. Returns
+    /// the new point, or `None` if there is no point under `label`.
+    pub fn rotate_derived(
+        &mut self,
+        group_pubkey: Point,
+        label: &[u8],
+        epoch: u32,
+    ) -> Option<Point> {
+        let q = derive_rotated_q(group_pubkey, label, epoch);
+        self.rotate(label, q).map(|_| q)
+    }
+
+    /// Remove the point stored under `label`, returning it if it was present.
+    pub fn remove(&mut self, label: &[u8]) -> Option<Point> {
+        self.points.remove(label)
+    }
+
+    /// Returns the point stored under `label`.
+    pub fn get(&self, label: &[u8]) -> Option<Point> {
+        self.points.get(label).copied()
+    }
+
+    /// Returns an iterator over the labels and their points, ordered by label.
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], Point)> {
+        self.points.iter().map(|(label, &q)| (label.as_slice(), q))
+    }
+
+    /// Returns the number of nested points.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns true if there are no nested points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Compute a shareholder's point shares `s_i * Q` for every nested point, keyed
+    /// by label.
+    pub fn point_shares(&self, share: &SecretShare) -> BTreeMap<Vec<u8>, PointShare> {
+        self.points
+            .iter()
+            .map(|(label, &q)| (label.clone(), share * q))
+            .collect()
+    }
+
+    /// Derive every nested secret from the secret-sharing polynomial, keyed by label.
+    /// This is the dealer's view, and matches what shareholders derive with
+    /// [`derive_secret`][Self::derive_secret].
+    pub fn derive_secrets(
+        &self,
+        polynomial: &SecretSharingPolynomial,
+    ) -> BTreeMap<Vec<u8>, [u8; 32]> {
+        self.points
+            .iter()
+            .map(|(label, &q)| {
+                (
+                    label.clone(),
+                    (q * polynomial).derive_secret_tagged(NESTED_SECRET_TAG, MaybeScalar::Zero),
+                )
+            })
+            .collect()
+    }
+
+    /// Derive the nested secret under `label` from the [`point_shares`][Self::point_shares]
+    /// of at least `threshold` shareholders. Entries for other labels are ignored.
+    ///
+    /// Returns `None` if there is no point under `label`, if any contribution is
+    /// missing a point share for it, if fewer than `threshold` contributions are
+    /// given, or if two point shares have the same input.
+    pub fn derive_secret(
+        &self,
+        label: &[u8],
+        contributions: &[BTreeMap<Vec<u8>, PointShare>],
+        threshold: usize,
+    ) -> Option<[u8; 32]> {
+        self.points.get(label)?;
+        if threshold == 0 || contributions.len() < threshold {
+            return None;
+        }
+        let point_shares = contributions
+            .iter()
+            .map(|contribution| contribution.get(label).copied())
+            .collect::<Option<Vec<PointShare>>>()?;
+        check_duplicate_inputs(&point_shares).ok()?;
+        Some(
+            InterpolatedPointPolynomial::new(point_shares)
+                .derive_secret_tagged(NESTED_SECRET_TAG, MaybeScalar::Zero),
+        )
+    }
+}
+
+/// A nested secret for a point `Q` with its own threshold `t'`, stricter than the
+/// threshold `t` of the main secret-sharing polynomial `f(x)`.
+///
+/// Alongside `f(x)`, the dealer samples an auxiliary polynomial `g(x)` with `t'`
+/// coefficients, and gives each shareholder an auxiliary share `g(x_i)` with the
+/// same input as their main share. The nested secret is derived from the point
+/// `Q * (f(0) + g(0))`, which any `t'` shareholders can interpolate from their
+/// [point shares](strict_point_share) `Q * (f(x_i) + g(x_i))`. Recovering `f(0)` with
+/// only `t` shares reveals nothing about the nested secret, because `g(0)` is independent.
+///
+/// The combined polynomial `f(x) + g(x)` has `max(t, t')` coefficients, so the nested
+/// threshold is only stricter if `t' > t`. Unlike nested secrets at the main threshold,
+/// this costs each shareholder one extra share to store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StrictNestedSecret {
+    q: Point,
+    auxiliary: SecretSharingPolynomial,
+}
+
+impl StrictNestedSecret {
+    /// Construct a strict nested secret for `Q` from the auxiliary polynomial `g(x)`,
+    /// whose coefficients must be uniformly random and secret.
+    pub fn new(q: Point, auxiliary: SecretSharingPolynomial) -> Self {
+        StrictNestedSecret { q, auxiliary }
+    }
+
+    /// Sample a random auxiliary polynomial, such that `threshold` shares are needed
+    /// to recover the nested secret for `Q`.
+    ///
+    /// Panics if `threshold` is zero.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(
+        q: Point,
+        threshold: usize,
+        rng: &mut R,
+    ) -> Self {
+        assert_ne!(threshold, 0, "threshold must be at least 1");
+        let auxiliary = SecretSharingPolynomial::new(crate::random_coefficients(rng, threshold));
+        StrictNestedSecret::new(q, auxiliary)
+    }
+
+    /// Returns the nested point `Q`.
+    pub fn q(&self) -> Point {
+        self.q
+    }
+
+    /// Returns the number of shares needed to recover the nested secret, assuming it
+    /// exceeds the threshold of the main polynomial.
+    pub fn threshold(&self) -> usize {
+        self.auxiliary.coefficients.len()
+    }
+
+    /// Issue the auxiliary share `g(x)`, to be given to the shareholder whose main
+    /// share has input `x`.
+    pub fn issue_auxiliary_share(&self, x: MaybeScalar) -> SecretShare {
+        self.auxiliary.issue_share(x)
+    }
+
+    /// Compute the point-sharing polynomial `Q * (f(x) + g(x))` for the given main
+    /// polynomial `f(x)`. The dealer can derive the nested secret from it at `x = 0`,
+    /// e.g. with [`derive_secret_tagged`][PointSharingPolynomial::derive_secret_tagged].
+    pub fn point_polynomial(&self, main: &SecretSharingPolynomial) -> PointSharingPolynomial {
+        self.q * (main + &self.auxiliary)
+    }
+}
+
+/// Compute a shareholder's point share `Q * (f(x_i) + g(x_i))` towards a
+/// [`StrictNestedSecret`], from their main share and auxiliary share.
+///
+/// Any `t'` of these point shares interpolate the polynomial returned by
+/// [`StrictNestedSecret::point_polynomial`].
+///
+/// Panics if the two shares have different inputs.
+pub fn strict_point_share(
+    share: &SecretShare,
+    auxiliary_share: &SecretShare,
+    q: Point,
+) -> PointShare {
+    assert_eq!(
+        share.input, auxiliary_share.input,
+        "main and auxiliary shares must have the same input"
+    );
+    Evaluation::new(share.input, (share.output + auxiliary_share.output) * q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hash_to_point_tagged, InterpolatedPointPolynomial, InterpolatedSecretPolynomial, Polynomial,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_nested_secrets() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(9), MaybeScalar::from(4)]);
+        let mut nested = NestedSecrets::new();
+        assert!(nested
+            .insert(b"backup", hash_to_point_tagged(b"qudoku/test", b"backup"))
+            .is_none());
+        assert!(nested
+            .insert(b"payroll", hash_to_point_tagged(b"qudoku/test", b"payroll"))
+            .is_none());
+        assert_eq!(nested.len(), 2);
+
+        let contributions: Vec<_> = (1..=2)
+            .map(|i| nested.point_shares(&f.issue_share(MaybeScalar::from(i))))
+            .collect();
+        let secrets = nested.derive_secrets(&f);
+        for (label, _) in nested.iter() {
+            assert_eq!(
+                nested.derive_secret(label, &contributions, 2),
+                Some(secrets[label])
+            );
+        }
+        assert_ne!(secrets[&b"backup"[..]], secrets[&b"payroll"[..]]);
+        assert_eq!(nested.derive_secret(b"missing", &contributions, 2), None);
+        assert_eq!(
+            nested.derive_secret(b"backup", &contributions[..1], 2),
+            None
+        );
+
+        let duplicated = [contributions[0].clone(), contributions[0].clone()];
+        assert_eq!(nested.derive_secret(b"backup", &duplicated, 2), None);
+
+        assert_eq!(
+            nested.remove(b"payroll"),
+            Some(hash_to_point_tagged(b"qudoku/test", b"payroll"))
+        );
+
+        let pubkey = f.group_pubkey().unwrap();
+        let q = nested.insert_derived(pubkey, b"audit");
+        assert_eq!(nested.get(b"audit"), Some(derive_q(pubkey, b"audit")));
+        assert_eq!(q, derive_q(pubkey, b"audit"));
+        assert_eq!(nested.iter().count(), 2);
+
+        let before = nested.derive_secrets(&f)[&b"audit"[..]];
+        let rotated = nested.rotate_derived(pubkey, b"audit", 1).unwrap();
+        assert_eq!(rotated, derive_rotated_q(pubkey, b"audit", 1));
+        let contributions: Vec<_> = (1..=2)
+            .map(|i| nested.point_shares(&f.issue_share(MaybeScalar::from(i))))
+            .collect();
+        let after = nested.derive_secret(b"audit", &contributions, 2).unwrap();
+        assert_ne!(after, before);
+        assert_eq!(after, nested.derive_secrets(&f)[&b"audit"[..]]);
+        assert_eq!(nested.rotate(b"missing", rotated), None);
+        assert_eq!(nested.get(b"missing"), None);
+    }
+
+    #[test]
+    fn test_strict_nested_secret() {
+        let q = hash_to_point_tagged(b"qudoku/test", b"strict");
+        let main = SecretSharingPolynomial::new(vec![MaybeScalar::from(100), MaybeScalar::from(3)]);
+        let auxiliary = SecretSharingPolynomial::new(
+            (1..=4).map(|i| MaybeScalar::from(i * 1000 + 1)).collect(),
+        );
+        let nested = StrictNestedSecret::new(q, auxiliary);
+        assert_eq!(nested.threshold(), 4);
+
+        let xs: Vec<MaybeScalar> = (1..=5).map(MaybeScalar::from).collect();
+        let shares = main.issue_shares(&xs);
+        let point_shares: Vec<PointShare> = shares
+            .iter()
+            .map(|share| strict_point_share(share, &nested.issue_auxiliary_share(share.input), q))
+            .collect();
+
+        // Two shares recover the main secret, but four are needed for the nested secret.
+        let recovered = InterpolatedSecretPolynomial::new(shares[..2].to_vec());
+        assert_eq!(
+            recovered.evaluate(MaybeScalar::Zero),
+            MaybeScalar::from(100)
+        );
+
+        let expected = nested
+            .point_polynomial(&main)
+            .derive_secret_tagged(b"qudoku/test", MaybeScalar::Zero);
+        let four = InterpolatedPointPolynomial::new(point_shares[1..].to_vec());
+        assert_eq!(
+            four.derive_secret_tagged(b"qudoku/test", MaybeScalar::Zero),
+            expected
+        );
+        let three = InterpolatedPointPolynomial::new(point_shares[..3].to_vec());
+        assert_ne!(
+            three.derive_secret_tagged(b"qudoku/test", MaybeScalar::Zero),
+            expected
+        );
+        assert_ne!(
+            (q * &main).derive_secret_tagged(b"qudoku/test", MaybeScalar::Zero),
+            expected
+        );
+    }
+}
@@ -2,8 +2,9 @@ use crate::{
     Evaluation, InterpolatedPointPolynomial, InterpolatedSecretPolynomial, PointShare,
     PointSharingPolynomial, SecretShare, SecretSharingPolynomial,
 };
-use secp::{Point, G};
-use std::ops::Mul;
+use alloc::vec::Vec;
+use core::ops::Mul;
+use secp::{MaybePoint, MaybeScalar, Point, G};
 
 /// Allows multiplying a secret share by a given fixed point.
 impl Mul<&SecretShare> for Point {
@@ -25,7 +26,7 @@ impl Mul<Point> for &SecretShare {
 impl Mul<SecretShare> for Point {
     type Output = PointShare;
     fn mul(self, rhs: SecretShare) -> Self::Output {
-        self * &rhs
+        Mul::mul(self, &rhs)
     }
 }
 impl Mul<Point> for SecretShare {
@@ -55,7 +56,7 @@ impl Mul<G> for &SecretShare {
 impl Mul<SecretShare> for G {
     type Output = PointShare;
     fn mul(self, rhs: SecretShare) -> Self::Output {
-        self * &rhs
+        Mul::mul(self, &rhs)
     }
 }
 impl Mul<G> for SecretShare {
@@ -86,7 +87,7 @@ impl Mul<Point> for &SecretSharingPolynomial {
 impl Mul<SecretSharingPolynomial> for Point {
     type Output = PointSharingPolynomial;
     fn mul(self, rhs: SecretSharingPolynomial) -> Self::Output {
-        self * &rhs
+        Mul::mul(self, &rhs)
     }
 }
 impl Mul<Point> for SecretSharingPolynomial {
@@ -122,6 +123,59 @@ impl Mul<G> for SecretSharingPolynomial {
     }
 }
 
+/// Allows multiplying a fixed-size secret sharing polynomial by a given fixed point.
+impl<const N: usize> Mul<&SecretSharingPolynomial<[MaybeScalar; N]>> for Point {
+    type Output = PointSharingPolynomial<[MaybePoint; N]>;
+
+    fn mul(self, rhs: &SecretSharingPolynomial<[MaybeScalar; N]>) -> Self::Output {
+        PointSharingPolynomial::new(rhs.coefficients.map(|scalar| scalar * self))
+    }
+}
+impl<const N: usize> Mul<Point> for &SecretSharingPolynomial<[MaybeScalar; N]> {
+    type Output = PointSharingPolynomial<[MaybePoint; N]>;
+    fn mul(self, rhs: Point) -> Self::Output {
+        rhs * self
+    }
+}
+impl<const N: usize> Mul<SecretSharingPolynomial<[MaybeScalar; N]>> for Point {
+    type Output = PointSharingPolynomial<[MaybePoint; N]>;
+    fn mul(self, rhs: SecretSharingPolynomial<[MaybeScalar; N]>) -> Self::Output {
+        Mul::mul(self, &rhs)
+    }
+}
+impl<const N: usize> Mul<Point> for SecretSharingPolynomial<[MaybeScalar; N]> {
+    type Output = PointSharingPolynomial<[MaybePoint; N]>;
+    fn mul(self, rhs: Point) -> Self::Output {
+        rhs * &self
+    }
+}
+
+/// Allows multiplying a fixed-size secret sharing polynomial by the secp256k1 generator point.
+impl<const N: usize> Mul<&SecretSharingPolynomial<[MaybeScalar; N]>> for G {
+    type Output = PointSharingPolynomial<[MaybePoint; N]>;
+    fn mul(self, rhs: &SecretSharingPolynomial<[MaybeScalar; N]>) -> Self::Output {
+        rhs * Point::generator()
+    }
+}
+impl<const N: usize> Mul<G> for &SecretSharingPolynomial<[MaybeScalar; N]> {
+    type Output = PointSharingPolynomial<[MaybePoint; N]>;
+    fn mul(self, _: G) -> Self::Output {
+        self * Point::generator()
+    }
+}
+impl<const N: usize> Mul<SecretSharingPolynomial<[MaybeScalar; N]>> for G {
+    type Output = PointSharingPolynomial<[MaybePoint; N]>;
+    fn mul(self, rhs: SecretSharingPolynomial<[MaybeScalar; N]>) -> Self::Output {
+        rhs * Point::generator()
+    }
+}
+impl<const N: usize> Mul<G> for SecretSharingPolynomial<[MaybeScalar; N]> {
+    type Output = PointSharingPolynomial<[MaybePoint; N]>;
+    fn mul(self, _: G) -> Self::Output {
+        self * Point::generator()
+    }
+}
+
 /// Allows multiplying an interpolated secret-sharing polynomial by a given fixed point.
 impl Mul<&InterpolatedSecretPolynomial> for Point {
     type Output = InterpolatedPointPolynomial;
@@ -148,7 +202,7 @@ impl Mul<Point> for &InterpolatedSecretPolynomial {
 impl Mul<InterpolatedSecretPolynomial> for Point {
     type Output = InterpolatedPointPolynomial;
     fn mul(self, rhs: InterpolatedSecretPolynomial) -> Self::Output {
-        self * &rhs
+        Mul::mul(self, &rhs)
     }
 }
 impl Mul<Point> for InterpolatedSecretPolynomial {
@@ -202,7 +256,7 @@ mod tests {
 
         let Z1 = &f * G;
 
-        // UNSAFE: do not use a Q point with a known dlog. Generate them using `hash_to_point`.
+        // UNSAFE: do not use a Q point with a known dlog. Generate them using `hash_to_point_tagged`.
         let Q = G * Scalar::try_from(100000).unwrap();
         let Z2 = &f * Q;
 
@@ -226,6 +280,26 @@ mod tests {
         assert_eq!((&interpolated_f * Q).evaluate(i), Z2.evaluate(i));
     }
 
+    #[test]
+    fn test_array_secret_sharing_mul_point() {
+        let f = SecretSharingPolynomial::new([
+            MaybeScalar::from(4),
+            MaybeScalar::from(1),
+            MaybeScalar::from(8),
+        ]);
+        let Q = G * Scalar::try_from(100000).unwrap();
+
+        let i = MaybeScalar::from(49);
+        assert_eq!((&f * G).evaluate(i), f.evaluate(i) * G);
+        assert_eq!((&f * Q).evaluate(i), f.evaluate(i) * Q);
+        assert_eq!(
+            (&f * Q).derive_secret_tagged(b"qudoku/test", i),
+            (<SecretSharingPolynomial>::from(f.clone()) * Q)
+                .derive_secret_tagged(b"qudoku/test", i)
+        );
+        assert_eq!(f.issue_share(i), SecretShare::new(i, f.evaluate(i)));
+    }
+
     #[test]
     fn test_secret_share_mul_point() {
         let share = SecretShare::new(49.into(), 49999.into());
@@ -0,0 +1,24 @@
+use alloc::vec::Vec;
+
+/// Maps `f` over `items` and collects the results in order. With the `parallel`
+/// feature enabled, the items are processed on the rayon thread pool.
+///
+/// Used for batch operations whose items are independent and each cost at least
+/// one scalar multiplication, so that the threading overhead is worthwhile.
+pub(crate) fn par_map<T, U, F>(items: &[T], f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items.par_iter().map(f).collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(f).collect()
+    }
+}
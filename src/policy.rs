@@ -0,0 +1,293 @@
+use crate::{try_interpolate, Evaluation, Polynomial, SecretSharingPolynomial};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul};
+use secp::{MaybePoint, MaybeScalar, Point};
+
+/// Errors which can occur when dealing or recovering a secret under a [`Policy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyError {
+    /// A threshold node has no children, a zero threshold, or a threshold greater
+    /// than its number of children.
+    InvalidThreshold,
+
+    /// The given shares do not satisfy the policy.
+    Unsatisfied,
+}
+
+impl core::fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PolicyError::InvalidThreshold => write!(f, "policy has an invalid threshold node"),
+            PolicyError::Unsatisfied => write!(f, "shares do not satisfy the policy"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolicyError {}
+
+/// A monotone access structure over shareholders identified by index, expressed as
+/// a tree of threshold gates. `AND` and `OR` are the `n`-of-`n` and `1`-of-`n` gates.
+///
+/// For example, "(2-of-3 executives) AND (3-of-5 engineers)" is:
+///
+/// ```
+/// use qudoku::Policy;
+///
+/// let executives = (0..3).map(Policy::Participant).collect();
+/// let engineers = (3..8).map(Policy::Participant).collect();
+/// let policy = Policy::and(vec![
+///     Policy::threshold(2, executives),
+///     Policy::threshold(3, engineers),
+/// ]);
+///
+/// assert!(policy.is_satisfied_by(&[0, 2, 3, 4, 7]));
+/// assert!(!policy.is_satisfied_by(&[0, 1, 2, 3, 4]));
+/// ```
+///
+/// Dealing compiles the tree into nested secret-sharing polynomials: each gate shares
+/// its secret with a polynomial whose threshold is the gate's, and passes the share at
+/// input `i + 1` down to its `i`-th child as that child's secret. Each leaf becomes one
+/// [`PolicyShare`] for its shareholder, who receives one share per leaf they appear in.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Policy {
+    /// A single shareholder.
+    Participant(usize),
+
+    /// A gate satisfied when at least `k` of its children are satisfied.
+    Threshold(usize, Vec<Policy>),
+}
+
+/// A share of a secret dealt under a [`Policy`], for the leaf at `path`.
+///
+/// The `path` holds the index of each child taken from the root to the leaf. The
+/// inner share is a share of the parent gate's polynomial, at input `path.last() + 1`.
+/// Shares can be multiplied by a point with [`to_point_share`][Self::to_point_share]
+/// so that a nested secret can be recovered without revealing the primary secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolicyShare<O = MaybeScalar> {
+    pub participant: usize,
+    pub path: Vec<usize>,
+    pub share: Evaluation<MaybeScalar, O>,
+}
+
+impl PolicyShare {
+    /// Multiply the share by a point `Q`.
+    pub fn to_point_share(&self, q: Point) -> PolicyShare<MaybePoint> {
+        PolicyShare {
+            participant: self.participant,
+            path: self.path.clone(),
+            share: self.share * q,
+        }
+    }
+}
+
+impl Policy {
+    /// A gate satisfied when all of the children are satisfied.
+    pub fn and(children: Vec<Policy>) -> Self {
+        Policy::Threshold(children.len(), children)
+    }
+
+    /// A gate satisfied when any of the children is satisfied.
+    pub fn or(children: Vec<Policy>) -> Self {
+        Policy::Threshold(1, children)
+    }
+
+    /// A gate satisfied when at least `k` of the children are satisfied.
+    pub fn threshold(k: usize, children: Vec<Policy>) -> Self {
+        Policy::Threshold(k, children)
+    }
+
+    /// Check that every gate has a threshold between one and its number of children.
+    pub fn validate(&self) -> Result<(), PolicyError> {
+        match self {
+            Policy::Participant(_) => Ok(()),
+            Policy::Threshold(k, children) => {
+                if *k == 0 || *k > children.len() {
+                    return Err(PolicyError::InvalidThreshold);
+                }
+                children.iter().try_for_each(Policy::validate)
+            }
+        }
+    }
+
+    /// Returns true if the given set of shareholders satisfies the policy.
+    pub fn is_satisfied_by(&self, participants: &[usize]) -> bool {
+        match self {
+            Policy::Participant(p) => participants.contains(p),
+            Policy::Threshold(k, children) => {
+                children
+                    .iter()
+                    .filter(|child| child.is_satisfied_by(participants))
+                    .count()
+                    >= *k
+            }
+        }
+    }
+
+    /// Deal shares of `secret` under the policy, drawing the higher-order coefficients
+    /// of every gate's polynomial from `next_coefficient`. The coefficients must be
+    /// uniformly random and secret, so this is mostly useful for deterministic tests.
+    ///
+    /// Returns the shares for every leaf, in depth-first order.
+    pub fn deal_with<F>(
+        &self,
+        secret: MaybeScalar,
+        mut next_coefficient: F,
+    ) -> Result<Vec<PolicyShare>, PolicyError>
+    where
+        F: FnMut() -> MaybeScalar,
+    {
+        self.validate()?;
+        let mut shares = Vec::new();
+        self.deal_node(
+            Evaluation::new(MaybeScalar::Zero, secret),
+            &mut Vec::new(),
+            &mut next_coefficient,
+            &mut shares,
+        );
+        Ok(shares)
+    }
+
+    /// Deal shares of `secret` under the policy, with random coefficients.
+    #[cfg(feature = "rand")]
+    pub fn deal<R: rand::RngCore + rand::CryptoRng>(
+        &self,
+        secret: MaybeScalar,
+        rng: &mut R,
+    ) -> Result<Vec<PolicyShare>, PolicyError> {
+        self.deal_with(secret, || MaybeScalar::Valid(secp::Scalar::random(rng)))
+    }
+
+    fn deal_node<F>(
+        &self,
+        node_share: Evaluation<MaybeScalar, MaybeScalar>,
+        path: &mut Vec<usize>,
+        next_coefficient: &mut F,
+        shares: &mut Vec<PolicyShare>,
+    ) where
+        F: FnMut() -> MaybeScalar,
+    {
+        match self {
+            Policy::Participant(participant) => shares.push(PolicyShare {
+                participant: *participant,
+                path: path.clone(),
+                share: node_share,
+            }),
+            Policy::Threshold(k, children) => {
+                let mut coefficients = vec![node_share.output];
+                coefficients.extend((1..*k).map(|_| next_coefficient()));
+                let polynomial = SecretSharingPolynomial::new(coefficients);
+
+                for (i, child) in children.iter().enumerate() {
+                    let x = MaybeScalar::from(i as u128 + 1);
+                    path.push(i);
+                    child.deal_node(
+                        Evaluation::new(x, polynomial.evaluate(x)),
+                        path,
+                        next_coefficient,
+                        shares,
+                    );
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    /// Recover the secret from a set of shares which satisfies the policy. Works with
+    /// scalar shares to recover the secret `s`, or with point shares under `Q` to
+    /// recover `s * Q` for a nested secret.
+    ///
+    /// Returns [`PolicyError::Unsatisfied`] if the shares do not satisfy the policy.
+    pub fn recover<O>(&self, shares: &[PolicyShare<O>]) -> Result<O, PolicyError>
+    where
+        O: Copy + num_traits::Zero + Mul<MaybeScalar, Output = O> + Add<O, Output = O>,
+    {
+        self.recover_node(&mut Vec::new(), shares)
+            .ok_or(PolicyError::Unsatisfied)
+    }
+
+    fn recover_node<O>(&self, path: &mut Vec<usize>, shares: &[PolicyShare<O>]) -> Option<O>
+    where
+        O: Copy + num_traits::Zero + Mul<MaybeScalar, Output = O> + Add<O, Output = O>,
+    {
+        match self {
+            Policy::Participant(_) => shares
+                .iter()
+                .find(|share| share.path == *path)
+                .map(|share| share.share.output),
+            Policy::Threshold(k, children) => {
+                let mut evaluations = Vec::with_capacity(*k);
+                for (i, child) in children.iter().enumerate() {
+                    if evaluations.len() == *k {
+                        break;
+                    }
+                    path.push(i);
+                    if let Some(output) = child.recover_node(path, shares) {
+                        evaluations.push(Evaluation::new(MaybeScalar::from(i as u128 + 1), output));
+                    }
+                    path.pop();
+                }
+                if evaluations.len() < *k {
+                    return None;
+                }
+                try_interpolate(&evaluations, MaybeScalar::Zero).ok()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash_to_point_tagged;
+
+    #[test]
+    fn test_policy() {
+        let executives = (0..3).map(Policy::Participant).collect();
+        let engineers = (3..8).map(Policy::Participant).collect();
+        let policy = Policy::or(vec![
+            Policy::and(vec![
+                Policy::threshold(2, executives),
+                Policy::threshold(3, engineers),
+            ]),
+            Policy::Participant(8),
+        ]);
+
+        let secret = MaybeScalar::from(0xABCDEF);
+        let mut counter = 0;
+        let shares = policy
+            .deal_with(secret, || {
+                counter += 1;
+                MaybeScalar::from(counter * 7919)
+            })
+            .unwrap();
+        assert_eq!(shares.len(), 9);
+
+        let holding = |participants: &[usize]| -> Vec<PolicyShare> {
+            shares
+                .iter()
+                .filter(|share| participants.contains(&share.participant))
+                .cloned()
+                .collect()
+        };
+        assert_eq!(policy.recover(&holding(&[0, 2, 3, 5, 7])), Ok(secret));
+        assert_eq!(policy.recover(&holding(&[8])), Ok(secret));
+        assert_eq!(
+            policy.recover(&holding(&[0, 1, 2, 3, 4])),
+            Err(PolicyError::Unsatisfied)
+        );
+
+        let q = hash_to_point_tagged(b"qudoku/test", b"policy");
+        let point_shares: Vec<PolicyShare<MaybePoint>> = holding(&[1, 2, 4, 5, 6])
+            .iter()
+            .map(|share| share.to_point_share(q))
+            .collect();
+        assert_eq!(policy.recover(&point_shares), Ok(secret * q));
+
+        assert_eq!(
+            Policy::threshold(3, vec![Policy::Participant(0)]).deal_with(secret, MaybeScalar::one),
+            Err(PolicyError::InvalidThreshold)
+        );
+    }
+}
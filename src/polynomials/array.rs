@@ -0,0 +1,57 @@
+use crate::StandardFormPolynomial;
+
+/// Represents a polynomial function expressed in standard form with a fixed number
+/// `N` of coefficients of type `T`, stored inline in an array rather than a `Vec`.
+///
+/// This avoids heap allocation, which is useful in `no-alloc` environments and for
+/// small fixed thresholds.
+pub type ArrayPolynomial<T, const N: usize> = StandardFormPolynomial<T, [T; N]>;
+
+impl<T: Clone, const N: usize> From<ArrayPolynomial<T, N>> for StandardFormPolynomial<T> {
+    fn from(poly: ArrayPolynomial<T, N>) -> Self {
+        StandardFormPolynomial::new(poly.coefficients.to_vec())
+    }
+}
+
+impl<T, const N: usize> TryFrom<StandardFormPolynomial<T>> for ArrayPolynomial<T, N> {
+    type Error = StandardFormPolynomial<T>;
+
+    /// Converts a standard-form polynomial into an array polynomial, returning the
+    /// original polynomial if it does not have exactly `N` coefficients.
+    fn try_from(mut poly: StandardFormPolynomial<T>) -> Result<Self, Self::Error> {
+        match <[T; N]>::try_from(core::mem::take(&mut poly.coefficients)) {
+            Ok(coefficients) => Ok(StandardFormPolynomial::new(coefficients)),
+            Err(coefficients) => Err(StandardFormPolynomial::new(coefficients)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Polynomial;
+
+    #[test]
+    fn test_array_polynomial() {
+        // f(x) = 1 + 3x + 2x^2
+        let poly = ArrayPolynomial::new([1, 3, 2]);
+        let std_poly = StandardFormPolynomial::<i32>::from(poly.clone());
+
+        assert_eq!(poly.degree(), 2);
+        for x in 0..6 {
+            assert_eq!(poly.evaluate(x), std_poly.evaluate(x));
+        }
+
+        assert_eq!(ArrayPolynomial::new([3, 0, 0]).degree(), 0);
+        assert_eq!(ArrayPolynomial::<i32, 0>::new([]).degree(), 0);
+
+        assert_eq!(
+            ArrayPolynomial::<i32, 3>::try_from(std_poly.clone()),
+            Ok(poly)
+        );
+        assert_eq!(
+            ArrayPolynomial::<i32, 2>::try_from(std_poly.clone()),
+            Err(std_poly)
+        );
+    }
+}
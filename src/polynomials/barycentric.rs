@@ -0,0 +1,185 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+use crate::{
+    check_duplicate_inputs, Error, Evaluation, LagrangeBasis, LagrangePolynomial, Limits,
+    Polynomial, UnsafeDiv,
+};
+
+/// Represents an interpolated polynomial in the
+/// [barycentric form](https://en.wikipedia.org/wiki/Lagrange_polynomial#Barycentric_form)
+/// of Lagrange interpolation:
+///
+/// ```notrust
+/// f(x) = l(x) * ∑ (w_i / (x - x_i)) * y_i
+/// ```
+///
+/// where `l(x) = ∏ (x - x_i)`. The weights `w_i` are computed once on construction in
+/// `O(t^2)` operations and held in a [`LagrangeBasis`], after which each evaluation
+/// takes `O(t)` operations and one division. Use this instead of [`LagrangePolynomial`]
+/// to issue many shares from an interpolated polynomial.
+pub struct BarycentricPolynomial<I, O> {
+    evaluations: Vec<Evaluation<I, O>>,
+    basis: LagrangeBasis<I>,
+}
+
+impl<I, O> BarycentricPolynomial<I, O>
+where
+    I: Copy
+        + PartialEq
+        + num_traits::One
+        + num_traits::Zero
+        + Sub<I, Output = I>
+        + UnsafeDiv<I, Output = I>
+        + Mul<I, Output = I>,
+{
+    /// Precompute the barycentric weights for the given set of evaluations.
+    ///
+    /// Panics if two or more evaluations reuse the same input.
+    pub fn new(evaluations: Vec<Evaluation<I, O>>) -> Self {
+        let basis = LagrangeBasis::new(evaluations.iter().map(|eval| eval.input).collect());
+        BarycentricPolynomial { evaluations, basis }
+    }
+
+    /// Precompute the barycentric weights like [`new`][Self::new], but return
+    /// [`Error::DuplicateInput`] if two or more evaluations reuse the same input, or
+    /// [`Error::LimitExceeded`] if there are more than the default [`Limits`] allow.
+    pub fn try_new(evaluations: Vec<Evaluation<I, O>>) -> Result<Self, Error> {
+        Self::try_new_limited(evaluations, &Limits::default())
+    }
+
+    /// Precompute the barycentric weights like [`try_new`][Self::try_new], but with
+    /// the given limits in place of the defaults.
+    pub fn try_new_limited(
+        evaluations: Vec<Evaluation<I, O>>,
+        limits: &Limits,
+    ) -> Result<Self, Error> {
+        limits
+            .check_evaluations(evaluations.len())
+            .map_err(Error::LimitExceeded)?;
+        check_duplicate_inputs(&evaluations)?;
+        Ok(Self::new(evaluations))
+    }
+
+    /// Returns the Lagrange basis for this polynomial's inputs.
+    pub fn basis(&self) -> &LagrangeBasis<I> {
+        &self.basis
+    }
+
+    /// Returns the evaluations which this polynomial interpolates.
+    pub fn evaluations(&self) -> &[Evaluation<I, O>] {
+        &self.evaluations
+    }
+}
+
+impl<I, O> From<LagrangePolynomial<I, O>> for BarycentricPolynomial<I, O>
+where
+    I: Copy
+        + PartialEq
+        + num_traits::One
+        + num_traits::Zero
+        + Sub<I, Output = I>
+        + UnsafeDiv<I, Output = I>
+        + Mul<I, Output = I>,
+{
+    fn from(mut poly: LagrangePolynomial<I, O>) -> Self {
+        BarycentricPolynomial::new(core::mem::take(&mut poly.evaluations))
+    }
+}
+
+impl<I, O> Polynomial<I, O> for BarycentricPolynomial<I, O>
+where
+    I: Copy
+        + PartialEq
+        + num_traits::One
+        + num_traits::Zero
+        + Sub<I, Output = I>
+        + UnsafeDiv<I, Output = I>
+        + Mul<I, Output = I>,
+    O: Copy + num_traits::Zero + Mul<I, Output = O> + Add<O, Output = O>,
+{
+    fn evaluate(&self, x: I) -> O {
+        self.evaluations
+            .iter()
+            .zip(self.basis.at(x))
+            .fold(O::zero(), |sum, (eval, l)| sum + eval.output * l)
+    }
+
+    fn evaluate_many(&self, xs: &[I]) -> Vec<O> {
+        self.basis
+            .at_many(xs)
+            .into_iter()
+            .map(|ls| {
+                self.evaluations
+                    .iter()
+                    .zip(ls)
+                    .fold(O::zero(), |sum, (eval, l)| sum + eval.output * l)
+            })
+            .collect()
+    }
+
+    fn degree(&self) -> usize {
+        match self.evaluations.len() {
+            0 => 0,
+            n => n - 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_barycentric_evaluate() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(7),
+            MaybeScalar::from(11),
+            MaybeScalar::from(13),
+        ]);
+        let shares: Vec<_> = [4, 1, 9]
+            .into_iter()
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        let barycentric = BarycentricPolynomial::new(shares.clone());
+        for i in 0..12 {
+            let x = MaybeScalar::from(i);
+            assert_eq!(barycentric.evaluate(x), f.evaluate(x));
+        }
+
+        let points = BarycentricPolynomial::new(shares.iter().map(|s| s * secp::G).collect());
+        assert_eq!(
+            points.evaluate(MaybeScalar::Zero),
+            MaybeScalar::from(7) * secp::G
+        );
+
+        assert!(matches!(
+            BarycentricPolynomial::try_new(vec![shares[0], shares[0]]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_barycentric_integers() {
+        let evaluations = vec![
+            Evaluation::new(0i64, 4i64),
+            Evaluation::new(1, 1),
+            Evaluation::new(2, 3),
+        ];
+        let lagrange = LagrangePolynomial::new(evaluations.clone());
+        let expected: Vec<i64> = (-3..6).map(|x| lagrange.evaluate(x)).collect();
+
+        let barycentric = BarycentricPolynomial::from(LagrangePolynomial::new(evaluations));
+        let xs: Vec<i64> = (-3..6).collect();
+        for (&x, &y) in xs.iter().zip(&expected) {
+            assert_eq!(barycentric.evaluate(x), y);
+        }
+        assert_eq!(barycentric.evaluate_many(&xs), expected);
+    }
+}
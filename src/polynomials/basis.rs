@@ -0,0 +1,250 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+use crate::{batch_invert, check_distinct, Error, Limits, UnsafeDiv};
+
+/// The Lagrange basis for a fixed set of distinct inputs `x_i`, which can be reused to
+/// interpolate any number of polynomials evaluated on those same inputs.
+///
+/// The barycentric weights `w_i = 1 / ∏_{j != i} (x_i - x_j)` are computed once on
+/// construction in `O(t^2)` operations and one division. Afterwards, evaluating the basis
+/// at any `x` takes `O(t)` operations and one division. This suits threshold signing,
+/// where the same signer set interpolates many values in a session.
+///
+/// For types which are not [fields][UnsafeDiv::FIELD], such as integers, there are no
+/// exact inverses, so the basis instead divides by each denominator on every evaluation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LagrangeBasis<I> {
+    inputs: Vec<I>,
+    /// The barycentric weights for field types, or otherwise the uninverted
+    /// denominators `∏_{j != i} (x_i - x_j)`.
+    weights: Vec<I>,
+}
+
+impl<I> LagrangeBasis<I>
+where
+    I: Copy
+        + PartialEq
+        + num_traits::One
+        + num_traits::Zero
+        + Sub<I, Output = I>
+        + UnsafeDiv<I, Output = I>
+        + Mul<I, Output = I>,
+{
+    /// Compute the basis for the given inputs.
+    ///
+    /// Panics if the inputs contain duplicates.
+    pub fn new(inputs: Vec<I>) -> Self {
+        let mut weights: Vec<I> = inputs
+            .iter()
+            .enumerate()
+            .map(|(i, &xi)| {
+                let mut bottom = I::one();
+                for (j, &xj) in inputs.iter().enumerate() {
+                    if i != j {
+                        bottom = bottom * (xi - xj);
+                    }
+                }
+                bottom
+            })
+            .collect();
+        if I::FIELD {
+            batch_invert(&mut weights);
+        }
+        LagrangeBasis { inputs, weights }
+    }
+
+    /// Compute the basis like [`new`][Self::new], but return [`Error::DuplicateInput`]
+    /// if the inputs contain duplicates, or [`Error::LimitExceeded`] if there are more
+    /// than the default [`Limits`] allow.
+    pub fn try_new(inputs: Vec<I>) -> Result<Self, Error> {
+        Self::try_new_limited(inputs, &Limits::default())
+    }
+
+    /// Compute the basis like [`try_new`][Self::try_new], but with the given limits
+    /// in place of the defaults.
+    pub fn try_new_limited(inputs: Vec<I>, limits: &Limits) -> Result<Self, Error> {
+        limits
+            .check_evaluations(inputs.len())
+            .map_err(Error::LimitExceeded)?;
+        check_distinct(&inputs)?;
+        Ok(Self::new(inputs))
+    }
+
+    /// Returns the inputs of the basis.
+    pub fn inputs(&self) -> &[I] {
+        &self.inputs
+    }
+
+    /// Evaluate every Lagrange basis polynomial at `x`, in the same order as the inputs.
+    pub fn at(&self, x: I) -> Vec<I> {
+        if let Some(k) = self.inputs.iter().position(|&xi| xi == x) {
+            return (0..self.inputs.len())
+                .map(|i| if i == k { I::one() } else { I::zero() })
+                .collect();
+        }
+
+        if !I::FIELD {
+            return self.divided_at(x);
+        }
+
+        // l_i(x) = l(x) * w_i / (x - x_i), where l(x) = ∏ (x - x_j)
+        let mut basis: Vec<I> = self.inputs.iter().map(|&xi| x - xi).collect();
+        let l = basis.iter().fold(I::one(), |l, &d| l * d);
+        batch_invert(&mut basis);
+        for (b, &w) in basis.iter_mut().zip(&self.weights) {
+            *b = l * w * *b;
+        }
+        basis
+    }
+
+    /// Evaluate every Lagrange basis polynomial at each of the given points, like
+    /// [`at`][Self::at]. All the divisions are shared in one batch inversion.
+    pub fn at_many(&self, xs: &[I]) -> Vec<Vec<I>> {
+        // Sharing divisions relies on exact inverses.
+        if !I::FIELD {
+            return xs.iter().map(|&x| self.at(x)).collect();
+        }
+        let n = self.inputs.len();
+        let matches: Vec<Option<usize>> = xs
+            .iter()
+            .map(|&x| self.inputs.iter().position(|&xi| xi == x))
+            .collect();
+
+        let mut differences = Vec::with_capacity(xs.len() * n);
+        let mut products = Vec::with_capacity(xs.len());
+        for (&x, k) in xs.iter().zip(&matches) {
+            if k.is_none() {
+                let start = differences.len();
+                differences.extend(self.inputs.iter().map(|&xi| x - xi));
+                products.push(differences[start..].iter().fold(I::one(), |l, &d| l * d));
+            }
+        }
+        batch_invert(&mut differences);
+
+        let mut inverses = differences.chunks(n.max(1));
+        let mut products = products.into_iter();
+        matches
+            .into_iter()
+            .map(|k| match k {
+                Some(k) => (0..n)
+                    .map(|i| if i == k { I::one() } else { I::zero() })
+                    .collect(),
+                None => {
+                    let l = products.next().unwrap_or_else(I::one);
+                    let inverses = inverses.next().unwrap_or(&[]);
+                    inverses
+                        .iter()
+                        .zip(&self.weights)
+                        .map(|(&inverse, &w)| l * w * inverse)
+                        .collect()
+                }
+            })
+            .collect()
+    }
+
+    /// Evaluate every basis polynomial at `x` by dividing each numerator
+    /// `∏_{j != i} (x - x_j)` by its denominator, for types without exact inverses.
+    fn divided_at(&self, x: I) -> Vec<I> {
+        let n = self.inputs.len();
+        let mut suffix_products = alloc::vec![I::one(); n + 1];
+        for j in (0..n).rev() {
+            suffix_products[j] = suffix_products[j + 1] * (x - self.inputs[j]);
+        }
+
+        let mut prefix_product = I::one();
+        let mut basis = Vec::with_capacity(n);
+        for (i, (&xi, &denominator)) in self.inputs.iter().zip(&self.weights).enumerate() {
+            let numerator = prefix_product * suffix_products[i + 1];
+            basis.push(I::unsafe_div(numerator, denominator));
+            prefix_product = prefix_product * (x - xi);
+        }
+        basis
+    }
+
+    /// Interpolate the polynomial which outputs `outputs[i]` at each input `x_i`, and
+    /// evaluate it at `x`.
+    ///
+    /// Panics if the number of outputs does not match the number of inputs.
+    pub fn interpolate<O>(&self, outputs: &[O], x: I) -> O
+    where
+        O: Copy + num_traits::Zero + Mul<I, Output = O> + Add<O, Output = O>,
+    {
+        assert_eq!(
+            outputs.len(),
+            self.inputs.len(),
+            "number of outputs must match the number of basis inputs"
+        );
+        outputs
+            .iter()
+            .zip(self.at(x))
+            .fold(O::zero(), |sum, (&y, l)| sum + y * l)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LagrangePolynomial, Polynomial, SecretSharingPolynomial};
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_lagrange_basis_reuse() {
+        let inputs: Vec<MaybeScalar> = [2, 5, 7].into_iter().map(MaybeScalar::from).collect();
+        let basis = LagrangeBasis::new(inputs.clone());
+
+        for seed in 1..4 {
+            let f = SecretSharingPolynomial::new(vec![
+                MaybeScalar::from(seed),
+                MaybeScalar::from(seed * 3),
+                MaybeScalar::from(seed * 5),
+            ]);
+            let outputs: Vec<MaybeScalar> = inputs.iter().map(|&x| f.evaluate(x)).collect();
+            for x in [0, 2, 11] {
+                let x = MaybeScalar::from(x);
+                assert_eq!(basis.interpolate(&outputs, x), f.evaluate(x));
+            }
+
+            let shares = inputs.iter().map(|&x| f.issue_share(x)).collect();
+            let x = MaybeScalar::from(13);
+            assert_eq!(basis.at(x), LagrangePolynomial::new(shares).basis_at(x));
+
+            let xs: Vec<MaybeScalar> = [13, 5, 0].into_iter().map(MaybeScalar::from).collect();
+            let many = basis.at_many(&xs);
+            for (&x, b) in xs.iter().zip(&many) {
+                assert_eq!(*b, basis.at(x));
+            }
+        }
+
+        assert_eq!(
+            LagrangeBasis::try_new(vec![MaybeScalar::from(1), MaybeScalar::from(1)]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_lagrange_basis_integers() {
+        // f(x) = 3x^2 + 2x + 5
+        let f = |x: i64| 3 * x * x + 2 * x + 5;
+        let inputs = vec![1i64, 2, 3];
+        let outputs: Vec<i64> = inputs.iter().map(|&x| f(x)).collect();
+        let basis = LagrangeBasis::new(inputs.clone());
+
+        let evaluations = inputs
+            .iter()
+            .zip(&outputs)
+            .map(|(&x, &y)| crate::Evaluation::new(x, y))
+            .collect();
+        let poly = LagrangePolynomial::new(evaluations);
+
+        for x in [0, 1, 4, -2] {
+            assert_eq!(basis.at(x), poly.basis_at(x));
+            assert_eq!(basis.at_many(&[x]), vec![poly.basis_at(x)]);
+        }
+        assert_eq!(basis.at(0), vec![3, -3, 1]);
+        assert_eq!(basis.interpolate(&outputs, 0), f(0));
+    }
+}
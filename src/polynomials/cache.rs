@@ -0,0 +1,305 @@
+use crate::{batch_invert, check_duplicate_inputs, Error, Evaluation, Limits};
+use alloc::{vec, vec::Vec};
+use secp::MaybeScalar;
+
+use alloc::collections::BTreeMap;
+use core::ops::{Add, Mul};
+
+/// Compute the Lagrange coefficients `λ_i` for evaluating a polynomial at zero,
+/// given the set of inputs `x_i` at which it has been evaluated:
+///
+/// ```notrust
+/// λ_i = ∏_{j != i} x_j / (x_j - x_i)
+/// ```
+///
+/// The value at zero of the polynomial through evaluations `(x_i, y_i)` is then
+/// `∑ λ_i * y_i`. The numerators are computed from running products taken from either
+/// end, and all denominators are inverted together with a single batch inversion.
+///
+/// Panics if the inputs contain duplicates.
+pub fn lagrange_coefficients_at_zero(inputs: &[MaybeScalar]) -> Vec<MaybeScalar> {
+    let mut denominators: Vec<MaybeScalar> = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, &xi)| {
+            let mut bottom = MaybeScalar::one();
+            for (j, &xj) in inputs.iter().enumerate() {
+                if i != j {
+                    bottom *= xj - xi;
+                }
+            }
+            bottom
+        })
+        .collect();
+    if denominators.iter().any(|d| d.is_zero()) {
+        panic!("shares include duplicate evaluation inputs");
+    }
+    batch_invert(&mut denominators);
+
+    // suffix[i] = x_{i+1} * ... * x_{n-1}
+    let mut suffix = vec![MaybeScalar::one(); inputs.len()];
+    for i in (1..inputs.len()).rev() {
+        suffix[i - 1] = suffix[i] * inputs[i];
+    }
+
+    let mut prefix = MaybeScalar::one();
+    let mut coefficients = Vec::with_capacity(inputs.len());
+    for ((&xi, &inverse), &after) in inputs.iter().zip(&denominators).zip(&suffix) {
+        coefficients.push(prefix * after * inverse);
+        prefix *= xi;
+    }
+    coefficients
+}
+
+/// Interpolate the value at zero of the polynomial passing through the given
+/// evaluations, using [`lagrange_coefficients_at_zero`]. This is the fast path for
+/// secret recovery and signature aggregation, which always evaluate at zero.
+///
+/// Panics if the evaluations contain duplicate inputs.
+pub fn interpolate_at_zero<O>(evaluations: &[Evaluation<MaybeScalar, O>]) -> O
+where
+    O: Copy + num_traits::Zero + Mul<MaybeScalar, Output = O> + Add<O, Output = O>,
+{
+    let inputs: Vec<MaybeScalar> = evaluations.iter().map(|eval| eval.input).collect();
+    evaluations
+        .iter()
+        .zip(lagrange_coefficients_at_zero(&inputs))
+        .fold(O::zero(), |sum, (eval, lambda)| sum + eval.output * lambda)
+}
+
+/// Interpolate the value at zero like [`interpolate_at_zero`], but first check the
+/// evaluations against the given limits, returning [`Error::LimitExceeded`] if there
+/// are too many, or [`Error::DuplicateInput`] instead of panicking if two evaluations
+/// share the same input. Use this for evaluations which come from untrusted sources.
+pub fn interpolate_at_zero_limited<O>(
+    evaluations: &[Evaluation<MaybeScalar, O>],
+    limits: &Limits,
+) -> Result<O, Error>
+where
+    O: Copy + num_traits::Zero + Mul<MaybeScalar, Output = O> + Add<O, Output = O>,
+{
+    check_evaluations_limited(evaluations, limits)?;
+    Ok(interpolate_at_zero(evaluations))
+}
+
+fn check_evaluations_limited<O>(
+    evaluations: &[Evaluation<MaybeScalar, O>],
+    limits: &Limits,
+) -> Result<(), Error> {
+    limits
+        .check_evaluations(evaluations.len())
+        .map_err(Error::LimitExceeded)?;
+    check_duplicate_inputs(evaluations)
+}
+
+/// The default maximum number of input sets held by a [`LagrangeCoefficientCache`].
+pub const DEFAULT_LAGRANGE_CACHE_ENTRIES: usize = 1024;
+
+/// A lazily-populated cache of Lagrange coefficients for evaluating at zero,
+/// keyed by the set of inputs used.
+///
+/// Groups which issue shares at standard consecutive indices `1..=n` tend to
+/// see the same subsets of shareholders over and over again, across repeated
+/// secret recoveries and partial signature aggregations. This cache computes the
+/// coefficients for each distinct input set once, and reuses them afterwards,
+/// regardless of the order in which the inputs are given.
+///
+/// The cache holds at most a fixed number of input sets, so that callers who accept
+/// shares from untrusted parties cannot grow it without bound. Once full, an
+/// arbitrary entry is evicted for each new input set.
+#[derive(Clone, Debug)]
+pub struct LagrangeCoefficientCache {
+    /// Coefficients in the order of the sorted input encodings used as the key.
+    cache: BTreeMap<Vec<[u8; 32]>, Vec<MaybeScalar>>,
+    max_entries: usize,
+}
+
+impl Default for LagrangeCoefficientCache {
+    fn default() -> Self {
+        Self::with_max_entries(DEFAULT_LAGRANGE_CACHE_ENTRIES)
+    }
+}
+
+impl LagrangeCoefficientCache {
+    /// Construct an empty cache holding at most [`DEFAULT_LAGRANGE_CACHE_ENTRIES`]
+    /// input sets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct an empty cache holding at most `max_entries` input sets.
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        LagrangeCoefficientCache {
+            cache: BTreeMap::new(),
+            max_entries,
+        }
+    }
+
+    /// Returns the number of distinct input sets currently cached.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns true if no input sets have been cached yet.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Returns the Lagrange coefficients for evaluating at zero, in the same order
+    /// as the given inputs, computing and caching them if needed. Any permutation
+    /// of a cached input set is served from the cache.
+    ///
+    /// Panics if the inputs contain duplicates.
+    pub fn coefficients_at_zero(&mut self, inputs: &[MaybeScalar]) -> Vec<MaybeScalar> {
+        let encodings: Vec<[u8; 32]> = inputs.iter().map(|x| x.serialize()).collect();
+        let mut order: Vec<usize> = (0..inputs.len()).collect();
+        order.sort_by(|&a, &b| encodings[a].cmp(&encodings[b]));
+        let key: Vec<[u8; 32]> = order.iter().map(|&i| encodings[i]).collect();
+
+        if !self.cache.contains_key(&key) {
+            let sorted_inputs: Vec<MaybeScalar> = order.iter().map(|&i| inputs[i]).collect();
+            let coefficients = lagrange_coefficients_at_zero(&sorted_inputs);
+            if self.max_entries == 0 {
+                return in_caller_order(&order, &coefficients);
+            }
+            if self.cache.len() >= self.max_entries {
+                self.cache.pop_first();
+            }
+            self.cache.insert(key.clone(), coefficients);
+        }
+        in_caller_order(&order, &self.cache[&key])
+    }
+
+    /// Interpolate the value at zero of the polynomial passing through the given
+    /// evaluations, using cached coefficients where possible.
+    ///
+    /// Panics if the evaluations contain duplicate inputs.
+    pub fn interpolate_at_zero<O>(&mut self, evaluations: &[Evaluation<MaybeScalar, O>]) -> O
+    where
+        O: Copy + num_traits::Zero + Mul<MaybeScalar, Output = O> + Add<O, Output = O>,
+    {
+        let inputs: Vec<MaybeScalar> = evaluations.iter().map(|eval| eval.input).collect();
+        let coefficients = self.coefficients_at_zero(&inputs);
+
+        evaluations
+            .iter()
+            .zip(coefficients)
+            .fold(O::zero(), |sum, (eval, lambda)| sum + eval.output * lambda)
+    }
+
+    /// Interpolate the value at zero like [`interpolate_at_zero`][Self::interpolate_at_zero],
+    /// but first check the evaluations against the given limits, returning
+    /// [`Error::LimitExceeded`] if there are too many, or [`Error::DuplicateInput`]
+    /// instead of panicking if two evaluations share the same input. Nothing is
+    /// cached for rejected evaluations.
+    pub fn interpolate_at_zero_limited<O>(
+        &mut self,
+        evaluations: &[Evaluation<MaybeScalar, O>],
+        limits: &Limits,
+    ) -> Result<O, Error>
+    where
+        O: Copy + num_traits::Zero + Mul<MaybeScalar, Output = O> + Add<O, Output = O>,
+    {
+        check_evaluations_limited(evaluations, limits)?;
+        Ok(self.interpolate_at_zero(evaluations))
+    }
+}
+
+/// Rearrange coefficients given in sorted input order back into the caller's
+/// order, where `order[k]` is the caller's index of the `k`-th sorted input.
+fn in_caller_order(order: &[usize], sorted: &[MaybeScalar]) -> Vec<MaybeScalar> {
+    let mut coefficients = vec![MaybeScalar::Zero; order.len()];
+    for (&i, &lambda) in order.iter().zip(sorted) {
+        coefficients[i] = lambda;
+    }
+    coefficients
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InterpolatedSecretPolynomial, Polynomial, SecretSharingPolynomial};
+
+    #[test]
+    fn test_lagrange_coefficient_cache() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(123),
+            MaybeScalar::from(456),
+            MaybeScalar::from(789),
+        ]);
+        let shares: Vec<_> = (1..=5)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        let mut cache = LagrangeCoefficientCache::new();
+        assert!(cache.is_empty());
+
+        for subset in [&shares[0..3], &shares[2..5], &shares[0..3], &shares[1..5]] {
+            assert_eq!(cache.interpolate_at_zero(subset), MaybeScalar::from(123));
+            assert_eq!(
+                cache.interpolate_at_zero(subset),
+                InterpolatedSecretPolynomial::new(subset.to_vec()).evaluate(MaybeScalar::Zero)
+            );
+        }
+        assert_eq!(cache.len(), 3);
+
+        let point_shares: Vec<_> = shares.iter().map(|share| share * secp::G).collect();
+        assert_eq!(
+            cache.interpolate_at_zero(&point_shares[0..3]),
+            MaybeScalar::from(123) * secp::G
+        );
+        assert_eq!(cache.len(), 3);
+
+        // Permutations of a cached input set hit the same entry, and the
+        // coefficients follow the caller's order.
+        let permuted = [shares[2], shares[0], shares[1]];
+        assert_eq!(cache.interpolate_at_zero(&permuted), MaybeScalar::from(123));
+        let inputs: Vec<_> = permuted.iter().map(|share| share.input).collect();
+        assert_eq!(
+            cache.coefficients_at_zero(&inputs),
+            lagrange_coefficients_at_zero(&inputs)
+        );
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn test_lagrange_coefficient_cache_bounded() {
+        let inputs: Vec<MaybeScalar> = (1..=6).map(MaybeScalar::from).collect();
+        let mut cache = LagrangeCoefficientCache::with_max_entries(2);
+        for subset in inputs.windows(3) {
+            assert_eq!(
+                cache.coefficients_at_zero(subset),
+                lagrange_coefficients_at_zero(subset)
+            );
+        }
+        assert_eq!(cache.len(), 2);
+
+        let mut disabled = LagrangeCoefficientCache::with_max_entries(0);
+        assert_eq!(
+            disabled.coefficients_at_zero(&inputs),
+            lagrange_coefficients_at_zero(&inputs)
+        );
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_interpolate_at_zero() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(123),
+            MaybeScalar::from(456),
+            MaybeScalar::from(789),
+        ]);
+        let shares: Vec<_> = [5, 1, 9, 2]
+            .into_iter()
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        let inputs: Vec<_> = shares.iter().map(|share| share.input).collect();
+        assert_eq!(
+            lagrange_coefficients_at_zero(&inputs),
+            InterpolatedSecretPolynomial::new(shares.clone()).basis_at(MaybeScalar::Zero)
+        );
+        assert_eq!(interpolate_at_zero(&shares), MaybeScalar::from(123));
+        assert_eq!(interpolate_at_zero(&shares[1..]), MaybeScalar::from(123));
+    }
+}
@@ -1,6 +1,7 @@
 /// Represents a polynomial evaluation at a certain input and output, which
 /// may be of different types.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Evaluation<I, O> {
     /// The input `x` value which is fed into a polynomial function.
     pub input: I,
@@ -1,17 +1,43 @@
-use crate::{Evaluation, Polynomial};
-use std::ops::{Add, Mul, Sub};
+use crate::{
+    horner_poly_evaluate, Error, Evaluation, LagrangeBasis, Limits, Polynomial,
+    StandardFormPolynomial,
+};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
+use k256::elliptic_curve::group::GroupEncoding;
+use k256::elliptic_curve::PrimeField;
+use secp::{MaybePoint, MaybeScalar};
+use subtle::{Choice, ConditionallySelectable};
 
-/// [`secp::MaybeScalar`] does not implement [`std::ops::Div`] on itself
+/// [`secp::MaybeScalar`] does not implement [`core::ops::Div`] on itself
 /// for safety reasons. The `UnsafeDiv` trait explicitly works around this.
 pub trait UnsafeDiv<T> {
     type Output;
 
+    /// Whether `Self` is a field, in which every non-zero denominator has an exact
+    /// inverse. Field types set this to `true` so that many divisions can share a
+    /// single inversion with `batch_invert`. Integer types keep the default, since
+    /// `1 / d` truncates to zero for them.
+    const FIELD: bool = false;
+
     fn unsafe_div(num: Self, denom: T) -> Self::Output;
+
+    /// Divide `num` by `denom`, returning [`Error::DivisionByZero`] instead of
+    /// panicking if `denom` is zero.
+    fn try_div(num: Self, denom: T) -> Result<Self::Output, Error>
+    where
+        Self: Sized,
+        T: num_traits::Zero,
+    {
+        if denom.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Self::unsafe_div(num, denom))
+    }
 }
 
 mod unsafe_div_impls {
     use super::*;
-    use secp::MaybeScalar;
 
     macro_rules! impl_unsafe_div {
         ( $($t:ty),* ) => {
@@ -34,6 +60,7 @@ mod unsafe_div_impls {
 
     impl UnsafeDiv<MaybeScalar> for MaybeScalar {
         type Output = MaybeScalar;
+        const FIELD: bool = true;
 
         fn unsafe_div(num: MaybeScalar, denom: MaybeScalar) -> Self::Output {
             match denom {
@@ -44,14 +71,67 @@ mod unsafe_div_impls {
     }
 }
 
-/// Evaluate a [Lagrange basis polynomial](https://en.wikipedia.org/wiki/Lagrange_polynomial).
+/// Invert every element of `values` in place using [Montgomery's trick], which
+/// performs a single division for the whole slice plus `3 * (n - 1)` multiplications.
+///
+/// Panics if any element is zero. Only meaningful for types which are fields; use
+/// [`divide_all`] in code which is generic over [`UnsafeDiv`].
+///
+/// [Montgomery's trick]: https://en.wikipedia.org/wiki/Modular_multiplicative_inverse#Multiple_inverses
+pub(crate) fn batch_invert<I>(values: &mut [I])
+where
+    I: Copy + num_traits::One + Mul<I, Output = I> + UnsafeDiv<I, Output = I>,
+{
+    // prefix_products[i] = values[0] * ... * values[i - 1]
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut acc = I::one();
+    for &v in values.iter() {
+        prefix_products.push(acc);
+        acc = acc * v;
+    }
+
+    // The only division: acc^-1 = (values[0] * ... * values[n - 1])^-1
+    let mut inverse = I::unsafe_div(I::one(), acc);
+
+    for i in (0..values.len()).rev() {
+        let v = values[i];
+        values[i] = inverse * prefix_products[i];
+        inverse = inverse * v;
+    }
+}
+
+/// Divide every element of `numerators` in place by the corresponding element of
+/// `denominators`.
+///
+/// For [field types][UnsafeDiv::FIELD], the denominators are inverted together with
+/// [`batch_invert`], so only one division is performed. Other types, such as integers,
+/// divide each numerator by its denominator separately.
+pub(crate) fn divide_all<I>(numerators: &mut [I], mut denominators: Vec<I>)
+where
+    I: Copy + num_traits::One + Mul<I, Output = I> + UnsafeDiv<I, Output = I>,
+{
+    debug_assert_eq!(numerators.len(), denominators.len());
+    if I::FIELD {
+        batch_invert(&mut denominators);
+        for (n, d) in numerators.iter_mut().zip(denominators) {
+            *n = *n * d;
+        }
+    } else {
+        for (n, d) in numerators.iter_mut().zip(denominators) {
+            *n = I::unsafe_div(*n, d);
+        }
+    }
+}
+
+/// Evaluate every [Lagrange basis polynomial](https://en.wikipedia.org/wiki/Lagrange_polynomial)
+/// for the given set of evaluations at input `x`.
 ///
-/// This function returns:
-/// - `0` if `x == evaluations[eval_index].input`
-/// - `1` if `x == evaluations[i].input` for any other `i != eval_index`
+/// The `i`-th element of the output is `1` if `x == evaluations[i].input`, and `0`
+/// if `x == evaluations[j].input` for any other `j != i`.
 ///
-/// The output is unpredictable for inputs which are not part of `evaluations`.
-fn langrange_poly_evaluate<I, O>(evaluations: &[Evaluation<I, O>], eval_index: usize, x: I) -> I
+/// The basis numerators and denominators are divided with [`divide_all`], so this
+/// performs one division for field types regardless of the number of evaluations.
+fn lagrange_basis<I, O>(evaluations: &[Evaluation<I, O>], x: I) -> Vec<I>
 where
     I: Copy
         + PartialEq
@@ -61,46 +141,55 @@ where
         + UnsafeDiv<I, Output = I>
         + Mul<I, Output = I>,
 {
-    let xj = evaluations[eval_index].input;
-
     // Short-circuit for efficiency.
-    if x == xj {
-        return I::one();
+    if let Some(k) = evaluations.iter().position(|eval| eval.input == x) {
+        return (0..evaluations.len())
+            .map(|i| if i == k { I::one() } else { I::zero() })
+            .collect();
     }
 
-    // For efficiency we compute the numerator and denominator of the lagrange polynomial separately.
-    let mut top = I::one();
-    let mut bottom = I::one();
-
-    for (i, eval) in evaluations.into_iter().enumerate() {
-        if i == eval_index {
-            continue;
-        }
-
-        top = top * (x - eval.input);
-
-        // Short circuit for efficiency.
-        if top.is_zero() {
-            return top;
-        }
+    let denominators: Vec<I> = evaluations
+        .iter()
+        .enumerate()
+        .map(|(i, eval_i)| {
+            let mut bottom = I::one();
+            for (j, eval_j) in evaluations.iter().enumerate() {
+                if i != j {
+                    bottom = bottom * (eval_i.input - eval_j.input);
+                }
+            }
 
-        bottom = bottom * (xj - eval.input);
+            // Invariant
+            debug_assert!(
+                !bottom.is_zero(),
+                "shares include duplicate evaluation inputs, causing div-by-zero error"
+            );
+            bottom
+        })
+        .collect();
 
-        // Invariant
-        debug_assert!(
-            !bottom.is_zero(),
-            "shares include duplicate evaluation inputs, causing div-by-zero error"
-        );
+    // The numerator of the i-th basis polynomial is the product of (x - x_j) for all
+    // j != i, which we compute from running products taken from either end.
+    let mut suffix_products = vec![I::one(); evaluations.len() + 1];
+    for j in (0..evaluations.len()).rev() {
+        suffix_products[j] = suffix_products[j + 1] * (x - evaluations[j].input);
     }
 
-    // top / bottom
-    I::unsafe_div(top, bottom)
+    let mut prefix_product = I::one();
+    let mut basis = Vec::with_capacity(evaluations.len());
+    for (i, eval) in evaluations.iter().enumerate() {
+        basis.push(prefix_product * suffix_products[i + 1]);
+        prefix_product = prefix_product * (x - eval.input);
+    }
+    divide_all(&mut basis, denominators);
+    basis
 }
 
 /// Represents a polynomial which can be evaluated using [Lagrange Interpolation]
 /// on a set of evaluations.
 ///
 /// [Lagrange Interpolation]: https://en.wikipedia.org/wiki/Lagrange_polynomial
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LagrangePolynomial<I, O> {
     pub evaluations: Vec<Evaluation<I, O>>,
 }
@@ -111,9 +200,424 @@ impl<I, O> LagrangePolynomial<I, O> {
     /// The evaluations are expected to have distinct input values.
     /// If two or more evaluations reuse the same input, evaluation and
     /// share-issuance will cause panics.
+    ///
+    /// No [`Limits`] are applied, so this should only be used with trusted evaluations.
+    /// Use [`try_new`][Self::try_new] or [`new_limited`][Self::new_limited] for
+    /// evaluations which come from untrusted sources.
     pub fn new(evaluations: Vec<Evaluation<I, O>>) -> Self {
         Self { evaluations }
     }
+
+    /// Construct a Lagrange Polynomial like [`new`][Self::new], but return
+    /// [`Error::DuplicateInput`] if two or more evaluations reuse the same input,
+    /// or [`Error::LimitExceeded`] if there are more than the default [`Limits`] allow.
+    /// Use this for evaluations which come from untrusted sources.
+    pub fn try_new(evaluations: Vec<Evaluation<I, O>>) -> Result<Self, Error>
+    where
+        I: PartialEq,
+    {
+        Self::try_new_limited(evaluations, &Limits::default())
+    }
+
+    /// Construct a Lagrange Polynomial like [`try_new`][Self::try_new], but with the
+    /// given limits in place of the defaults.
+    pub fn try_new_limited(
+        evaluations: Vec<Evaluation<I, O>>,
+        limits: &Limits,
+    ) -> Result<Self, Error>
+    where
+        I: PartialEq,
+    {
+        limits
+            .check_evaluations(evaluations.len())
+            .map_err(Error::LimitExceeded)?;
+        check_duplicate_inputs(&evaluations)?;
+        Ok(Self { evaluations })
+    }
+
+    /// Construct a Lagrange Polynomial like [`try_new`][Self::try_new], but also
+    /// return [`Error::ZeroInput`] if any evaluation has a zero input. A share
+    /// issued at zero is the secret itself, so this usually indicates a mistake.
+    pub fn try_new_nonzero(evaluations: Vec<Evaluation<I, O>>) -> Result<Self, Error>
+    where
+        I: PartialEq + num_traits::Zero,
+    {
+        if let Some(index) = evaluations.iter().position(|eval| eval.input.is_zero()) {
+            return Err(Error::ZeroInput { index });
+        }
+        Self::try_new(evaluations)
+    }
+
+    /// Evaluate every Lagrange basis polynomial for this polynomial's set of evaluation
+    /// inputs at `x`. The output of the polynomial at `x` is the sum of each evaluation
+    /// output multiplied by the corresponding basis value.
+    pub fn basis_at(&self, x: I) -> Vec<I>
+    where
+        I: Copy
+            + PartialEq
+            + num_traits::One
+            + num_traits::Zero
+            + Sub<I, Output = I>
+            + UnsafeDiv<I, Output = I>
+            + Mul<I, Output = I>,
+    {
+        lagrange_basis(&self.evaluations, x)
+    }
+}
+
+/// An output type which [`LagrangePolynomial::evaluate_hardened`] can accumulate
+/// without branching on secret data, by converting it to and from its `k256`
+/// representation.
+pub trait HardenedOutput: Copy {
+    /// The `k256` type on which the constant-time arithmetic is done.
+    type Repr: Copy
+        + Default
+        + ConditionallySelectable
+        + Add<Self::Repr, Output = Self::Repr>
+        + Mul<k256::Scalar, Output = Self::Repr>;
+
+    fn to_repr(self) -> Self::Repr;
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+impl HardenedOutput for MaybeScalar {
+    type Repr = k256::Scalar;
+
+    fn to_repr(self) -> k256::Scalar {
+        Option::from(k256::Scalar::from_repr(self.serialize().into()))
+            .expect("serialized scalars are always reduced")
+    }
+
+    fn from_repr(repr: k256::Scalar) -> Self {
+        MaybeScalar::reduce_from(&repr.to_bytes().into())
+    }
+}
+
+impl HardenedOutput for MaybePoint {
+    type Repr = k256::ProjectivePoint;
+
+    fn to_repr(self) -> k256::ProjectivePoint {
+        match self {
+            MaybePoint::Infinity => k256::ProjectivePoint::IDENTITY,
+            MaybePoint::Valid(p) => Option::<k256::AffinePoint>::from(
+                k256::AffinePoint::from_bytes(&p.serialize().into()),
+            )
+            .expect("serialized points are always on the curve")
+            .into(),
+        }
+    }
+
+    fn from_repr(repr: k256::ProjectivePoint) -> Self {
+        // The identity encodes as 33 zero bytes, which decodes as infinity.
+        MaybePoint::from_slice(&repr.to_affine().to_bytes())
+            .expect("encoded points are always valid")
+    }
+}
+
+impl<O: HardenedOutput> LagrangePolynomial<MaybeScalar, O> {
+    /// Evaluate the polynomial on a given input, using a hardened code path
+    /// intended for environments where an attacker may observe the timing of
+    /// reconstruction, such as HSMs and enclaves.
+    ///
+    /// Unlike [`Polynomial::evaluate`], this method never short-circuits when `x` is
+    /// equal to one of the evaluation inputs, or when a partial product is zero.
+    /// Every Lagrange basis polynomial is computed in full with `k256` scalar
+    /// arithmetic, which is constant-time, and the outputs are accumulated with
+    /// their `k256` representations.
+    ///
+    /// To avoid leaking the number of shares used, the caller may pass a `padded_len`
+    /// greater than the number of evaluations. The outer loop then runs `padded_len`
+    /// times, and so does the inner loop of every basis polynomial. The extra rounds
+    /// are dummy evaluations which repeat the work of a real one, and whose weights
+    /// and factors are discarded with constant-time selects, so the amount of work
+    /// done depends only on `padded_len`. If `padded_len` is smaller than the number
+    /// of evaluations, no padding is performed.
+    ///
+    /// Panics if two or more evaluations reuse the same input.
+    pub fn evaluate_hardened(&self, x: MaybeScalar, padded_len: usize) -> O {
+        let n_evals = self.evaluations.len();
+        if n_evals == 0 {
+            return O::from_repr(O::Repr::default());
+        }
+        let rounds = n_evals.max(padded_len);
+
+        let x = x.to_repr();
+        let inputs: Vec<k256::Scalar> = self
+            .evaluations
+            .iter()
+            .map(|eval| eval.input.to_repr())
+            .collect();
+
+        let mut out = O::Repr::default();
+        for i in 0..rounds {
+            let real = Choice::from((i < n_evals) as u8);
+            let i = i % n_evals;
+            let xi = inputs[i];
+
+            let mut top = k256::Scalar::ONE;
+            let mut bottom = k256::Scalar::ONE;
+            for k in 0..rounds {
+                let skip = Choice::from((k == i) as u8) | Choice::from((k >= n_evals) as u8);
+                let xk = inputs[k % n_evals];
+                top *= k256::Scalar::conditional_select(&(x - xk), &k256::Scalar::ONE, skip);
+                bottom *= k256::Scalar::conditional_select(&(xi - xk), &k256::Scalar::ONE, skip);
+            }
+
+            // The denominator depends only on the public evaluation inputs.
+            let inverse: k256::Scalar =
+                Option::from(bottom.invert()).expect("shares include duplicate evaluation inputs");
+            let weight =
+                k256::Scalar::conditional_select(&k256::Scalar::ZERO, &(top * inverse), real);
+            out = out + self.evaluations[i].output.to_repr() * weight;
+        }
+
+        O::from_repr(out)
+    }
+}
+
+/// Returns an error if `inputs` contains duplicates.
+pub(crate) fn check_distinct<I: PartialEq>(inputs: &[I]) -> Result<(), Error> {
+    for (second, x) in inputs.iter().enumerate() {
+        if let Some(first) = inputs[..second].iter().position(|other| other == x) {
+            return Err(Error::DuplicateInput { first, second });
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error if `evaluations` contains duplicate inputs.
+pub(crate) fn check_duplicate_inputs<I: PartialEq, O>(
+    evaluations: &[Evaluation<I, O>],
+) -> Result<(), Error> {
+    for (second, eval) in evaluations.iter().enumerate() {
+        if let Some(first) = evaluations[..second]
+            .iter()
+            .position(|other| other.input == eval.input)
+        {
+            return Err(Error::DuplicateInput { first, second });
+        }
+    }
+    Ok(())
+}
+
+/// Interpolate the polynomial passing through a set of evaluations, and evaluate it
+/// at input `x`.
+///
+/// Unlike evaluating a [`LagrangePolynomial`], this returns an error instead of
+/// panicking if there are no evaluations, or if two evaluations share the same input.
+/// Returns [`Error::LimitExceeded`] if there are more evaluations than the default
+/// [`Limits`] allow.
+pub fn try_interpolate<I, O>(evaluations: &[Evaluation<I, O>], x: I) -> Result<O, Error>
+where
+    I: Copy
+        + PartialEq
+        + num_traits::One
+        + num_traits::Zero
+        + Sub<I, Output = I>
+        + UnsafeDiv<I, Output = I>
+        + Mul<I, Output = I>,
+    O: Copy + num_traits::Zero + Mul<I, Output = O> + Add<O, Output = O>,
+{
+    try_interpolate_limited(evaluations, x, &Limits::default())
+}
+
+/// Interpolate and evaluate like [`try_interpolate`], but with the given limits in
+/// place of the defaults.
+pub fn try_interpolate_limited<I, O>(
+    evaluations: &[Evaluation<I, O>],
+    x: I,
+    limits: &Limits,
+) -> Result<O, Error>
+where
+    I: Copy
+        + PartialEq
+        + num_traits::One
+        + num_traits::Zero
+        + Sub<I, Output = I>
+        + UnsafeDiv<I, Output = I>
+        + Mul<I, Output = I>,
+    O: Copy + num_traits::Zero + Mul<I, Output = O> + Add<O, Output = O>,
+{
+    if evaluations.is_empty() {
+        return Err(Error::Empty);
+    }
+    limits
+        .check_evaluations(evaluations.len())
+        .map_err(Error::LimitExceeded)?;
+    check_duplicate_inputs(evaluations)?;
+    let mut out = O::zero();
+    for (eval, l) in evaluations.iter().zip(lagrange_basis(evaluations, x)) {
+        out = out + eval.output * l;
+    }
+    Ok(out)
+}
+
+impl<I, O> LagrangePolynomial<I, O>
+where
+    I: Copy
+        + PartialEq
+        + num_traits::One
+        + num_traits::Zero
+        + Sub<I, Output = I>
+        + UnsafeDiv<I, Output = I>
+        + Mul<I, Output = I>,
+    O: Copy + num_traits::Zero + Mul<I, Output = O> + Add<O, Output = O>,
+{
+    /// Evaluate the polynomial on a given input like [`Polynomial::evaluate`], but
+    /// return an error instead of panicking if the polynomial has no evaluations, or
+    /// if two evaluations share the same input.
+    pub fn try_evaluate(&self, x: I) -> Result<O, Error> {
+        try_interpolate(&self.evaluations, x)
+    }
+
+    /// Recover the coefficients of the interpolated polynomial, so that it can be
+    /// evaluated many times with Horner's method. This takes `O(n^2)` operations and,
+    /// for field types, one division for `n` evaluations.
+    ///
+    /// Panics if two evaluations share the same input.
+    pub fn to_standard_form(&self) -> StandardFormPolynomial<O>
+    where
+        I: Add<I, Output = I>,
+    {
+        let n = self.evaluations.len();
+
+        // The master polynomial m(x) = ∏ (x - x_j), with n + 1 coefficients.
+        let mut master = vec![I::zero(); n + 1];
+        master[0] = I::one();
+        for (j, eval) in self.evaluations.iter().enumerate() {
+            for k in (1..=j + 1).rev() {
+                master[k] = master[k - 1] - eval.input * master[k];
+            }
+            master[0] = I::zero() - eval.input * master[0];
+        }
+
+        // Each basis numerator m(x) / (x - x_i), by synthetic division. Its value at
+        // x_i is the basis denominator ∏_{j != i} (x_i - x_j).
+        let quotients: Vec<Vec<I>> = self
+            .evaluations
+            .iter()
+            .map(|eval| {
+                let mut quotient = vec![I::zero(); n];
+                let mut carry = I::zero();
+                for k in (0..n).rev() {
+                    carry = master[k + 1] + eval.input * carry;
+                    quotient[k] = carry;
+                }
+                quotient
+            })
+            .collect();
+
+        // Divide each basis numerator by its denominator, giving the coefficients
+        // of every basis polynomial.
+        let mut basis: Vec<I> = quotients.concat();
+        let denominators: Vec<I> = self
+            .evaluations
+            .iter()
+            .zip(&quotients)
+            .flat_map(|(eval, quotient)| {
+                core::iter::repeat_n(horner_poly_evaluate(eval.input, quotient), n)
+            })
+            .collect();
+        divide_all(&mut basis, denominators);
+
+        let mut coefficients = vec![O::zero(); n];
+        for (eval, basis_coefficients) in self.evaluations.iter().zip(basis.chunks(n.max(1))) {
+            for (c, &l) in coefficients.iter_mut().zip(basis_coefficients) {
+                *c = *c + eval.output * l;
+            }
+        }
+        StandardFormPolynomial::new(coefficients)
+    }
+}
+
+impl LagrangePolynomial<MaybeScalar, MaybeScalar> {
+    /// Evaluate a secret-sharing polynomial on a given input without branching on
+    /// secret data, for use when reconstructing secrets.
+    ///
+    /// Unlike [`Polynomial::evaluate`], this never returns early when `x` is equal to
+    /// one of the evaluation inputs, and never skips work when a partial product is
+    /// zero. Unlike [`evaluate_hardened`][Self::evaluate_hardened], the denominators of
+    /// the basis polynomials, which depend only on the public evaluation inputs, are
+    /// inverted together with [`batch_invert`], so only one inversion is performed.
+    ///
+    /// Panics if two or more evaluations reuse the same input.
+    pub fn evaluate_ct(&self, x: MaybeScalar) -> MaybeScalar {
+        let evaluations = &self.evaluations;
+
+        let mut denominators: Vec<MaybeScalar> = evaluations
+            .iter()
+            .enumerate()
+            .map(|(i, eval_i)| {
+                let mut bottom = MaybeScalar::one();
+                // The index comparison is public information and so is safe to branch on.
+                for (j, eval_j) in evaluations.iter().enumerate() {
+                    if i != j {
+                        bottom *= eval_i.input - eval_j.input;
+                    }
+                }
+                bottom
+            })
+            .collect();
+
+        // The evaluation inputs are public, so checking them for duplicates leaks nothing.
+        if denominators.iter().any(|d| d.is_zero()) {
+            panic!("shares include duplicate evaluation inputs");
+        }
+        batch_invert(&mut denominators);
+
+        let mut suffix_products = vec![MaybeScalar::one(); evaluations.len() + 1];
+        for j in (0..evaluations.len()).rev() {
+            suffix_products[j] = suffix_products[j + 1] * (x - evaluations[j].input);
+        }
+
+        let mut out = MaybeScalar::Zero;
+        let mut prefix_product = MaybeScalar::one();
+        for (i, eval) in evaluations.iter().enumerate() {
+            out += eval.output * denominators[i] * prefix_product * suffix_products[i + 1];
+            prefix_product *= x - eval.input;
+        }
+        out
+    }
+}
+
+/// Evaluate the line passing through two evaluations `a` and `b` at input `x`.
+///
+/// This is a closed-form fast path for threshold-2 groups, equivalent to evaluating
+/// a [`LagrangePolynomial`] built from `a` and `b`. It computes a single weight
+/// `w = (x - b.input) / (a.input - b.input)` and returns `b.output + w * (a.output - b.output)`,
+/// so interpolating a point-sharing polynomial costs one scalar multiplication
+/// and one point addition.
+///
+/// Panics if `a` and `b` have the same input.
+pub fn interpolate_line<O>(
+    a: &Evaluation<MaybeScalar, O>,
+    b: &Evaluation<MaybeScalar, O>,
+    x: MaybeScalar,
+) -> O
+where
+    O: Copy + Add<O, Output = O> + Sub<O, Output = O> + Mul<MaybeScalar, Output = O>,
+{
+    let denominator = match a.input - b.input {
+        MaybeScalar::Valid(d) => d,
+        MaybeScalar::Zero => panic!("shares include duplicate evaluation inputs"),
+    };
+    let weight = (x - b.input) / denominator;
+    b.output + (a.output - b.output) * weight
+}
+
+impl LagrangePolynomial<MaybeScalar, MaybePoint> {
+    /// Evaluate the polynomial on a given input like [`Polynomial::evaluate`], but
+    /// with the closed-form [`interpolate_line`] when there are exactly two evaluations,
+    /// saving one point multiplication. Deriving a secret from a threshold-2 group,
+    /// such as a nested secret, uses this fast path.
+    ///
+    /// Panics if two evaluations share the same input.
+    pub fn evaluate_fast(&self, x: MaybeScalar) -> MaybePoint {
+        match self.evaluations.as_slice() {
+            [a, b] => interpolate_line(a, b, x),
+            _ => Polynomial::evaluate(self, x),
+        }
+    }
 }
 
 impl<I, O> Polynomial<I, O> for LagrangePolynomial<I, O>
@@ -133,13 +637,31 @@ where
     fn evaluate(&self, x: I) -> O {
         let mut out = O::zero();
 
-        for (i, eval) in self.evaluations.iter().enumerate() {
-            out = out + eval.output * langrange_poly_evaluate(&self.evaluations, i, x)
+        for (eval, l) in self.evaluations.iter().zip(self.basis_at(x)) {
+            out = out + eval.output * l
         }
 
         out
     }
 
+    fn evaluate_many(&self, xs: &[I]) -> Vec<O> {
+        // The shared basis relies on exact inverses.
+        if !I::FIELD {
+            return xs.iter().map(|&x| self.evaluate(x)).collect();
+        }
+        let basis = LagrangeBasis::new(self.evaluations.iter().map(|eval| eval.input).collect());
+        basis
+            .at_many(xs)
+            .into_iter()
+            .map(|ls| {
+                self.evaluations
+                    .iter()
+                    .zip(ls)
+                    .fold(O::zero(), |sum, (eval, l)| sum + eval.output * l)
+            })
+            .collect()
+    }
+
     fn degree(&self) -> usize {
         match self.evaluations.len() {
             0 => 0,
@@ -148,12 +670,98 @@ where
     }
 }
 
+/// Returns the outputs of `rhs` reordered to match the inputs of `lhs`.
+///
+/// Panics if the two polynomials are not evaluated on the same set of inputs.
+fn matching_outputs<I: PartialEq, O: Copy>(
+    lhs: &LagrangePolynomial<I, O>,
+    rhs: &LagrangePolynomial<I, O>,
+) -> Vec<O> {
+    assert_eq!(
+        lhs.evaluations.len(),
+        rhs.evaluations.len(),
+        "Lagrange polynomials must have the same evaluation inputs"
+    );
+    lhs.evaluations
+        .iter()
+        .map(|eval| {
+            rhs.evaluations
+                .iter()
+                .find(|other| other.input == eval.input)
+                .expect("Lagrange polynomials must have the same evaluation inputs")
+                .output
+        })
+        .collect()
+}
+
+/// Adds two interpolated polynomials pointwise. The evaluations may be in any order.
+///
+/// Panics if the two polynomials are not evaluated on the same set of inputs.
+impl<I, O> Add<&LagrangePolynomial<I, O>> for &LagrangePolynomial<I, O>
+where
+    I: Copy + PartialEq,
+    O: Copy + Add<O, Output = O>,
+{
+    type Output = LagrangePolynomial<I, O>;
+
+    fn add(self, rhs: &LagrangePolynomial<I, O>) -> Self::Output {
+        let outputs = matching_outputs(self, rhs);
+        LagrangePolynomial::new(
+            self.evaluations
+                .iter()
+                .zip(outputs)
+                .map(|(eval, other)| Evaluation::new(eval.input, eval.output + other))
+                .collect(),
+        )
+    }
+}
+
+/// Subtracts two interpolated polynomials pointwise. The evaluations may be in any order.
+///
+/// Panics if the two polynomials are not evaluated on the same set of inputs.
+impl<I, O> Sub<&LagrangePolynomial<I, O>> for &LagrangePolynomial<I, O>
+where
+    I: Copy + PartialEq,
+    O: Copy + Sub<O, Output = O>,
+{
+    type Output = LagrangePolynomial<I, O>;
+
+    fn sub(self, rhs: &LagrangePolynomial<I, O>) -> Self::Output {
+        let outputs = matching_outputs(self, rhs);
+        LagrangePolynomial::new(
+            self.evaluations
+                .iter()
+                .zip(outputs)
+                .map(|(eval, other)| Evaluation::new(eval.input, eval.output - other))
+                .collect(),
+        )
+    }
+}
+
+/// Negates every evaluation output of an interpolated polynomial.
+impl<I, O> Neg for &LagrangePolynomial<I, O>
+where
+    I: Copy,
+    O: Copy + Neg<Output = O>,
+{
+    type Output = LagrangePolynomial<I, O>;
+
+    fn neg(self) -> Self::Output {
+        LagrangePolynomial::new(
+            self.evaluations
+                .iter()
+                .map(|eval| Evaluation::new(eval.input, -eval.output))
+                .collect(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_langrange_poly_evaluate() {
+    fn test_lagrange_basis() {
         let evaluations = vec![
             Evaluation {
                 input: 0,
@@ -169,17 +777,9 @@ mod tests {
             },
         ];
 
-        assert_eq!(langrange_poly_evaluate(&evaluations, 0, 0), 1);
-        assert_eq!(langrange_poly_evaluate(&evaluations, 0, 1), 0);
-        assert_eq!(langrange_poly_evaluate(&evaluations, 0, 2), 0);
-
-        assert_eq!(langrange_poly_evaluate(&evaluations, 1, 0), 0);
-        assert_eq!(langrange_poly_evaluate(&evaluations, 1, 1), 1);
-        assert_eq!(langrange_poly_evaluate(&evaluations, 1, 2), 0);
-
-        assert_eq!(langrange_poly_evaluate(&evaluations, 2, 0), 0);
-        assert_eq!(langrange_poly_evaluate(&evaluations, 2, 1), 0);
-        assert_eq!(langrange_poly_evaluate(&evaluations, 2, 2), 1);
+        assert_eq!(lagrange_basis(&evaluations, 0), vec![1, 0, 0]);
+        assert_eq!(lagrange_basis(&evaluations, 1), vec![0, 1, 0]);
+        assert_eq!(lagrange_basis(&evaluations, 2), vec![0, 0, 1]);
 
         let poly = LagrangePolynomial::new(evaluations);
 
@@ -187,4 +787,237 @@ mod tests {
             assert_eq!(poly.evaluate(eval.input), eval.output);
         }
     }
+
+    #[test]
+    fn test_evaluate_hardened() {
+        use secp::Point;
+
+        let poly = LagrangePolynomial::new(vec![
+            Evaluation::new(MaybeScalar::from(1), MaybeScalar::from(40)),
+            Evaluation::new(MaybeScalar::from(3), MaybeScalar::from(5)),
+            Evaluation::new(MaybeScalar::from(4), MaybeScalar::from(1000)),
+        ]);
+
+        for x in [0, 1, 2, 3, 4, 5, 100] {
+            let x = MaybeScalar::from(x);
+            let expected = poly.evaluate(x);
+            assert_eq!(poly.evaluate_hardened(x, 0), expected);
+            assert_eq!(poly.evaluate_hardened(x, 3), expected);
+            assert_eq!(poly.evaluate_hardened(x, 10), expected);
+        }
+
+        let point_poly = LagrangePolynomial::new(
+            poly.evaluations
+                .iter()
+                .map(|eval| Evaluation::new(eval.input, eval.output * Point::generator()))
+                .collect(),
+        );
+        let x = MaybeScalar::from(7);
+        assert_eq!(
+            point_poly.evaluate_hardened(x, 5),
+            poly.evaluate(x) * Point::generator()
+        );
+    }
+
+    #[test]
+    fn test_interpolate_line() {
+        use secp::G;
+
+        let a = Evaluation::new(MaybeScalar::from(2), MaybeScalar::from(1001));
+        let b = Evaluation::new(MaybeScalar::from(7), MaybeScalar::from(55));
+        let poly = LagrangePolynomial::new(vec![a, b]);
+
+        for x in [0, 1, 2, 7, 300] {
+            let x = MaybeScalar::from(x);
+            assert_eq!(interpolate_line(&a, &b, x), poly.evaluate(x));
+            assert_eq!(interpolate_line(&b, &a, x), poly.evaluate(x));
+        }
+
+        let a_point = Evaluation::new(a.input, a.output * G);
+        let b_point = Evaluation::new(b.input, b.output * G);
+        assert_eq!(
+            interpolate_line(&a_point, &b_point, MaybeScalar::Zero),
+            poly.evaluate(MaybeScalar::Zero) * G
+        );
+
+        let points = LagrangePolynomial::new(vec![a_point, b_point]);
+        for x in [0, 1, 5] {
+            let x = MaybeScalar::from(x);
+            assert_eq!(points.evaluate(x), Polynomial::evaluate(&points, x));
+        }
+    }
+
+    #[test]
+    fn test_try_interpolate() {
+        let evaluations = vec![
+            Evaluation::new(MaybeScalar::from(1), MaybeScalar::from(40)),
+            Evaluation::new(MaybeScalar::from(3), MaybeScalar::from(5)),
+        ];
+        let poly = LagrangePolynomial::new(evaluations.clone());
+        let x = MaybeScalar::from(9);
+        assert_eq!(try_interpolate(&evaluations, x), Ok(poly.evaluate(x)));
+        assert_eq!(poly.try_evaluate(x), Ok(poly.evaluate(x)));
+
+        let duplicate =
+            LagrangePolynomial::new(vec![evaluations[0], evaluations[1], evaluations[0]]);
+        assert_eq!(
+            duplicate.try_evaluate(x),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 2
+            })
+        );
+
+        let empty: Vec<Evaluation<MaybeScalar, MaybeScalar>> = vec![];
+        assert_eq!(try_interpolate(&empty, x), Err(Error::Empty));
+
+        assert_eq!(
+            MaybeScalar::try_div(MaybeScalar::one(), MaybeScalar::Zero),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(<u32 as UnsafeDiv<u32>>::try_div(6, 3), Ok(2));
+    }
+
+    #[test]
+    fn test_lagrange_try_new() {
+        let a = Evaluation::new(MaybeScalar::from(1), MaybeScalar::from(40));
+        let b = Evaluation::new(MaybeScalar::from(3), MaybeScalar::from(5));
+        let zero = Evaluation::new(MaybeScalar::Zero, MaybeScalar::from(7));
+
+        assert!(LagrangePolynomial::try_new(vec![a, b]).is_ok());
+        assert!(LagrangePolynomial::try_new(vec![a, zero]).is_ok());
+        assert_eq!(
+            LagrangePolynomial::try_new(vec![a, b, b]).err(),
+            Some(Error::DuplicateInput {
+                first: 1,
+                second: 2
+            })
+        );
+        assert_eq!(
+            LagrangePolynomial::try_new_nonzero(vec![a, zero]).err(),
+            Some(Error::ZeroInput { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_to_standard_form() {
+        let f = crate::SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(7),
+            MaybeScalar::from(11),
+            MaybeScalar::from(13),
+        ]);
+        let shares: Vec<_> = [3, 1, 8]
+            .into_iter()
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+        assert_eq!(
+            LagrangePolynomial::new(shares.clone()).to_standard_form(),
+            f
+        );
+
+        let point_shares = shares.iter().map(|share| share * secp::G).collect();
+        assert_eq!(
+            LagrangePolynomial::new(point_shares).to_standard_form(),
+            secp::G * &f
+        );
+
+        let fewer = LagrangePolynomial::new(shares[..2].to_vec()).to_standard_form();
+        assert_eq!(fewer.coefficients.len(), 2);
+        assert_eq!(fewer.evaluate(shares[0].input), shares[0].output);
+    }
+
+    #[test]
+    fn test_lagrange_add_sub_neg() {
+        let f =
+            crate::SecretSharingPolynomial::new(vec![MaybeScalar::from(7), MaybeScalar::from(11)]);
+        let g =
+            crate::SecretSharingPolynomial::new(vec![MaybeScalar::from(3), MaybeScalar::from(5)]);
+        let interpolate = |p: &crate::SecretSharingPolynomial, inputs: [u128; 2]| {
+            LagrangePolynomial::new(
+                inputs
+                    .into_iter()
+                    .map(|i| p.issue_share(MaybeScalar::from(i)))
+                    .collect(),
+            )
+        };
+
+        let sum = &interpolate(&f, [1, 2]) + &interpolate(&g, [2, 1]);
+        assert_eq!(sum.evaluate(MaybeScalar::Zero), MaybeScalar::from(10));
+        let difference = &interpolate(&f, [1, 2]) - &interpolate(&g, [1, 2]);
+        assert_eq!(difference.evaluate(MaybeScalar::Zero), MaybeScalar::from(4));
+        assert_eq!(
+            (-&interpolate(&f, [1, 2])).evaluate(MaybeScalar::Zero),
+            -MaybeScalar::from(7)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_many() {
+        let f = crate::SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(7),
+            MaybeScalar::from(11),
+            MaybeScalar::from(13),
+        ]);
+        let interpolated = LagrangePolynomial::new(
+            (1..=3)
+                .map(|i| f.issue_share(MaybeScalar::from(i)))
+                .collect(),
+        );
+        let xs: Vec<MaybeScalar> = (0..10).map(MaybeScalar::from).collect();
+        assert_eq!(interpolated.evaluate_many(&xs), f.evaluate_many(&xs));
+        assert_eq!(
+            interpolated.evaluate_many(&xs),
+            xs.iter().map(|&x| f.evaluate(x)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_batch_invert() {
+        let mut values: Vec<MaybeScalar> = (1..=6).map(MaybeScalar::from).collect();
+        batch_invert(&mut values);
+        for (i, inverse) in values.into_iter().enumerate() {
+            assert_eq!(
+                inverse * MaybeScalar::from(i as u128 + 1),
+                MaybeScalar::one()
+            );
+        }
+
+        let mut empty: Vec<MaybeScalar> = vec![];
+        batch_invert(&mut empty);
+    }
+
+    #[test]
+    fn test_integer_lagrange_evaluate() {
+        // Integer division truncates, so each basis term must be divided separately.
+        let poly: LagrangePolynomial<i64, i64> = LagrangePolynomial::new(vec![
+            Evaluation::new(0, 4),
+            Evaluation::new(1, 1),
+            Evaluation::new(2, 3),
+        ]);
+        assert_eq!(poly.evaluate(3), 10);
+        assert_eq!(try_interpolate(&poly.evaluations, 3), Ok(10));
+        assert_eq!(poly.evaluate_many(&[3, 1]), vec![10, 1]);
+    }
+
+    #[test]
+    fn test_scalar_lagrange_evaluate() {
+        use crate::SecretSharingPolynomial;
+
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(17),
+            MaybeScalar::from(3),
+            MaybeScalar::from(80),
+            MaybeScalar::from(1),
+        ]);
+        let poly = LagrangePolynomial::new(
+            (10..14)
+                .map(|i| f.issue_share(MaybeScalar::from(i)))
+                .collect(),
+        );
+        for x in [0, 1, 10, 13, 55] {
+            let x = MaybeScalar::from(x);
+            assert_eq!(poly.evaluate(x), f.evaluate(x));
+            assert_eq!(poly.evaluate_ct(x), f.evaluate(x));
+        }
+    }
 }
@@ -1,10 +1,25 @@
+mod array;
+mod barycentric;
+mod basis;
+mod cache;
 mod evaluation;
 mod lagrange;
+mod robust;
 mod standard;
+mod subproduct;
 
+pub use array::*;
+pub use barycentric::*;
+pub use basis::*;
+pub use cache::*;
 pub use evaluation::*;
 pub use lagrange::*;
+pub use robust::*;
 pub use standard::*;
+pub use subproduct::*;
+
+use alloc::vec::Vec;
+use secp::MaybeScalar;
 
 /// A trait common to any class of univariate polynomial function with input type `I`
 /// and output type `O`.
@@ -12,6 +27,16 @@ pub trait Polynomial<I, O> {
     /// Evaluate the polynomial on a given input.
     fn evaluate(&self, input: I) -> O;
 
+    /// Evaluate the polynomial on each of the given inputs, in order. Implementations
+    /// may share work between evaluations; interpolated polynomials share all their
+    /// divisions in one batch inversion.
+    fn evaluate_many(&self, xs: &[I]) -> Vec<O>
+    where
+        I: Copy,
+    {
+        xs.iter().map(|&x| self.evaluate(x)).collect()
+    }
+
     /// Returns the degree of the polynomial, which is usually the number of coefficients
     /// minus 1. If the polynomial has no coefficients, it has degree zero.
     fn degree(&self) -> usize;
@@ -21,4 +46,68 @@ pub trait Polynomial<I, O> {
     fn interpolation_threshold(&self) -> usize {
         self.degree() + 1
     }
+
+    /// Check whether this polynomial and `other` are the same function, given that
+    /// neither has a degree greater than `degree_bound`. The representations may differ,
+    /// e.g. a dealer's standard-form polynomial and one interpolated from shares.
+    ///
+    /// Two distinct polynomials of degree at most `d` agree on at most `d` inputs, so this
+    /// compares their outputs at the first `degree_bound + 1` inputs of the
+    /// [`InputSequence`]. The [`degree`][Self::degree] of either polynomial is always a
+    /// valid bound for it.
+    ///
+    /// Returns `false` if the input type has fewer than `degree_bound + 1` elements, as
+    /// `GF(2^8)` does for bounds above 255, since equivalence cannot be confirmed.
+    fn is_equivalent<P>(&self, other: &P, degree_bound: usize) -> bool
+    where
+        Self: Sized,
+        P: Polynomial<I, O>,
+        I: Copy + InputSequence,
+        O: PartialEq,
+    {
+        for i in 0..=degree_bound {
+            let Some(x) = I::nth_input(i) else {
+                return false;
+            };
+            if self.evaluate(x) != other.evaluate(x) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A type from which distinct polynomial inputs can be enumerated, such as for
+/// comparing two polynomials at enough points to tell them apart.
+///
+/// Counting up from zero by adding one is not enough in general: in a field of
+/// characteristic 2 such as `GF(2^8)`, `1 + 1 = 0`.
+pub trait InputSequence: Sized {
+    /// Returns the `n`-th input of the sequence, or `None` if the type has no more
+    /// than `n` elements. Inputs at different positions are always distinct.
+    fn nth_input(n: usize) -> Option<Self>;
+}
+
+macro_rules! impl_input_sequence {
+    ( $($t:ty),* ) => {
+        $(
+            impl InputSequence for $t {
+                fn nth_input(n: usize) -> Option<Self> {
+                    <$t>::try_from(n).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_input_sequence! {
+    i8, i16, i32, i64, i128,
+    u8, u16, u32, u64, u128,
+    usize
+}
+
+impl InputSequence for MaybeScalar {
+    fn nth_input(n: usize) -> Option<Self> {
+        Some(MaybeScalar::from(n as u128))
+    }
 }
@@ -0,0 +1,366 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Sub};
+
+use crate::{
+    horner_poly_evaluate, Evaluation, LimitExceeded, Limits, StandardFormPolynomial, UnsafeDiv,
+};
+
+/// Solve the linear system `matrix * v = rhs` by Gaussian elimination. Free variables
+/// are set to zero. Returns `None` if the system is inconsistent.
+fn solve_linear_system<F>(mut matrix: Vec<Vec<F>>, mut rhs: Vec<F>, n_vars: usize) -> Option<Vec<F>>
+where
+    F: Copy
+        + PartialEq
+        + num_traits::Zero
+        + num_traits::One
+        + Sub<F, Output = F>
+        + Mul<F, Output = F>
+        + UnsafeDiv<F, Output = F>,
+{
+    let n_rows = matrix.len();
+    let mut pivot_cols = Vec::with_capacity(n_vars);
+    let mut row = 0;
+
+    for col in 0..n_vars {
+        let pivot = match (row..n_rows).find(|&r| !matrix[r][col].is_zero()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        matrix.swap(row, pivot);
+        rhs.swap(row, pivot);
+
+        let inverse = F::unsafe_div(F::one(), matrix[row][col]);
+        for v in matrix[row][col..].iter_mut() {
+            *v = *v * inverse;
+        }
+        rhs[row] = rhs[row] * inverse;
+
+        let pivot_row = matrix[row].clone();
+        for r in 0..n_rows {
+            if r != row && !matrix[r][col].is_zero() {
+                let factor = matrix[r][col];
+                for (v, &p) in matrix[r][col..].iter_mut().zip(&pivot_row[col..]) {
+                    *v = *v - factor * p;
+                }
+                rhs[r] = rhs[r] - factor * rhs[row];
+            }
+        }
+
+        pivot_cols.push(col);
+        row += 1;
+        if row == n_rows {
+            break;
+        }
+    }
+
+    // Any remaining rows are all-zero on the left, so they must be zero on the right.
+    if rhs[row..].iter().any(|v| !v.is_zero()) {
+        return None;
+    }
+
+    let mut solution = vec![F::zero(); n_vars];
+    for (r, &col) in pivot_cols.iter().enumerate() {
+        solution[col] = rhs[r];
+    }
+    Some(solution)
+}
+
+/// Divide `numerator` by the monic polynomial `denominator`, both given as coefficients
+/// in ascending order of degree. Returns `None` if the remainder is not zero.
+fn divide_exact<F>(numerator: &[F], denominator: &[F]) -> Option<Vec<F>>
+where
+    F: Copy + num_traits::Zero + Sub<F, Output = F> + Mul<F, Output = F>,
+{
+    let d = denominator.len() - 1;
+    if numerator.len() <= d {
+        return numerator.iter().all(|c| c.is_zero()).then(Vec::new);
+    }
+
+    let mut remainder = numerator.to_vec();
+    let mut quotient = vec![F::zero(); numerator.len() - d];
+    for i in (0..quotient.len()).rev() {
+        let q = remainder[i + d];
+        quotient[i] = q;
+        for (j, &c) in denominator.iter().enumerate() {
+            remainder[i + j] = remainder[i + j] - q * c;
+        }
+    }
+
+    remainder.iter().all(|c| c.is_zero()).then_some(quotient)
+}
+
+/// Interpolate a polynomial with `threshold` coefficients from a set of evaluations,
+/// some of which may be corrupted, using the
+/// [Berlekamp–Welch algorithm](https://en.wikipedia.org/wiki/Berlekamp%E2%80%93Welch_algorithm).
+///
+/// Up to `(n - threshold) / 2` corrupted evaluations are tolerated, where `n` is the
+/// number of evaluations. Plain Lagrange interpolation would silently produce the wrong
+/// polynomial if any evaluation were corrupted.
+///
+/// Returns `None` if there are fewer than `threshold` evaluations, if no polynomial
+/// of the required degree agrees with enough of the evaluations, or if there are more
+/// evaluations than the default [`Limits`] allow. The evaluations are expected to have
+/// distinct inputs.
+pub fn robust_interpolate<F>(
+    evaluations: &[Evaluation<F, F>],
+    threshold: usize,
+) -> Option<StandardFormPolynomial<F>>
+where
+    F: Copy
+        + PartialEq
+        + num_traits::Zero
+        + num_traits::One
+        + Add<F, Output = F>
+        + Sub<F, Output = F>
+        + Mul<F, Output = F>
+        + UnsafeDiv<F, Output = F>,
+{
+    robust_interpolate_limited(evaluations, threshold, &Limits::default())
+        .ok()
+        .flatten()
+}
+
+/// Interpolate like [`robust_interpolate`], but with the given limits in place of
+/// the defaults. Solving for the error locator takes `O(n^3)` operations, so the
+/// number of evaluations and the threshold are checked before any work is done.
+pub fn robust_interpolate_limited<F>(
+    evaluations: &[Evaluation<F, F>],
+    threshold: usize,
+    limits: &Limits,
+) -> Result<Option<StandardFormPolynomial<F>>, LimitExceeded>
+where
+    F: Copy
+        + PartialEq
+        + num_traits::Zero
+        + num_traits::One
+        + Add<F, Output = F>
+        + Sub<F, Output = F>
+        + Mul<F, Output = F>
+        + UnsafeDiv<F, Output = F>,
+{
+    let n = evaluations.len();
+    limits.check_shares(n)?;
+    limits.check_degree(threshold.saturating_sub(1))?;
+    if threshold == 0 || n < threshold {
+        return Ok(None);
+    }
+    let max_errors = (n - threshold) / 2;
+
+    // Find the error locator E(x) (monic, degree e) and Q(x) = P(x) * E(x) (degree
+    // e + threshold - 1) such that Q(x_i) = y_i * E(x_i) for every evaluation.
+    //
+    // Unknowns: q_0 .. q_{e+t-1}, then e_0 .. e_{e-1}. The leading coefficient of E is 1.
+    //   sum_j q_j * x_i^j - y_i * sum_j e_j * x_i^j = y_i * x_i^e
+    let n_q = max_errors + threshold;
+    let n_vars = n_q + max_errors;
+
+    let mut matrix = Vec::with_capacity(n);
+    let mut rhs = Vec::with_capacity(n);
+    for eval in evaluations {
+        let mut row = Vec::with_capacity(n_vars);
+        let mut power = F::one();
+        let mut powers = Vec::with_capacity(n_q);
+        for _ in 0..n_q {
+            powers.push(power);
+            power = power * eval.input;
+        }
+        row.extend_from_slice(&powers);
+        for &p in &powers[..max_errors] {
+            row.push(F::zero() - eval.output * p);
+        }
+        matrix.push(row);
+        rhs.push(eval.output * powers[max_errors]);
+    }
+
+    let Some(solution) = solve_linear_system(matrix, rhs, n_vars) else {
+        return Ok(None);
+    };
+    let q = &solution[..n_q];
+    let mut e = solution[n_q..].to_vec();
+    e.push(F::one());
+
+    let Some(mut p) = divide_exact(q, &e) else {
+        return Ok(None);
+    };
+    p.resize(threshold, F::zero());
+
+    // The decoded polynomial must agree with all but at most `max_errors` evaluations.
+    let disagreements = evaluations
+        .iter()
+        .filter(|eval| horner_poly_evaluate::<F, F, F>(eval.input, &p) != eval.output)
+        .count();
+    if disagreements > max_errors {
+        return Ok(None);
+    }
+
+    Ok(Some(StandardFormPolynomial::new(p)))
+}
+
+/// Errors returned by [`interpolate_identifying_cheaters`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InconsistentShares {
+    /// Fewer evaluations than the threshold were given.
+    NotEnoughShares,
+
+    /// No polynomial of the required degree agrees with enough of the evaluations,
+    /// so the cheaters cannot be identified.
+    Undecodable,
+
+    /// The evaluations at these indices are inconsistent with the polynomial on
+    /// which all the other evaluations agree.
+    Cheaters(Vec<usize>),
+
+    /// There were more evaluations, or a higher threshold, than the limits allow.
+    LimitExceeded(LimitExceeded),
+}
+
+impl core::fmt::Display for InconsistentShares {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InconsistentShares::NotEnoughShares => write!(f, "not enough shares to interpolate"),
+            InconsistentShares::Undecodable => write!(f, "too many inconsistent shares"),
+            InconsistentShares::Cheaters(indices) => {
+                write!(f, "inconsistent shares at indices {:?}", indices)
+            }
+            InconsistentShares::LimitExceeded(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InconsistentShares {}
+
+/// Interpolate a polynomial with `threshold` coefficients from a set of evaluations,
+/// checking that every evaluation is consistent with it.
+///
+/// The largest consistent subset of evaluations is found with [`robust_interpolate`].
+/// If any evaluations fall outside that subset, their indices are returned as
+/// [`InconsistentShares::Cheaters`], so that the shareholders who submitted them can
+/// be identified. At most `(n - threshold) / 2` cheaters can be identified; if there
+/// are exactly `threshold` evaluations, they are always consistent.
+///
+/// Returns [`InconsistentShares::LimitExceeded`] if there are more evaluations than
+/// the default [`Limits`] allow.
+pub fn interpolate_identifying_cheaters<F>(
+    evaluations: &[Evaluation<F, F>],
+    threshold: usize,
+) -> Result<StandardFormPolynomial<F>, InconsistentShares>
+where
+    F: Copy
+        + PartialEq
+        + num_traits::Zero
+        + num_traits::One
+        + Add<F, Output = F>
+        + Sub<F, Output = F>
+        + Mul<F, Output = F>
+        + UnsafeDiv<F, Output = F>,
+{
+    interpolate_identifying_cheaters_limited(evaluations, threshold, &Limits::default())
+}
+
+/// Interpolate like [`interpolate_identifying_cheaters`], but with the given limits
+/// in place of the defaults.
+pub fn interpolate_identifying_cheaters_limited<F>(
+    evaluations: &[Evaluation<F, F>],
+    threshold: usize,
+    limits: &Limits,
+) -> Result<StandardFormPolynomial<F>, InconsistentShares>
+where
+    F: Copy
+        + PartialEq
+        + num_traits::Zero
+        + num_traits::One
+        + Add<F, Output = F>
+        + Sub<F, Output = F>
+        + Mul<F, Output = F>
+        + UnsafeDiv<F, Output = F>,
+{
+    if threshold == 0 || evaluations.len() < threshold {
+        return Err(InconsistentShares::NotEnoughShares);
+    }
+    let poly = robust_interpolate_limited(evaluations, threshold, limits)
+        .map_err(InconsistentShares::LimitExceeded)?
+        .ok_or(InconsistentShares::Undecodable)?;
+
+    let cheaters: Vec<usize> = evaluations
+        .iter()
+        .enumerate()
+        .filter(|(_, eval)| {
+            horner_poly_evaluate::<F, F, F>(eval.input, &poly.coefficients) != eval.output
+        })
+        .map(|(i, _)| i)
+        .collect();
+
+    if cheaters.is_empty() {
+        Ok(poly)
+    } else {
+        Err(InconsistentShares::Cheaters(cheaters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Gf256, Polynomial, SecretShare, SecretSharingPolynomial};
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_robust_interpolate() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(11),
+            MaybeScalar::from(22),
+            MaybeScalar::from(33),
+        ]);
+        let mut shares: Vec<SecretShare> = (1..=7)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        // No errors.
+        assert_eq!(robust_interpolate(&shares, 3), Some(f.clone()));
+        assert_eq!(robust_interpolate(&shares[..3], 3), Some(f.clone()));
+
+        // 7 shares with threshold 3 tolerate 2 errors.
+        shares[1].output = MaybeScalar::from(999);
+        assert_eq!(robust_interpolate(&shares, 3), Some(f.clone()));
+        shares[5].output = MaybeScalar::from(12345);
+        assert_eq!(robust_interpolate(&shares, 3), Some(f.clone()));
+
+        // Too many errors are detected rather than returning garbage.
+        shares[6].output = MaybeScalar::from(5);
+        assert_eq!(robust_interpolate(&shares, 3), None);
+
+        assert_eq!(robust_interpolate(&shares[..2], 3), None);
+    }
+
+    #[test]
+    fn test_robust_interpolate_gf256() {
+        let f = StandardFormPolynomial::new(vec![Gf256(0x42), Gf256(0x07)]);
+        let mut evals: Vec<Evaluation<Gf256, Gf256>> = (1..=5)
+            .map(|i| Evaluation::new(Gf256(i), f.evaluate(Gf256(i))))
+            .collect();
+        evals[3].output = Gf256(0);
+        assert_eq!(robust_interpolate(&evals, 2), Some(f));
+    }
+
+    #[test]
+    fn test_identify_cheaters() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(6)]);
+        let mut shares: Vec<SecretShare> = (1..=6)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        assert_eq!(interpolate_identifying_cheaters(&shares, 2), Ok(f.clone()));
+
+        shares[4].output += MaybeScalar::one();
+        shares[0].output = MaybeScalar::Zero;
+        assert_eq!(
+            interpolate_identifying_cheaters(&shares, 2),
+            Err(InconsistentShares::Cheaters(vec![0, 4]))
+        );
+
+        assert_eq!(
+            interpolate_identifying_cheaters(&shares[..1], 2),
+            Err(InconsistentShares::NotEnoughShares)
+        );
+    }
+}
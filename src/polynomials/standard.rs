@@ -1,6 +1,9 @@
-use std::ops::{Add, Mul};
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Neg, Sub};
 
-use crate::Polynomial;
+use crate::{Error, Polynomial};
+use secp::MaybeScalar;
 
 /// Evaluate a standard-form polynomial using [Horner's method].
 ///
@@ -11,7 +14,7 @@ use crate::Polynomial;
 /// that this function can be reused for any kind of [`Polynomial`].
 ///
 /// [Horner's method]: https://en.wikipedia.org/wiki/Horner%27s_method
-fn horner_poly_evaluate<I, T, O>(x: I, coefficients: &[T]) -> O
+pub(crate) fn horner_poly_evaluate<I, T, O>(x: I, coefficients: &[T]) -> O
 where
     O: Copy,
     O: num_traits::Zero + Mul<I, Output = O> + Add<T, Output = O>,
@@ -23,28 +26,77 @@ where
     // Start from highest-degree coefficients.
     // Example with a degree 3 polynomial, with coefficients [a0, a1, a2, a3]:
     //   f(x) = a0 + x(a1 + x(a2 + x(a3)))
-    for &a in coefficients.into_iter().rev() {
+    for &a in coefficients.iter().rev() {
         out = out * x + a
     }
 
     out
 }
 
+/// Computes the degree of a standard-form polynomial from its coefficients,
+/// not counting trailing zero coefficients.
+pub(crate) fn standard_form_degree<T: num_traits::Zero>(coefficients: &[T]) -> usize {
+    let mut degree = match coefficients.len() {
+        0 => 0,
+        t => t - 1,
+    };
+
+    // Do not count trailing zero coefficients.
+    for coeff in coefficients.iter().rev() {
+        if coeff.is_zero() && degree > 0 {
+            degree -= 1;
+        } else {
+            break;
+        }
+    }
+
+    degree
+}
+
+pub(crate) mod sealed {
+    use alloc::vec::Vec;
+
+    pub trait Sealed {}
+
+    impl<T> Sealed for Vec<T> {}
+    impl<T, const N: usize> Sealed for [T; N] {}
+}
+
+/// Storage for the coefficients of a [`StandardFormPolynomial`]: a `Vec<T>` by
+/// default, or an array `[T; N]` to hold a fixed number of coefficients inline,
+/// without heap allocation. This is useful in `no-alloc` environments and for
+/// small fixed thresholds.
+pub trait Coefficients<T>: AsRef<[T]> + AsMut<[T]> + sealed::Sealed {}
+
+impl<T> Coefficients<T> for Vec<T> {}
+impl<T, const N: usize> Coefficients<T> for [T; N] {}
+
 /// Represents a polynomial function expressed in standard form with
-/// coefficients of type `T`.
+/// coefficients of type `T`, stored in `C`.
+///
+/// The derived `Debug` and `PartialEq` implementations print and compare the
+/// coefficients directly. Hold secret polynomials in a [`Secret`][crate::Secret]
+/// to redact them and compare them in constant time.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-pub struct StandardFormPolynomial<T> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StandardFormPolynomial<T, C: Coefficients<T> = Vec<T>> {
     /// The ordered set of coefficients, starting with the constant term.
-    pub coefficients: Vec<T>,
+    pub coefficients: C,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    marker: PhantomData<T>,
 }
 
-impl<T> StandardFormPolynomial<T> {
+impl<T, C: Coefficients<T>> StandardFormPolynomial<T, C> {
     /// The `coefficients` are assumed to be presented in ascending order of degree,
     /// starting with the constant term `coefficients[0]`.
     ///
     /// Panics if coefficients is empty.
-    pub fn new(coefficients: Vec<T>) -> Self {
-        Self { coefficients }
+    pub fn new(coefficients: C) -> Self {
+        Self {
+            coefficients,
+            marker: PhantomData,
+        }
     }
 
     /// Returns the degree of the polynomial, which is usually the number of coefficients
@@ -54,25 +106,29 @@ impl<T> StandardFormPolynomial<T> {
     where
         T: num_traits::Zero,
     {
-        let mut degree = match self.coefficients.len() {
-            0 => 0,
-            t => t - 1,
-        };
-
-        // Do not count trailing zero coefficients.
-        for coeff in self.coefficients.iter().rev() {
-            if coeff.is_zero() && degree > 0 {
-                degree -= 1;
-            } else {
-                break;
-            }
-        }
+        standard_form_degree(self.coefficients.as_ref())
+    }
+}
 
-        return degree;
+impl<T, C: Coefficients<T>> StandardFormPolynomial<T, C>
+where
+    T: Copy + num_traits::Zero,
+{
+    /// Evaluate the polynomial on a given input like [`Polynomial::evaluate`], but
+    /// return [`Error::Empty`] if the polynomial has no coefficients.
+    pub fn try_evaluate<I>(&self, x: I) -> Result<T, Error>
+    where
+        I: Copy,
+        T: Mul<I, Output = T> + Add<T, Output = T>,
+    {
+        if self.coefficients.as_ref().is_empty() {
+            return Err(Error::Empty);
+        }
+        Ok(horner_poly_evaluate(x, self.coefficients.as_ref()))
     }
 }
 
-impl<I, T> Polynomial<I, T> for StandardFormPolynomial<T>
+impl<I, T, C: Coefficients<T>> Polynomial<I, T> for StandardFormPolynomial<T, C>
 where
     I: Copy,
     T: Copy + num_traits::Zero,
@@ -87,6 +143,134 @@ where
     }
 }
 
+/// Adds two polynomials coefficient-wise, treating missing coefficients as zero.
+/// This aggregates dealings, as in DKG or proactive refresh.
+impl<T> Add<&StandardFormPolynomial<T>> for &StandardFormPolynomial<T>
+where
+    T: Copy + num_traits::Zero + Add<T, Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+
+    fn add(self, rhs: &StandardFormPolynomial<T>) -> Self::Output {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        let coefficient = |c: &[T], i: usize| c.get(i).copied().unwrap_or_else(T::zero);
+        StandardFormPolynomial::new(
+            (0..len)
+                .map(|i| coefficient(&self.coefficients, i) + coefficient(&rhs.coefficients, i))
+                .collect(),
+        )
+    }
+}
+impl<T> Add for StandardFormPolynomial<T>
+where
+    T: Copy + num_traits::Zero + Add<T, Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+    fn add(self, rhs: StandardFormPolynomial<T>) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+/// Subtracts two polynomials coefficient-wise, treating missing coefficients as zero.
+impl<T> Sub<&StandardFormPolynomial<T>> for &StandardFormPolynomial<T>
+where
+    T: Copy + num_traits::Zero + Sub<T, Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+
+    fn sub(self, rhs: &StandardFormPolynomial<T>) -> Self::Output {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        let coefficient = |c: &[T], i: usize| c.get(i).copied().unwrap_or_else(T::zero);
+        StandardFormPolynomial::new(
+            (0..len)
+                .map(|i| coefficient(&self.coefficients, i) - coefficient(&rhs.coefficients, i))
+                .collect(),
+        )
+    }
+}
+impl<T> Sub for StandardFormPolynomial<T>
+where
+    T: Copy + num_traits::Zero + Sub<T, Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+    fn sub(self, rhs: StandardFormPolynomial<T>) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+/// Negates every coefficient of a polynomial.
+impl<T> Neg for &StandardFormPolynomial<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+
+    fn neg(self) -> Self::Output {
+        StandardFormPolynomial::new(self.coefficients.iter().map(|&c| -c).collect())
+    }
+}
+impl<T> Neg for StandardFormPolynomial<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+/// Multiplies two polynomials by schoolbook convolution. The product of polynomials with
+/// `a` and `b` coefficients has `a + b - 1` coefficients, or none if either is empty.
+impl<T> Mul<&StandardFormPolynomial<T>> for &StandardFormPolynomial<T>
+where
+    T: Copy + num_traits::Zero + Mul<T, Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+
+    fn mul(self, rhs: &StandardFormPolynomial<T>) -> Self::Output {
+        if self.coefficients.is_empty() || rhs.coefficients.is_empty() {
+            return StandardFormPolynomial::new(Vec::new());
+        }
+        let mut product = vec![T::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (out, &b) in product[i..].iter_mut().zip(&rhs.coefficients) {
+                *out = *out + a * b;
+            }
+        }
+        StandardFormPolynomial::new(product)
+    }
+}
+impl<T> Mul for StandardFormPolynomial<T>
+where
+    T: Copy + num_traits::Zero + Mul<T, Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+    fn mul(self, rhs: StandardFormPolynomial<T>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+/// Scales every coefficient of a polynomial by a scalar.
+impl<T> Mul<MaybeScalar> for &StandardFormPolynomial<T>
+where
+    T: Copy + Mul<MaybeScalar, Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+
+    fn mul(self, rhs: MaybeScalar) -> Self::Output {
+        StandardFormPolynomial::new(self.coefficients.iter().map(|&c| c * rhs).collect())
+    }
+}
+impl<T> Mul<MaybeScalar> for StandardFormPolynomial<T>
+where
+    T: Copy + Mul<MaybeScalar, Output = T>,
+{
+    type Output = StandardFormPolynomial<T>;
+    fn mul(self, rhs: MaybeScalar) -> Self::Output {
+        &self * rhs
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,5 +304,81 @@ mod tests {
 
         // f(4) = 1 + 12 + 32
         assert_eq!(poly.evaluate(4), 45);
+
+        assert_eq!(poly.try_evaluate(4), Ok(45));
+        assert_eq!(
+            StandardFormPolynomial::<i32>::new(vec![]).try_evaluate(4),
+            Err(Error::Empty)
+        );
+    }
+
+    #[test]
+    fn test_is_equivalent() {
+        use crate::{Gf256, LagrangePolynomial};
+        use secp::MaybeScalar;
+
+        let f = crate::SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(7),
+            MaybeScalar::from(11),
+            MaybeScalar::from(13),
+        ]);
+        let shares: Vec<_> = (1..=3)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+        let interpolated = LagrangePolynomial::new(shares.clone());
+        assert!(f.is_equivalent(&interpolated, 2));
+        assert!(interpolated.is_equivalent(&f, interpolated.degree()));
+
+        let mut tampered = shares;
+        tampered[0].output += MaybeScalar::one();
+        assert!(!f.is_equivalent(&LagrangePolynomial::new(tampered), 2));
+
+        let g = StandardFormPolynomial::new(vec![Gf256(1), Gf256(2)]);
+        assert!(g.is_equivalent(&g.clone(), 1));
+
+        // Characteristic 2 needs inputs other than 0 and 1 for higher bounds.
+        let h = StandardFormPolynomial::new(vec![Gf256(1), Gf256(2), Gf256(3)]);
+        let h_shares: Vec<_> = (1..=3)
+            .map(|i| crate::Evaluation::new(Gf256(i), h.evaluate(Gf256(i))))
+            .collect();
+        assert!(h.is_equivalent(&LagrangePolynomial::new(h_shares), 2));
+        assert!(!h.is_equivalent(&g, 2));
+        assert!(!h.is_equivalent(&h.clone(), 256));
+    }
+
+    #[test]
+    fn test_polynomial_add_sub_neg() {
+        let f = StandardFormPolynomial::new(vec![1, 2, 3]);
+        let g = StandardFormPolynomial::new(vec![10, 20]);
+
+        assert_eq!(&f + &g, StandardFormPolynomial::new(vec![11, 22, 3]));
+        assert_eq!(&g + &f, StandardFormPolynomial::new(vec![11, 22, 3]));
+        assert_eq!(&g - &f, StandardFormPolynomial::new(vec![9, 18, -3]));
+        assert_eq!(-f.clone(), StandardFormPolynomial::new(vec![-1, -2, -3]));
+        assert_eq!((f.clone() - f.clone()).degree(), 0);
+        for x in 0..5 {
+            assert_eq!((&f + &g).evaluate(x), f.evaluate(x) + g.evaluate(x));
+        }
+    }
+
+    #[test]
+    fn test_polynomial_mul() {
+        // (1 + 2x)(3 + x + x^2) = 3 + 7x + 3x^2 + 2x^3
+        let f = StandardFormPolynomial::new(vec![1, 2]);
+        let g = StandardFormPolynomial::new(vec![3, 1, 1]);
+        assert_eq!(&f * &g, StandardFormPolynomial::new(vec![3, 7, 3, 2]));
+        assert_eq!((&f * &g).degree(), f.degree() + g.degree());
+        assert_eq!(
+            &f * &StandardFormPolynomial::new(vec![]),
+            StandardFormPolynomial::new(vec![])
+        );
+
+        let h = StandardFormPolynomial::new(vec![MaybeScalar::from(2), MaybeScalar::from(3)]);
+        let x = MaybeScalar::from(9);
+        assert_eq!(
+            (&h * MaybeScalar::from(5)).evaluate(x),
+            h.evaluate(x) * MaybeScalar::from(5)
+        );
+        assert_eq!((&h * &h).evaluate(x), h.evaluate(x) * h.evaluate(x));
     }
 }
@@ -0,0 +1,352 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Sub};
+
+use crate::{batch_invert, check_distinct, Error, Limits, StandardFormPolynomial, UnsafeDiv};
+
+/// Below this many coefficients, products and remainders use the schoolbook
+/// algorithms, which are faster for small operands.
+const SCHOOLBOOK_CUTOFF: usize = 32;
+
+/// A subproduct tree over a fixed set of distinct inputs `x_i`, for evaluating and
+/// interpolating polynomials with many points at once.
+///
+/// The leaves are the linear polynomials `x - x_i`, and every other node is the
+/// product of its children, so the root is `∏ (x - x_i)`. Walking remainders down
+/// the tree evaluates a polynomial at every input, and walking linear combinations
+/// up the tree interpolates one. With Karatsuba multiplication and Newton-iteration
+/// division, both take `O(n^1.59 log n)` field operations rather than the `O(n^2)`
+/// of evaluating or interpolating one point at a time, which pays off for sharings
+/// with hundreds or thousands of shareholders.
+///
+/// Evaluation only divides by monic polynomials, so it works for any type, including
+/// integers. Interpolation needs exact inverses, and is only available for types which
+/// are [fields][UnsafeDiv::FIELD].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SubproductTree<T> {
+    inputs: Vec<T>,
+
+    /// The nodes of each level of the tree as coefficient vectors, starting with
+    /// the leaves. The last level holds only the root.
+    levels: Vec<Vec<Vec<T>>>,
+}
+
+impl<T> SubproductTree<T>
+where
+    T: Copy
+        + PartialEq
+        + num_traits::Zero
+        + num_traits::One
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>
+        + UnsafeDiv<T, Output = T>,
+{
+    /// Build the subproduct tree for the given inputs.
+    ///
+    /// Interpolation panics if the inputs contain duplicates.
+    pub fn new(inputs: Vec<T>) -> Self {
+        let mut levels = Vec::new();
+        let mut level: Vec<Vec<T>> = inputs
+            .iter()
+            .map(|&x| vec![T::zero() - x, T::one()])
+            .collect();
+        while level.len() > 1 {
+            let parent = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => Self::mul(left, right),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(level);
+            level = parent;
+        }
+        if !level.is_empty() {
+            levels.push(level);
+        }
+        SubproductTree { inputs, levels }
+    }
+
+    /// Build the subproduct tree like [`new`][Self::new], but return
+    /// [`Error::DuplicateInput`] if the inputs contain duplicates, or
+    /// [`Error::LimitExceeded`] if there are more than the default [`Limits`] allow.
+    /// Use [`try_new_limited`][Self::try_new_limited] for larger groups.
+    pub fn try_new(inputs: Vec<T>) -> Result<Self, Error> {
+        Self::try_new_limited(inputs, &Limits::default())
+    }
+
+    /// Build the subproduct tree like [`try_new`][Self::try_new], but with the given
+    /// limits in place of the defaults. The cost of [`interpolate`][Self::interpolate]
+    /// and [`evaluate`][Self::evaluate] is bounded by the size of the tree, so this
+    /// also bounds the work done by every later interpolation.
+    pub fn try_new_limited(inputs: Vec<T>, limits: &Limits) -> Result<Self, Error> {
+        limits
+            .check_evaluations(inputs.len())
+            .map_err(Error::LimitExceeded)?;
+        check_distinct(&inputs)?;
+        Ok(Self::new(inputs))
+    }
+
+    /// Returns the inputs of the tree.
+    pub fn inputs(&self) -> &[T] {
+        &self.inputs
+    }
+
+    /// Returns the coefficients of the root `∏ (x - x_i)`, which vanishes on every input.
+    pub fn root(&self) -> &[T] {
+        match self.levels.last() {
+            Some(level) => &level[0],
+            None => &[],
+        }
+    }
+
+    /// Evaluate the standard-form polynomial with the given coefficients at every input
+    /// of the tree, in the same order as the inputs.
+    pub fn evaluate(&self, coefficients: &[T]) -> Vec<T> {
+        let mut remainders = vec![coefficients.to_vec()];
+        for level in self.levels.iter().rev() {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(j, node)| Self::remainder(&remainders[j / 2], node))
+                .collect();
+        }
+        if self.levels.is_empty() {
+            return Vec::new();
+        }
+        remainders
+            .into_iter()
+            .map(|r| r.first().copied().unwrap_or_else(T::zero))
+            .collect()
+    }
+
+    /// Interpolate the polynomial of degree less than the number of inputs which
+    /// outputs `outputs[i]` at each input `x_i`.
+    ///
+    /// Panics if the number of outputs does not match the number of inputs, if
+    /// the inputs contain duplicates, or if `T` is not a [field][UnsafeDiv::FIELD].
+    pub fn interpolate(&self, outputs: &[T]) -> StandardFormPolynomial<T> {
+        // Integer inverses truncate to zero, which would silently zero every weight.
+        assert!(T::FIELD, "subproduct interpolation requires a field type");
+        assert_eq!(
+            outputs.len(),
+            self.inputs.len(),
+            "number of outputs must match the number of tree inputs"
+        );
+        if self.levels.is_empty() {
+            return StandardFormPolynomial::new(Vec::new());
+        }
+
+        // The barycentric weight of x_i is 1 / m'(x_i), where m is the root.
+        let mut k = T::zero();
+        let derivative: Vec<T> = self.root()[1..]
+            .iter()
+            .map(|&c| {
+                k = k + T::one();
+                c * k
+            })
+            .collect();
+        let mut weights = self.evaluate(&derivative);
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "cannot interpolate with duplicate inputs"
+        );
+        batch_invert(&mut weights);
+
+        // Each node combines its children as left * m_right + right * m_left.
+        let mut combined: Vec<Vec<T>> = outputs
+            .iter()
+            .zip(weights)
+            .map(|(&y, w)| vec![y * w])
+            .collect();
+        for level in &self.levels[..self.levels.len() - 1] {
+            combined = combined
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(values, nodes)| match (values, nodes) {
+                    ([left, right], [left_node, right_node]) => {
+                        Self::add(&Self::mul(left, right_node), &Self::mul(right, left_node))
+                    }
+                    _ => values[0].clone(),
+                })
+                .collect();
+        }
+
+        let mut coefficients = combined.swap_remove(0);
+        coefficients.resize(self.inputs.len(), T::zero());
+        StandardFormPolynomial::new(coefficients)
+    }
+
+    fn add(a: &[T], b: &[T]) -> Vec<T> {
+        let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        let mut out = long.to_vec();
+        for (o, &s) in out.iter_mut().zip(short) {
+            *o = *o + s;
+        }
+        out
+    }
+
+    /// Multiply two polynomials with Karatsuba's method.
+    fn mul(a: &[T], b: &[T]) -> Vec<T> {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        let mut out = vec![T::zero(); a.len() + b.len() - 1];
+        if a.len().min(b.len()) < SCHOOLBOOK_CUTOFF {
+            for (i, &ai) in a.iter().enumerate() {
+                for (j, &bj) in b.iter().enumerate() {
+                    out[i + j] = out[i + j] + ai * bj;
+                }
+            }
+            return out;
+        }
+
+        // a = a0 + x^h a1 and b = b0 + x^h b1. When the operands are unbalanced,
+        // only the longer one is split.
+        let h = a.len().max(b.len()) / 2;
+        if a.len() <= h || b.len() <= h {
+            let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+            let (low, high) = long.split_at(h);
+            for (i, c) in Self::mul(low, short).into_iter().enumerate() {
+                out[i] = out[i] + c;
+            }
+            for (i, c) in Self::mul(high, short).into_iter().enumerate() {
+                out[h + i] = out[h + i] + c;
+            }
+            return out;
+        }
+
+        let (a0, a1) = a.split_at(h);
+        let (b0, b1) = b.split_at(h);
+        let z0 = Self::mul(a0, b0);
+        let z2 = Self::mul(a1, b1);
+        let mut z1 = Self::mul(&Self::add(a0, a1), &Self::add(b0, b1));
+        for (i, &c) in z0.iter().enumerate() {
+            z1[i] = z1[i] - c;
+        }
+        for (i, &c) in z2.iter().enumerate() {
+            z1[i] = z1[i] - c;
+        }
+
+        for (i, c) in z0.into_iter().enumerate() {
+            out[i] = out[i] + c;
+        }
+        for (i, c) in z1.into_iter().enumerate() {
+            out[h + i] = out[h + i] + c;
+        }
+        for (i, c) in z2.into_iter().enumerate() {
+            out[2 * h + i] = out[2 * h + i] + c;
+        }
+        out
+    }
+
+    /// Computes `h` such that `g * h = 1 mod x^k` by Newton iteration, given that
+    /// `g[0] = 1`. Each step doubles the precision: `h' = h * (2 - g * h)`.
+    fn inverse_series(g: &[T], k: usize) -> Vec<T> {
+        let mut h = vec![T::one()];
+        let mut precision = 1;
+        while precision < k {
+            precision = (2 * precision).min(k);
+            let mut e = Self::mul(&g[..g.len().min(precision)], &h);
+            e.truncate(precision);
+            for c in e.iter_mut() {
+                *c = T::zero() - *c;
+            }
+            e[0] = e[0] + T::one() + T::one();
+            h = Self::mul(&h, &e);
+            h.truncate(precision);
+        }
+        h
+    }
+
+    /// Computes the remainder of `f` divided by the monic polynomial `m`.
+    fn remainder(f: &[T], m: &[T]) -> Vec<T> {
+        let d = m.len() - 1;
+        if f.len() <= d {
+            return f.to_vec();
+        }
+
+        // The number of quotient coefficients.
+        let k = f.len() - d;
+        if d < SCHOOLBOOK_CUTOFF || k < SCHOOLBOOK_CUTOFF {
+            let mut r = f.to_vec();
+            for i in (d..r.len()).rev() {
+                let q = r[i];
+                for (j, &mj) in m[..d].iter().enumerate() {
+                    r[i - d + j] = r[i - d + j] - q * mj;
+                }
+            }
+            r.truncate(d);
+            return r;
+        }
+
+        // With polynomials reversed, the quotient is rev(f) / rev(m) mod x^k.
+        let reversed_f: Vec<T> = f.iter().rev().take(k).copied().collect();
+        let reversed_m: Vec<T> = m.iter().rev().copied().collect();
+        let mut quotient = Self::mul(&reversed_f, &Self::inverse_series(&reversed_m, k));
+        quotient.truncate(k);
+        quotient.reverse();
+
+        let product = Self::mul(&quotient, m);
+        f[..d].iter().zip(product).map(|(&c, p)| c - p).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Gf256, Polynomial};
+    use secp::MaybeScalar;
+
+    #[test]
+    fn test_subproduct_tree() {
+        for n in [1, 2, 5, 100] {
+            let inputs: Vec<MaybeScalar> = (1..=n).map(|i| MaybeScalar::from(i * 7)).collect();
+            let coefficients: Vec<MaybeScalar> =
+                (0..n).map(|i| MaybeScalar::from(i * i + 3)).collect();
+            let f = StandardFormPolynomial::new(coefficients.clone());
+
+            let tree = SubproductTree::new(inputs.clone());
+            let outputs = tree.evaluate(&coefficients);
+            assert_eq!(outputs, f.evaluate_many(&inputs));
+            assert_eq!(tree.interpolate(&outputs), f);
+            for &x in &inputs {
+                assert_eq!(
+                    StandardFormPolynomial::new(tree.root().to_vec()).evaluate(x),
+                    MaybeScalar::Zero
+                );
+            }
+        }
+
+        let inputs: Vec<Gf256> = (1..=200).map(Gf256).collect();
+        let coefficients: Vec<Gf256> = (0..200).map(|i| Gf256(i as u8 ^ 0x5A)).collect();
+        let tree = SubproductTree::new(inputs);
+        let outputs = tree.evaluate(&coefficients);
+        assert_eq!(tree.interpolate(&outputs).coefficients, coefficients);
+
+        assert_eq!(
+            SubproductTree::try_new(vec![Gf256(1), Gf256(2), Gf256(1)]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_subproduct_tree_integers() {
+        let inputs = vec![1i64, 2, 3, 4];
+        let coefficients = vec![5i64, -2, 0, 3];
+        let f = StandardFormPolynomial::new(coefficients.clone());
+        let tree = SubproductTree::new(inputs.clone());
+        assert_eq!(tree.evaluate(&coefficients), f.evaluate_many(&inputs));
+    }
+
+    #[test]
+    #[should_panic(expected = "requires a field type")]
+    fn test_subproduct_tree_integer_interpolation() {
+        let tree = SubproductTree::new(vec![1i64, 2, 3]);
+        tree.interpolate(&[1, 4, 9]);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    Evaluation, InputSequence, LagrangePolynomial, Polynomial, StandardFormPolynomial, UnsafeDiv,
+};
+use core::ops::{Add, Mul, Neg, Sub};
+use crypto_bigint::modular::constant_mod::{Residue, ResidueParams};
+use crypto_bigint::Uint;
+
+/// An element of the prime field `GF(p)`, for an arbitrary prime modulus `p` defined
+/// at compile time with [`crypto_bigint::impl_modulus`].
+///
+/// The modulus must be prime, or interpolation may panic when it encounters an input
+/// difference with no multiplicative inverse. Arithmetic is constant-time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrimeFieldElement<MOD: ResidueParams<LIMBS>, const LIMBS: usize>(
+    pub Residue<MOD, LIMBS>,
+);
+
+/// A share of a secret in an arbitrary prime field.
+pub type PrimeFieldShare<MOD, const LIMBS: usize> =
+    Evaluation<PrimeFieldElement<MOD, LIMBS>, PrimeFieldElement<MOD, LIMBS>>;
+
+/// A secret sharing polynomial over an arbitrary prime field.
+pub type PrimeFieldSharingPolynomial<MOD, const LIMBS: usize> =
+    StandardFormPolynomial<PrimeFieldElement<MOD, LIMBS>>;
+
+/// A secret polynomial interpolated from shares in an arbitrary prime field.
+pub type InterpolatedPrimeFieldPolynomial<MOD, const LIMBS: usize> =
+    LagrangePolynomial<PrimeFieldElement<MOD, LIMBS>, PrimeFieldElement<MOD, LIMBS>>;
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> PrimeFieldElement<MOD, LIMBS> {
+    /// Reduce an integer modulo `p` to construct a field element.
+    pub const fn new(integer: &Uint<LIMBS>) -> Self {
+        PrimeFieldElement(Residue::new(integer))
+    }
+
+    /// Returns the canonical integer representation of this element, in `[0, p)`.
+    pub const fn retrieve(&self) -> Uint<LIMBS> {
+        self.0.retrieve()
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> From<u64> for PrimeFieldElement<MOD, LIMBS> {
+    fn from(n: u64) -> Self {
+        PrimeFieldElement::new(&Uint::from_u64(n))
+    }
+}
+
+/// Enumerates the integers `0, 1, 2, ...` below the modulus.
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> InputSequence
+    for PrimeFieldElement<MOD, LIMBS>
+{
+    fn nth_input(n: usize) -> Option<Self> {
+        let n = Uint::from_u64(u64::try_from(n).ok()?);
+        if n >= MOD::MODULUS {
+            return None;
+        }
+        Some(PrimeFieldElement::new(&n))
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Add for PrimeFieldElement<MOD, LIMBS> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        PrimeFieldElement(self.0 + rhs.0)
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Sub for PrimeFieldElement<MOD, LIMBS> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        PrimeFieldElement(self.0 - rhs.0)
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Mul for PrimeFieldElement<MOD, LIMBS> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        PrimeFieldElement(self.0 * rhs.0)
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Neg for PrimeFieldElement<MOD, LIMBS> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        PrimeFieldElement(-self.0)
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> num_traits::Zero
+    for PrimeFieldElement<MOD, LIMBS>
+{
+    fn zero() -> Self {
+        PrimeFieldElement(Residue::ZERO)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == Residue::ZERO
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> num_traits::One
+    for PrimeFieldElement<MOD, LIMBS>
+{
+    fn one() -> Self {
+        PrimeFieldElement(Residue::ONE)
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> UnsafeDiv<PrimeFieldElement<MOD, LIMBS>>
+    for PrimeFieldElement<MOD, LIMBS>
+{
+    type Output = Self;
+    const FIELD: bool = true;
+
+    fn unsafe_div(num: Self, denom: Self) -> Self {
+        let (inverse, is_some) = denom.0.invert();
+        if !bool::from(is_some) {
+            unreachable!("divided by non-invertible field element");
+        }
+        PrimeFieldElement(num.0 * inverse)
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> PrimeFieldSharingPolynomial<MOD, LIMBS> {
+    /// Issue a share at the given input `x`.
+    pub fn issue_share(&self, x: PrimeFieldElement<MOD, LIMBS>) -> PrimeFieldShare<MOD, LIMBS> {
+        Evaluation::new(x, self.evaluate(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::{impl_modulus, U256};
+    use num_traits::Zero;
+
+    impl_modulus!(
+        Curve25519Prime,
+        U256,
+        "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+    );
+
+    type Fp = PrimeFieldElement<Curve25519Prime, { U256::LIMBS }>;
+
+    #[test]
+    fn test_prime_field_sharing() {
+        let secret =
+            U256::from_be_hex("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef");
+        let f =
+            PrimeFieldSharingPolynomial::new(vec![Fp::new(&secret), Fp::from(77), Fp::from(1000)]);
+
+        let shares: Vec<_> = (1..=3).map(|i| f.issue_share(Fp::from(i))).collect();
+        let interpolated = InterpolatedPrimeFieldPolynomial::new(shares);
+        assert_eq!(interpolated.evaluate(Fp::zero()).retrieve(), secret);
+        assert_eq!(interpolated.evaluate(Fp::from(9)), f.evaluate(Fp::from(9)));
+
+        assert_eq!(-Fp::from(1) + Fp::from(1), Fp::zero());
+    }
+}
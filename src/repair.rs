@@ -0,0 +1,156 @@
+use crate::{check_distinct, Error, Evaluation, LagrangePolynomial, SecretShare};
+use alloc::vec::Vec;
+use secp::MaybeScalar;
+
+/// Compute the Lagrange coefficient of the helper at `index`, for interpolating
+/// the polynomial at `x_new` from the shares of `helpers`.
+fn repair_coefficient(helpers: &[MaybeScalar], index: usize, x_new: MaybeScalar) -> MaybeScalar {
+    let basis = LagrangePolynomial::<MaybeScalar, ()>::new(
+        helpers.iter().map(|&x| Evaluation::new(x, ())).collect(),
+    )
+    .basis_at(x_new);
+    basis[index]
+}
+
+/// Compute a helper's masked contributions towards repairing the share at `x_new`.
+/// This is the first step of the repair protocol.
+///
+/// A threshold of shareholders, whose inputs are listed in `helpers`, can compute a
+/// replacement share `f(x_new)` for a shareholder who lost theirs, without any party
+/// learning `f` or another party's share:
+///
+/// 1. Each helper calls `repair_deltas`, which splits their weighted share
+///    `λ_i * f(x_i)` into one random additive part per helper. The `i`-th part
+///    is sent privately to the `i`-th helper in `helpers`.
+/// 2. Each helper sums the parts they received with [`aggregate_repair_deltas`],
+///    and sends the sum privately to the new shareholder.
+/// 3. The new shareholder sums those values with [`recover_repaired_share`].
+///
+/// `masks` must contain `helpers.len() - 1` uniformly random scalars, which must
+/// never be reused.
+///
+/// Returns [`Error::DuplicateInput`] if `helpers` contains duplicates,
+/// [`Error::ReservedInput`] if `x_new` is in `helpers`, [`Error::UnknownInput`] if
+/// `share.input` is not in `helpers`, or [`Error::WrongCount`] if the number of
+/// masks is wrong.
+pub fn repair_deltas(
+    share: &SecretShare,
+    helpers: &[MaybeScalar],
+    x_new: MaybeScalar,
+    masks: &[MaybeScalar],
+) -> Result<Vec<MaybeScalar>, Error> {
+    check_distinct(helpers)?;
+    if let Some(index) = helpers.iter().position(|&x| x == x_new) {
+        return Err(Error::ReservedInput { index });
+    }
+    let index = helpers
+        .iter()
+        .position(|&x| x == share.input)
+        .ok_or(Error::UnknownInput)?;
+    if masks.len() + 1 != helpers.len() {
+        return Err(Error::WrongCount {
+            expected: helpers.len().saturating_sub(1),
+            count: masks.len(),
+        });
+    }
+
+    let weighted = repair_coefficient(helpers, index, x_new) * share.output;
+    let mask_sum = masks.iter().fold(MaybeScalar::Zero, |sum, &m| sum + m);
+
+    let mut deltas = masks.to_vec();
+    deltas.push(weighted - mask_sum);
+    Ok(deltas)
+}
+
+/// Compute a helper's masked contributions like [`repair_deltas`], sampling the
+/// masks randomly. Returns an error in the same cases as [`repair_deltas`].
+#[cfg(feature = "rand")]
+pub fn repair_deltas_random<R: rand::RngCore + rand::CryptoRng>(
+    share: &SecretShare,
+    helpers: &[MaybeScalar],
+    x_new: MaybeScalar,
+    rng: &mut R,
+) -> Result<Vec<MaybeScalar>, Error> {
+    let masks = crate::random_coefficients(rng, helpers.len().saturating_sub(1));
+    repair_deltas(share, helpers, x_new, &masks)
+}
+
+/// Sum the parts a helper received from every helper (including themselves) in the
+/// first step of the repair protocol. The result is sent to the new shareholder.
+pub fn aggregate_repair_deltas(deltas: &[MaybeScalar]) -> MaybeScalar {
+    deltas.iter().fold(MaybeScalar::Zero, |sum, &d| sum + d)
+}
+
+/// Recover the repaired share at `x_new` from the aggregated values sent by each helper.
+pub fn recover_repaired_share(x_new: MaybeScalar, sums: &[MaybeScalar]) -> SecretShare {
+    Evaluation::new(x_new, aggregate_repair_deltas(sums))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+
+    #[test]
+    fn test_share_repair() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(10),
+            MaybeScalar::from(20),
+            MaybeScalar::from(30),
+        ]);
+        let helpers: Vec<MaybeScalar> = [1, 3, 4].into_iter().map(MaybeScalar::from).collect();
+        let x_new = MaybeScalar::from(2);
+
+        // deltas[i][j] is the part helper i sends to helper j.
+        let deltas: Vec<Vec<MaybeScalar>> = helpers
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| {
+                let masks = [
+                    MaybeScalar::from(1000 + i as u128),
+                    MaybeScalar::from(2000 + i as u128),
+                ];
+                repair_deltas(&f.issue_share(x), &helpers, x_new, &masks).unwrap()
+            })
+            .collect();
+
+        let sums: Vec<MaybeScalar> = (0..helpers.len())
+            .map(|j| {
+                let received: Vec<MaybeScalar> = deltas.iter().map(|d| d[j]).collect();
+                aggregate_repair_deltas(&received)
+            })
+            .collect();
+
+        assert_eq!(recover_repaired_share(x_new, &sums), f.issue_share(x_new));
+
+        let share = f.issue_share(helpers[0]);
+        let masks = [MaybeScalar::from(1), MaybeScalar::from(2)];
+        assert_eq!(
+            repair_deltas(&share, &helpers, helpers[2], &masks),
+            Err(Error::ReservedInput { index: 2 })
+        );
+        assert_eq!(
+            repair_deltas(
+                &f.issue_share(x_new),
+                &helpers,
+                MaybeScalar::from(5),
+                &masks
+            ),
+            Err(Error::UnknownInput)
+        );
+        assert_eq!(
+            repair_deltas(&share, &helpers, x_new, &masks[..1]),
+            Err(Error::WrongCount {
+                expected: 2,
+                count: 1
+            })
+        );
+        assert_eq!(
+            repair_deltas(&share, &[helpers[0], helpers[0]], x_new, &masks[..1]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use crate::{tagged_hash, LimitExceeded, Limits};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use secp::MaybeScalar;
+
+/// Deterministically derive the share input `x` for a shareholder identity.
+///
+/// The `counter` is used to resolve collisions: the first counter which yields
+/// a non-zero input not already taken by another identity is used.
+pub fn derive_share_input(identity: &str, counter: u32) -> MaybeScalar {
+    let mut preimage = Vec::with_capacity(4 + identity.len());
+    preimage.extend_from_slice(&counter.to_be_bytes());
+    preimage.extend_from_slice(identity.as_bytes());
+    MaybeScalar::reduce_from(&tagged_hash(b"qudoku/roster/index", &preimage))
+}
+
+/// The maximum length in bytes of a roster identity, which is serialized with a
+/// 2-byte length prefix.
+pub const MAX_IDENTITY_LEN: usize = u16::MAX as usize;
+
+fn check_identity_len(identity: &str) -> Result<(), RosterError> {
+    if identity.len() > MAX_IDENTITY_LEN {
+        return Err(RosterError::IdentityTooLong(identity.len()));
+    }
+    Ok(())
+}
+
+/// Errors which can occur when modifying or parsing a [`Roster`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RosterError {
+    /// The identity is already registered in the roster.
+    DuplicateIdentity(String),
+
+    /// The identity is not registered in the roster, or has been revoked.
+    UnknownIdentity(String),
+
+    /// The identity is longer than [`MAX_IDENTITY_LEN`] bytes, so it cannot be serialized.
+    IdentityTooLong(usize),
+
+    /// The roster encoding is malformed.
+    InvalidEncoding,
+
+    /// The roster encoding exceeds the default [`Limits`].
+    LimitExceeded(LimitExceeded),
+}
+
+impl core::fmt::Display for RosterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RosterError::DuplicateIdentity(id) => write!(f, "identity {:?} already registered", id),
+            RosterError::UnknownIdentity(id) => write!(f, "identity {:?} is not active", id),
+            RosterError::IdentityTooLong(len) => {
+                write!(
+                    f,
+                    "identity of {} bytes exceeds {} bytes",
+                    len, MAX_IDENTITY_LEN
+                )
+            }
+            RosterError::InvalidEncoding => write!(f, "invalid roster encoding"),
+            RosterError::LimitExceeded(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RosterError {}
+
+/// A single shareholder registered in a [`Roster`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RosterEntry {
+    /// The human-readable identity of the shareholder.
+    pub identity: String,
+
+    /// The share input `x` assigned to the shareholder.
+    pub input: MaybeScalar,
+
+    /// Whether the shareholder has been revoked. Revoked inputs are never reassigned.
+    pub revoked: bool,
+}
+
+/// A registry mapping human identities to share inputs.
+///
+/// Inputs are derived with [`derive_share_input`], so every participant who
+/// replays the same sequence of registrations arrives at the same roster.
+/// Entries are kept in registration order, and the roster can be serialized
+/// and published alongside a group's public information so that identities
+/// resolve to inputs identically for everyone.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Roster {
+    entries: Vec<RosterEntry>,
+}
+
+impl Roster {
+    /// Construct an empty roster.
+    pub fn new() -> Self {
+        Roster::default()
+    }
+
+    /// Returns all entries in registration order, including revoked ones.
+    pub fn entries(&self) -> &[RosterEntry] {
+        &self.entries
+    }
+
+    /// Returns an iterator over entries which have not been revoked.
+    pub fn active(&self) -> impl Iterator<Item = &RosterEntry> {
+        self.entries.iter().filter(|entry| !entry.revoked)
+    }
+
+    fn find_active(&self, identity: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| !entry.revoked && entry.identity == identity)
+    }
+
+    /// Register a new identity, returning the share input assigned to it.
+    ///
+    /// If the derived input is zero or already assigned to another entry (active
+    /// or revoked), the next counter value is tried. Identities longer than
+    /// [`MAX_IDENTITY_LEN`] bytes are rejected.
+    pub fn register(&mut self, identity: &str) -> Result<MaybeScalar, RosterError> {
+        check_identity_len(identity)?;
+        if self.find_active(identity).is_some() {
+            return Err(RosterError::DuplicateIdentity(identity.to_string()));
+        }
+
+        let mut counter = 0;
+        let input = loop {
+            let input = derive_share_input(identity, counter);
+            if input != MaybeScalar::Zero && self.identity_of_any(input).is_none() {
+                break input;
+            }
+            counter += 1;
+        };
+
+        self.entries.push(RosterEntry {
+            identity: identity.to_string(),
+            input,
+            revoked: false,
+        });
+        Ok(input)
+    }
+
+    /// Rename an active identity. The share input assigned to it is unchanged.
+    /// New identities longer than [`MAX_IDENTITY_LEN`] bytes are rejected.
+    pub fn rename(&mut self, old: &str, new: &str) -> Result<(), RosterError> {
+        check_identity_len(new)?;
+        let index = self
+            .find_active(old)
+            .ok_or_else(|| RosterError::UnknownIdentity(old.to_string()))?;
+        if self.find_active(new).is_some() {
+            return Err(RosterError::DuplicateIdentity(new.to_string()));
+        }
+        self.entries[index].identity = new.to_string();
+        Ok(())
+    }
+
+    /// Revoke an active identity, returning its share input. The input remains
+    /// reserved, so it is never reassigned to a later registration.
+    pub fn revoke(&mut self, identity: &str) -> Result<MaybeScalar, RosterError> {
+        let index = self
+            .find_active(identity)
+            .ok_or_else(|| RosterError::UnknownIdentity(identity.to_string()))?;
+        self.entries[index].revoked = true;
+        Ok(self.entries[index].input)
+    }
+
+    /// Resolve an active identity to its share input.
+    pub fn resolve(&self, identity: &str) -> Option<MaybeScalar> {
+        self.find_active(identity)
+            .map(|index| self.entries[index].input)
+    }
+
+    /// Resolve a share input to the active identity it is assigned to.
+    pub fn identity_of(&self, input: MaybeScalar) -> Option<&str> {
+        self.active()
+            .find(|entry| entry.input == input)
+            .map(|entry| entry.identity.as_str())
+    }
+
+    fn identity_of_any(&self, input: MaybeScalar) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.input == input)
+            .map(|entry| entry.identity.as_str())
+    }
+
+    /// Serialize the roster. Each entry is encoded in registration order as
+    /// `revoked (1 byte) || x (32 bytes) || len (2 bytes, big-endian) || identity`,
+    /// preceded by the number of entries as a 4-byte big-endian integer.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&(self.entries.len() as u32).to_be_bytes());
+        for entry in self.entries.iter() {
+            out.push(entry.revoked as u8);
+            out.extend_from_slice(&entry.input.serialize());
+            out.extend_from_slice(&(entry.identity.len() as u16).to_be_bytes());
+            out.extend_from_slice(entry.identity.as_bytes());
+        }
+        out
+    }
+
+    /// Parse a roster from the encoding produced by [`serialize`][Self::serialize].
+    ///
+    /// Returns an error if the encoding is malformed, if it contains duplicate
+    /// inputs or duplicate active identities, or if it exceeds the default [`Limits`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RosterError> {
+        Limits::default()
+            .check_roster_encoding(bytes)
+            .map_err(RosterError::LimitExceeded)?;
+        Roster::parse(bytes)
+    }
+
+    pub(crate) fn parse(bytes: &[u8]) -> Result<Self, RosterError> {
+        fn take<'a>(bytes: &mut &'a [u8], n: usize) -> Result<&'a [u8], RosterError> {
+            if bytes.len() < n {
+                return Err(RosterError::InvalidEncoding);
+            }
+            let (head, tail) = bytes.split_at(n);
+            *bytes = tail;
+            Ok(head)
+        }
+
+        let mut rest = bytes;
+        let count = u32::from_be_bytes(take(&mut rest, 4)?.try_into().unwrap());
+
+        let mut roster = Roster::new();
+        for _ in 0..count {
+            let revoked = match take(&mut rest, 1)?[0] {
+                0 => false,
+                1 => true,
+                _ => return Err(RosterError::InvalidEncoding),
+            };
+            let input = MaybeScalar::from_slice(take(&mut rest, 32)?)
+                .map_err(|_| RosterError::InvalidEncoding)?;
+            let len = u16::from_be_bytes(take(&mut rest, 2)?.try_into().unwrap());
+            let identity = core::str::from_utf8(take(&mut rest, len as usize)?)
+                .map_err(|_| RosterError::InvalidEncoding)?;
+
+            if input == MaybeScalar::Zero || roster.identity_of_any(input).is_some() {
+                return Err(RosterError::InvalidEncoding);
+            }
+            if !revoked && roster.find_active(identity).is_some() {
+                return Err(RosterError::DuplicateIdentity(identity.to_string()));
+            }
+
+            roster.entries.push(RosterEntry {
+                identity: identity.to_string(),
+                input,
+                revoked,
+            });
+        }
+
+        if !rest.is_empty() {
+            return Err(RosterError::InvalidEncoding);
+        }
+        Ok(roster)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roster() {
+        let mut roster = Roster::new();
+        let alice = roster.register("alice").unwrap();
+        let bob = roster.register("bob").unwrap();
+        assert_eq!(alice, derive_share_input("alice", 0));
+        assert_ne!(alice, bob);
+        assert_eq!(
+            roster.register("alice"),
+            Err(RosterError::DuplicateIdentity("alice".to_string()))
+        );
+
+        assert_eq!(roster.resolve("alice"), Some(alice));
+        assert_eq!(roster.identity_of(bob), Some("bob"));
+
+        roster.rename("bob", "robert").unwrap();
+        assert_eq!(roster.resolve("bob"), None);
+        assert_eq!(roster.resolve("robert"), Some(bob));
+        assert_eq!(
+            roster.rename("robert", "alice"),
+            Err(RosterError::DuplicateIdentity("alice".to_string()))
+        );
+
+        assert_eq!(roster.revoke("alice"), Ok(alice));
+        assert_eq!(roster.resolve("alice"), None);
+        assert_eq!(roster.identity_of(alice), None);
+        assert_eq!(roster.active().count(), 1);
+
+        // Re-registering a revoked identity collides with its old input,
+        // so it is assigned the next counter value.
+        let alice2 = roster.register("alice").unwrap();
+        assert_eq!(alice2, derive_share_input("alice", 1));
+
+        let bytes = roster.serialize();
+        assert_eq!(Roster::from_bytes(&bytes), Ok(roster.clone()));
+        assert_eq!(
+            Roster::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(RosterError::InvalidEncoding)
+        );
+
+        let long = "x".repeat(MAX_IDENTITY_LEN + 1);
+        assert_eq!(
+            roster.register(&long),
+            Err(RosterError::IdentityTooLong(MAX_IDENTITY_LEN + 1))
+        );
+        assert_eq!(
+            roster.rename("robert", &long),
+            Err(RosterError::IdentityTooLong(MAX_IDENTITY_LEN + 1))
+        );
+        assert_eq!(roster.serialize(), bytes);
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            Roster::from_bytes(&trailing),
+            Err(RosterError::InvalidEncoding)
+        );
+    }
+}
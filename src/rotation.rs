@@ -0,0 +1,203 @@
+//! Key rotation with linked commitments.
+//!
+//! When a group rotates its secret, rather than only refreshing its shares, the new
+//! group has a new [`VssCommitment`] and a new public key. To let external systems
+//! follow the key's lineage, a threshold of the old group signs a [`RotationLink`]
+//! naming both commitments, using the [`signing`][crate::signing] protocol under the
+//! old group key:
+//!
+//! 1. The new polynomial is dealt, giving the new commitment.
+//! 2. A threshold of the old shareholders sign [`rotation_message`] for the old and new
+//!    commitments, producing an ordinary BIP-340 signature under the old group key.
+//! 3. The [`RotationLink`] is published. A chain of links from a trusted commitment
+//!    is checked with [`verify_lineage`].
+
+use crate::{tagged_hash, verify_schnorr, VssCommitment};
+
+/// Errors which can occur when constructing or following rotation links. Each
+/// variant identifies the index of the offending link.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RotationError {
+    /// The link's signature does not verify under the old group key.
+    InvalidSignature(usize),
+
+    /// The link's old commitment is not the previous link's new commitment, or not
+    /// the trusted commitment for the first link.
+    BrokenChain(usize),
+}
+
+impl core::fmt::Display for RotationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RotationError::InvalidSignature(i) => {
+                write!(f, "rotation link {} has an invalid signature", i)
+            }
+            RotationError::BrokenChain(i) => {
+                write!(
+                    f,
+                    "rotation link {} does not follow the previous commitment",
+                    i
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RotationError {}
+
+/// Returns the message which the old group signs to endorse the new commitment,
+/// a tagged hash of the fingerprints of both commitments.
+pub fn rotation_message(old: &VssCommitment, new: &VssCommitment) -> [u8; 32] {
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(&old.fingerprint());
+    preimage[32..].copy_from_slice(&new.fingerprint());
+    tagged_hash(b"qudoku/rotation", &preimage)
+}
+
+/// A proof that the group with commitment `old` endorsed `new` as its successor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RotationLink {
+    pub old: VssCommitment,
+    pub new: VssCommitment,
+
+    /// A BIP-340 signature on [`rotation_message`] under the old group key.
+    pub signature: [u8; 64],
+}
+
+impl RotationLink {
+    /// Construct a link from the signature produced by a threshold of the old group,
+    /// returning [`RotationError::InvalidSignature`] if it does not verify.
+    pub fn new(
+        old: VssCommitment,
+        new: VssCommitment,
+        signature: [u8; 64],
+    ) -> Result<Self, RotationError> {
+        let link = RotationLink {
+            old,
+            new,
+            signature,
+        };
+        if !link.verify() {
+            return Err(RotationError::InvalidSignature(0));
+        }
+        Ok(link)
+    }
+
+    /// Returns the message signed by the old group.
+    pub fn message(&self) -> [u8; 32] {
+        rotation_message(&self.old, &self.new)
+    }
+
+    /// Verify the signature under the old group key.
+    pub fn verify(&self) -> bool {
+        match self.old.public_key().not_inf() {
+            Ok(old_key) => verify_schnorr(old_key, &self.message(), &self.signature),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Follow a chain of rotation links starting from a trusted commitment, and return
+/// the current commitment at the end of the chain.
+pub fn verify_lineage<'a>(
+    trusted: &'a VssCommitment,
+    links: &'a [RotationLink],
+) -> Result<&'a VssCommitment, RotationError> {
+    let mut current = trusted;
+    for (i, link) in links.iter().enumerate() {
+        if &link.old != current {
+            return Err(RotationError::BrokenChain(i));
+        }
+        if !link.verify() {
+            return Err(RotationError::InvalidSignature(i));
+        }
+        current = &link.new;
+    }
+    Ok(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::{SigningNonces, SigningPackage};
+    use crate::{SecretShare, SecretSharingPolynomial};
+    use alloc::vec::Vec;
+    use secp::MaybeScalar;
+
+    /// Sign a message with shares 1 and 2 of a 2-of-n polynomial.
+    fn threshold_sign(f: &SecretSharingPolynomial, message: [u8; 32]) -> [u8; 64] {
+        let shares: Vec<SecretShare> = (1..=2)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+        let nonces: Vec<SigningNonces> = shares
+            .iter()
+            .map(|share| SigningNonces::derive(share, &message, b"rotation", b""))
+            .collect();
+        let commitments = shares
+            .iter()
+            .zip(&nonces)
+            .map(|(share, n)| n.commitments(share.input))
+            .collect();
+        let group_key = f.commitment().public_key().unwrap();
+        let package = SigningPackage::new(group_key, message, commitments).unwrap();
+        let partials: Vec<_> = shares
+            .iter()
+            .zip(nonces)
+            .map(|(share, n)| package.sign(share, n).unwrap())
+            .collect();
+        package.aggregate(&partials).unwrap()
+    }
+
+    #[test]
+    fn test_rotation_lineage() {
+        let polynomials: Vec<SecretSharingPolynomial> = (0..3u128)
+            .map(|i| {
+                SecretSharingPolynomial::new(vec![
+                    MaybeScalar::from(1000 + i),
+                    MaybeScalar::from(7 + i),
+                ])
+            })
+            .collect();
+        let commitments: Vec<VssCommitment> = polynomials.iter().map(|f| f.commitment()).collect();
+
+        let links: Vec<RotationLink> = (0..2)
+            .map(|i| {
+                let message = rotation_message(&commitments[i], &commitments[i + 1]);
+                let signature = threshold_sign(&polynomials[i], message);
+                RotationLink::new(
+                    commitments[i].clone(),
+                    commitments[i + 1].clone(),
+                    signature,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        assert_eq!(verify_lineage(&commitments[0], &links), Ok(&commitments[2]));
+        assert_eq!(verify_lineage(&commitments[0], &[]), Ok(&commitments[0]));
+        assert_eq!(
+            verify_lineage(&commitments[1], &links),
+            Err(RotationError::BrokenChain(0))
+        );
+        assert_eq!(
+            verify_lineage(&commitments[0], &[links[1].clone()]),
+            Err(RotationError::BrokenChain(0))
+        );
+
+        // A link signed by the new group rather than the old one is rejected.
+        let message = rotation_message(&commitments[0], &commitments[1]);
+        let forged = threshold_sign(&polynomials[1], message);
+        assert_eq!(
+            RotationLink::new(commitments[0].clone(), commitments[1].clone(), forged),
+            Err(RotationError::InvalidSignature(0))
+        );
+
+        let mut tampered = links.clone();
+        tampered[1].signature[63] ^= 1;
+        assert_eq!(
+            verify_lineage(&commitments[0], &tampered),
+            Err(RotationError::InvalidSignature(1))
+        );
+    }
+}
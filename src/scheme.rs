@@ -0,0 +1,142 @@
+use crate::{check_duplicate_inputs, Error, Polynomial, SecretShare, SecretSharingPolynomial};
+use secp::{MaybeScalar, Scalar};
+
+/// A `T`-of-`N` secret sharing scheme, with the threshold `T` and share count `N`
+/// encoded in the type.
+///
+/// Shares are issued at the inputs `1..=N`, and recovery takes exactly `T` shares,
+/// so interpolating with the wrong number of shares is a type error. Constructing a
+/// scheme with `T == 0` or `T > N` fails to compile. Nothing here allocates.
+///
+/// ```compile_fail
+/// use qudoku::{Scheme, SecretSharingPolynomial};
+/// use qudoku::secp::MaybeScalar;
+///
+/// let polynomial = SecretSharingPolynomial::new([MaybeScalar::Zero; 3]);
+/// let scheme = Scheme::<3, 2>::new(polynomial);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scheme<const T: usize, const N: usize> {
+    polynomial: SecretSharingPolynomial<[MaybeScalar; T]>,
+}
+
+impl<const T: usize, const N: usize> Scheme<T, N> {
+    const VALID: () = {
+        assert!(T >= 1, "threshold must be at least one");
+        assert!(T <= N, "threshold must not exceed the number of shares");
+    };
+
+    /// Construct a scheme from a secret sharing polynomial with `T` coefficients.
+    pub fn new(polynomial: SecretSharingPolynomial<[MaybeScalar; T]>) -> Self {
+        let () = Self::VALID;
+        Scheme { polynomial }
+    }
+
+    /// Construct a scheme which shares `secret` using random higher-order coefficients.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(secret: MaybeScalar, rng: &mut R) -> Self {
+        let mut coefficients = [MaybeScalar::Zero; T];
+        for (i, c) in coefficients.iter_mut().enumerate() {
+            *c = if i == 0 {
+                secret
+            } else {
+                MaybeScalar::Valid(Scalar::random(rng))
+            };
+        }
+        Self::new(SecretSharingPolynomial::new(coefficients))
+    }
+
+    /// Returns the secret sharing polynomial.
+    pub fn polynomial(&self) -> &SecretSharingPolynomial<[MaybeScalar; T]> {
+        &self.polynomial
+    }
+
+    /// Returns the shared secret `f(0)`.
+    pub fn secret(&self) -> MaybeScalar {
+        self.polynomial.coefficients[0]
+    }
+
+    /// Issue all `N` shares, at the inputs `1..=N`.
+    pub fn issue_shares(&self) -> [SecretShare; N] {
+        core::array::from_fn(|i| {
+            self.polynomial
+                .issue_share(MaybeScalar::from(i as u128 + 1))
+        })
+    }
+
+    /// Recover the secret from exactly `T` shares.
+    ///
+    /// Returns an error if any two shares have the same input, or if any share has a
+    /// zero input.
+    pub fn recover(shares: &[SecretShare; T]) -> Result<MaybeScalar, Error> {
+        let () = Self::VALID;
+        check_duplicate_inputs(shares)?;
+        if let Some(index) = shares.iter().position(|share| share.input.is_zero()) {
+            return Err(Error::ZeroInput { index });
+        }
+
+        let mut secret = MaybeScalar::Zero;
+        for (i, share) in shares.iter().enumerate() {
+            let mut numerator = MaybeScalar::one();
+            let mut denominator = MaybeScalar::one();
+            for (j, other) in shares.iter().enumerate() {
+                if i != j {
+                    numerator *= other.input;
+                    denominator *= other.input - share.input;
+                }
+            }
+            let denominator = Scalar::try_from(denominator).map_err(|_| Error::DivisionByZero)?;
+            secret += share.output * numerator / denominator;
+        }
+        Ok(secret)
+    }
+}
+
+impl<const T: usize, const N: usize> Polynomial<MaybeScalar, MaybeScalar> for Scheme<T, N> {
+    fn evaluate(&self, x: MaybeScalar) -> MaybeScalar {
+        self.polynomial.evaluate(x)
+    }
+
+    fn degree(&self) -> usize {
+        T - 1
+    }
+}
+
+impl<const T: usize, const N: usize> From<Scheme<T, N>>
+    for SecretSharingPolynomial<[MaybeScalar; T]>
+{
+    fn from(scheme: Scheme<T, N>) -> Self {
+        scheme.polynomial
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Evaluation;
+
+    #[test]
+    fn test_scheme() {
+        let scheme = Scheme::<2, 3>::new(SecretSharingPolynomial::new([
+            MaybeScalar::from(1234),
+            MaybeScalar::from(5678),
+        ]));
+        let [a, b, c] = scheme.issue_shares();
+        assert_eq!(a.input, MaybeScalar::from(1));
+        assert_eq!(c.input, MaybeScalar::from(3));
+
+        assert_eq!(Scheme::<2, 3>::recover(&[a, b]), Ok(scheme.secret()));
+        assert_eq!(Scheme::<2, 3>::recover(&[c, a]), Ok(scheme.secret()));
+        assert_eq!(
+            Scheme::<2, 3>::recover(&[b, b]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 1
+            })
+        );
+        assert_eq!(
+            Scheme::<2, 3>::recover(&[Evaluation::new(MaybeScalar::Zero, b.output), a]),
+            Err(Error::ZeroInput { index: 0 })
+        );
+    }
+}
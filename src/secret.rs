@@ -0,0 +1,100 @@
+use crate::{Coefficients, SecretSharingPolynomial};
+use secp::MaybeScalar;
+use subtle::{Choice, ConstantTimeEq};
+
+/// Holds a secret value, such as a [`SecretSharingPolynomial`], so that it is not
+/// leaked by accident.
+///
+/// `Debug` output shows nothing of the value, and equality is checked in constant
+/// time using [`ConstantTimeEq`]. The value itself is only reachable through
+/// [`expose_secret`][Self::expose_secret].
+///
+/// With the `zeroize` feature, a `Secret` can be wrapped in [`zeroize::Zeroizing`]
+/// to wipe the value when it is dropped.
+#[derive(Clone, Default)]
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    /// Wrap a secret value.
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    /// Returns a reference to the secret value.
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the secret value.
+    pub fn expose_secret_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    /// Unwrap the secret value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+impl<T> core::fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Secret").finish_non_exhaustive()
+    }
+}
+
+impl<T: ConstantTimeEq> ConstantTimeEq for Secret<T> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<T: ConstantTimeEq> PartialEq for Secret<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<T: ConstantTimeEq> Eq for Secret<T> {}
+
+/// Compares every coefficient without branching on their values. The number of
+/// coefficients is treated as public.
+impl<C: Coefficients<MaybeScalar>> ConstantTimeEq for SecretSharingPolynomial<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.coefficients
+            .as_ref()
+            .ct_eq(other.coefficients.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_polynomial() {
+        let f = Secret::new(SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(5),
+            MaybeScalar::from(6),
+        ]));
+        let g = Secret::new(SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(5),
+            MaybeScalar::from(7),
+        ]));
+
+        assert_eq!(f, f.clone());
+        assert_ne!(f, g);
+        assert_ne!(
+            f,
+            Secret::new(SecretSharingPolynomial::new(vec![MaybeScalar::from(5)]))
+        );
+        assert_eq!(format!("{:?}", f), "Secret { .. }");
+        assert_eq!(f.expose_secret().coefficients[1], MaybeScalar::from(6));
+        assert_eq!(g.into_inner().coefficients[1], MaybeScalar::from(7));
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    check_duplicate_inputs, recover_secret, Error, PointShare, SecretShare, VssCommitment,
+    WeightedShare,
+};
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar, Point};
+
+/// A shareholder's view of a qudoku group: their secret share, the dealer's
+/// commitment to the group polynomial `f(x)`, and the nested `Q` points whose
+/// secrets the group can recover.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shareholder {
+    share: SecretShare,
+    commitment: VssCommitment,
+    q_points: Vec<Point>,
+}
+
+impl Shareholder {
+    /// Construct a shareholder from their share, the group commitment, and the nested
+    /// `Q` points. The share is not verified; call [`verify`][Self::verify] for that.
+    pub fn new(share: SecretShare, commitment: VssCommitment, q_points: Vec<Point>) -> Self {
+        Shareholder {
+            share,
+            commitment,
+            q_points,
+        }
+    }
+
+    /// Returns the shareholder's secret share.
+    pub fn share(&self) -> &SecretShare {
+        &self.share
+    }
+
+    /// Returns the dealer's commitment to the group polynomial.
+    pub fn commitment(&self) -> &VssCommitment {
+        &self.commitment
+    }
+
+    /// Returns the nested `Q` points.
+    pub fn q_points(&self) -> &[Point] {
+        &self.q_points
+    }
+
+    /// Replace the nested point `old` with `new` after the group rotates a nested
+    /// secret. The secret share is unchanged. Returns false if `old` is not one of the
+    /// shareholder's nested points.
+    pub fn rotate_q(&mut self, old: Point, new: Point) -> bool {
+        match self.q_points.iter_mut().find(|q| **q == old) {
+            Some(q) => {
+                *q = new;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the group's public key `f(0) * G`.
+    pub fn group_public_key(&self) -> MaybePoint {
+        self.commitment.public_key()
+    }
+
+    /// Check that the share is consistent with the dealer's commitment.
+    pub fn verify(&self) -> bool {
+        self.commitment.verify_share(&self.share)
+    }
+
+    /// Compute the point share `s_i * Q` for an arbitrary point `Q`.
+    pub fn point_share(&self, q: Point) -> PointShare {
+        self.share * q
+    }
+
+    /// Compute the shareholder's contribution towards recovering every nested secret:
+    /// one point share `s_i * Q` for each nested `Q` point, in order.
+    pub fn derive_contribution(&self) -> Vec<PointShare> {
+        self.q_points.iter().map(|&q| self.point_share(q)).collect()
+    }
+}
+
+/// The shares of a shareholder who holds several inputs, such as a participant in a
+/// weighted group or someone holding several roles. The shares are verified and
+/// contributed to recovery together, with [`recover_multi_share_secret`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct MultiShare {
+    shares: Vec<SecretShare>,
+}
+
+impl core::fmt::Debug for MultiShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let inputs: Vec<MaybeScalar> = self.shares.iter().map(|share| share.input).collect();
+        f.debug_struct("MultiShare")
+            .field("inputs", &inputs)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MultiShare {
+    /// Bundle the shares held by one shareholder.
+    ///
+    /// Returns an error if no shares are given, if any share has a zero input, or if
+    /// two shares have the same input.
+    pub fn new(shares: Vec<SecretShare>) -> Result<Self, Error> {
+        if shares.is_empty() {
+            return Err(Error::Empty);
+        }
+        if let Some(index) = shares.iter().position(|share| share.input.is_zero()) {
+            return Err(Error::ZeroInput { index });
+        }
+        check_duplicate_inputs(&shares)?;
+        Ok(MultiShare { shares })
+    }
+
+    /// Returns the shares, in the order they were given.
+    pub fn shares(&self) -> &[SecretShare] {
+        &self.shares
+    }
+
+    /// Returns the number of shares held, which counts towards the threshold.
+    pub fn weight(&self) -> usize {
+        self.shares.len()
+    }
+
+    /// Check every share against the dealer's commitment. Returns `Err(i)` with the
+    /// index of the first share which fails verification.
+    pub fn verify(&self, commitment: &VssCommitment) -> Result<(), usize> {
+        commitment.verify_shares(&self.shares)
+    }
+}
+
+impl TryFrom<WeightedShare> for MultiShare {
+    type Error = Error;
+
+    fn try_from(bundle: WeightedShare) -> Result<Self, Self::Error> {
+        MultiShare::new(bundle.shares)
+    }
+}
+
+/// Errors returned by [`recover_multi_share_secret`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MultiShareError {
+    /// The share at index `share` of the bundle at index `bundle` fails verification
+    /// against the commitment, so none of that bundle's shares were used.
+    InvalidShare { bundle: usize, share: usize },
+
+    /// The verified shares could not recover the secret.
+    Recovery(Error),
+}
+
+impl core::fmt::Display for MultiShareError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MultiShareError::InvalidShare { bundle, share } => {
+                write!(f, "share {} of bundle {} fails verification", share, bundle)
+            }
+            MultiShareError::Recovery(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MultiShareError {}
+
+/// Recover the secret committed to by `commitment` from the bundles of several
+/// shareholders, whose weights must sum to at least the commitment's threshold.
+///
+/// Every share of every bundle is verified before recovery, so a shareholder's shares
+/// are contributed all together or not at all. Returns an error if any share fails
+/// verification, or if recovery fails as in [`recover_secret`], such as when two
+/// bundles hold the same input.
+pub fn recover_multi_share_secret(
+    bundles: &[MultiShare],
+    commitment: &VssCommitment,
+) -> Result<MaybeScalar, MultiShareError> {
+    for (bundle, multi_share) in bundles.iter().enumerate() {
+        multi_share
+            .verify(commitment)
+            .map_err(|share| MultiShareError::InvalidShare { bundle, share })?;
+    }
+    let shares: Vec<SecretShare> = bundles
+        .iter()
+        .flat_map(|bundle| bundle.shares.iter().copied())
+        .collect();
+    recover_secret(&shares, commitment.threshold()).map_err(MultiShareError::Recovery)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hash_to_point_tagged, SecretSharingPolynomial};
+
+    #[test]
+    fn test_shareholder() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(10), MaybeScalar::from(20)]);
+        let q_points = vec![
+            hash_to_point_tagged(b"qudoku/test", b"one"),
+            hash_to_point_tagged(b"qudoku/test", b"two"),
+        ];
+        let share = f.issue_share(MaybeScalar::from(3));
+
+        let shareholder = Shareholder::new(share, f.commitment(), q_points.clone());
+        assert!(shareholder.verify());
+
+        let contribution = shareholder.derive_contribution();
+        assert_eq!(contribution.len(), 2);
+        for (point_share, &q) in contribution.iter().zip(&q_points) {
+            assert_eq!(*point_share, (q * &f).issue_share(MaybeScalar::from(3)));
+        }
+
+        let mut rotated = shareholder.clone();
+        let new_q = hash_to_point_tagged(b"qudoku/test", b"three");
+        assert!(rotated.rotate_q(q_points[1], new_q));
+        assert_eq!(rotated.q_points(), &[q_points[0], new_q]);
+        assert_eq!(rotated.share(), shareholder.share());
+        assert!(!rotated.rotate_q(q_points[1], new_q));
+
+        let forged = SecretShare::new(share.input, share.output + MaybeScalar::one());
+        assert!(!Shareholder::new(forged, f.commitment(), q_points).verify());
+    }
+
+    #[test]
+    fn test_multi_share() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(1234),
+            MaybeScalar::from(5),
+            MaybeScalar::from(6),
+        ]);
+        let commitment = f.commitment();
+        let share = |i: u128| f.issue_share(MaybeScalar::from(i));
+
+        let alice = MultiShare::new(vec![share(1), share(2)]).unwrap();
+        let bob = MultiShare::new(vec![share(3)]).unwrap();
+        assert_eq!(alice.weight(), 2);
+        assert_eq!(alice.verify(&commitment), Ok(()));
+        assert_eq!(
+            recover_multi_share_secret(&[alice.clone(), bob.clone()], &commitment),
+            Ok(MaybeScalar::from(1234))
+        );
+        assert_eq!(
+            recover_multi_share_secret(core::slice::from_ref(&alice), &commitment),
+            Err(MultiShareError::Recovery(Error::NotEnoughShares {
+                threshold: 3,
+                count: 2
+            }))
+        );
+
+        let mut forged = share(4);
+        forged.output += MaybeScalar::one();
+        let carol = MultiShare::new(vec![share(5), forged]).unwrap();
+        assert_eq!(carol.verify(&commitment), Err(1));
+        assert_eq!(
+            recover_multi_share_secret(&[bob.clone(), carol, alice.clone()], &commitment),
+            Err(MultiShareError::InvalidShare {
+                bundle: 1,
+                share: 1
+            })
+        );
+
+        assert_eq!(
+            recover_multi_share_secret(&[alice.clone(), bob.clone(), bob], &commitment),
+            Err(MultiShareError::Recovery(Error::DuplicateInput {
+                first: 2,
+                second: 3
+            }))
+        );
+
+        assert_eq!(MultiShare::new(vec![]), Err(Error::Empty));
+        assert_eq!(
+            MultiShare::new(vec![share(1), share(0)]),
+            Err(Error::ZeroInput { index: 1 })
+        );
+        assert_eq!(
+            MultiShare::new(vec![share(1), share(1)]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 1
+            })
+        );
+
+        let debug = format!("{:?}", alice);
+        assert!(debug.contains("inputs"));
+        assert!(!debug.contains("output"));
+    }
+}
@@ -1,5 +1,12 @@
-use crate::{sha256, Evaluation, LagrangePolynomial, Polynomial, StandardFormPolynomial};
-use secp::{MaybePoint, MaybeScalar};
+use crate::{
+    bech32::{self, Bech32Error},
+    check_distinct, check_duplicate_inputs, hkdf_sha256, interpolate_line, scalar_from_wide,
+    sha256, sha256_into, tagged_hash, try_interpolate, Coefficients, Error, Evaluation,
+    LagrangePolynomial, LimitExceeded, Limits, Polynomial, StandardFormPolynomial,
+};
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar, Point, G};
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
 /// Represents a secret share held by a shareholder.
 pub type SecretShare = Evaluation<MaybeScalar, MaybeScalar>;
@@ -10,11 +17,18 @@ pub type PointShare = Evaluation<MaybeScalar, MaybePoint>;
 
 /// Represents the secret-sharing polynomial available to the dealer in its
 /// original standard form, composed of a set of scalar coefficients.
-pub type SecretSharingPolynomial = StandardFormPolynomial<MaybeScalar>;
+///
+/// The coefficients are held in a `Vec` by default. Use an array, e.g.
+/// `SecretSharingPolynomial<[MaybeScalar; N]>`, to store a fixed number `N`
+/// of coefficients without heap allocation.
+pub type SecretSharingPolynomial<C = Vec<MaybeScalar>> = StandardFormPolynomial<MaybeScalar, C>;
 
 /// Represents the point-sharing polynomial available to the dealer in standard
 /// form, composed of a set of point coefficients.
-pub type PointSharingPolynomial = StandardFormPolynomial<MaybePoint>;
+///
+/// As with [`SecretSharingPolynomial`], the coefficients may be held in an
+/// array `[MaybePoint; N]` instead of a `Vec`.
+pub type PointSharingPolynomial<C = Vec<MaybePoint>> = StandardFormPolynomial<MaybePoint, C>;
 
 /// Represents a secret-sharing polynomial interpolated from a set of shares.
 pub type InterpolatedSecretPolynomial = LagrangePolynomial<MaybeScalar, MaybeScalar>;
@@ -23,8 +37,8 @@ pub type InterpolatedSecretPolynomial = LagrangePolynomial<MaybeScalar, MaybeSca
 pub type InterpolatedPointPolynomial = LagrangePolynomial<MaybeScalar, MaybePoint>;
 
 macro_rules! impl_issue_share {
-    ( $t:ty, $share:ty ) => {
-        impl $t {
+    ( [$($generics:tt)*] $t:ty, $share:ty ) => {
+        impl<$($generics)*> $t {
             /// Issue a share at the given input `x`.
             pub fn issue_share(&self, x: MaybeScalar) -> $share {
                 Evaluation {
@@ -32,26 +46,925 @@ macro_rules! impl_issue_share {
                     output: self.evaluate(x),
                 }
             }
+
+            /// Issue shares at each of the given inputs, in order. Work is shared
+            /// between the evaluations where the polynomial representation allows it,
+            /// as in [`Polynomial::evaluate_many`].
+            ///
+            /// Panics if `xs` contains duplicates, which would issue the same share twice.
+            pub fn issue_shares(&self, xs: &[MaybeScalar]) -> Vec<$share> {
+                self.try_issue_shares(xs)
+                    .expect("cannot issue shares at duplicate inputs")
+            }
+
+            /// Returns an iterator which lazily issues shares at the sequential inputs
+            /// `start`, `start + 1`, `start + 2`, and so on. Each share is evaluated only
+            /// when the iterator is advanced, so shares can be handed out as shareholders
+            /// arrive without holding every share in memory at once.
+            ///
+            /// The input zero is always skipped, since the share there is the secret
+            /// itself, so a `start` of zero begins at one.
+            ///
+            /// The iterator is unbounded; use [`Iterator::take`] to limit it.
+            pub fn iter_shares(&self, start: MaybeScalar) -> impl Iterator<Item = $share> + '_ {
+                let mut x = start;
+                core::iter::from_fn(move || {
+                    if x.is_zero() {
+                        x = MaybeScalar::one();
+                    }
+                    let share = self.issue_share(x);
+                    x += MaybeScalar::one();
+                    Some(share)
+                })
+            }
+
+            /// Issue shares like [`issue_shares`][Self::issue_shares], but return
+            /// [`Error::DuplicateInput`] if `xs` contains duplicates.
+            pub fn try_issue_shares(&self, xs: &[MaybeScalar]) -> Result<Vec<$share>, Error> {
+                check_distinct(xs)?;
+                Ok(xs
+                    .iter()
+                    .zip(self.evaluate_many(xs))
+                    .map(|(&x, y)| Evaluation::new(x, y))
+                    .collect())
+            }
         }
     };
+    ( $t:ty, $share:ty ) => {
+        impl_issue_share! { [] $t, $share }
+    };
 }
 
-impl_issue_share! { SecretSharingPolynomial, SecretShare }
-impl_issue_share! { PointSharingPolynomial, PointShare }
+impl_issue_share! { [C: Coefficients<MaybeScalar>] SecretSharingPolynomial<C>, SecretShare }
+impl_issue_share! { [C: Coefficients<MaybePoint>] PointSharingPolynomial<C>, PointShare }
 impl_issue_share! { InterpolatedSecretPolynomial, SecretShare }
 impl_issue_share! { InterpolatedPointPolynomial, PointShare }
+#[cfg(feature = "mlock")]
+impl_issue_share! { crate::LockedSecretPolynomial, SecretShare }
+
+impl SecretSharingPolynomial {
+    /// Sample a secret-sharing polynomial with the given `secret` as its constant
+    /// term and random higher-order coefficients, such that `threshold` shares are
+    /// needed to recover the secret.
+    ///
+    /// Panics if `threshold` is zero.
+    #[cfg(feature = "rand")]
+    pub fn random_with_secret<R: rand::RngCore + rand::CryptoRng>(
+        secret: MaybeScalar,
+        threshold: usize,
+        rng: &mut R,
+    ) -> Self {
+        assert_ne!(threshold, 0, "threshold must be at least 1");
+        let mut coefficients = Vec::with_capacity(threshold);
+        coefficients.push(secret);
+        coefficients.extend(crate::random_coefficients(rng, threshold - 1));
+        SecretSharingPolynomial::new(coefficients)
+    }
+
+    /// Split an existing secret key, such as a signing key, into a secret-sharing
+    /// polynomial with random higher-order coefficients, such that `threshold` shares
+    /// are needed to recover it. The [`group_pubkey`][Self::group_pubkey] of the
+    /// polynomial is the public key of `secret`.
+    ///
+    /// Panics if `threshold` is zero.
+    #[cfg(feature = "rand")]
+    pub fn from_secret<R: rand::RngCore + rand::CryptoRng>(
+        secret: secp::Scalar,
+        threshold: usize,
+        rng: &mut R,
+    ) -> Self {
+        SecretSharingPolynomial::random_with_secret(MaybeScalar::Valid(secret), threshold, rng)
+    }
+
+    /// Returns the group's public key `f(0) * G`.
+    pub fn group_pubkey(&self) -> MaybePoint {
+        self.evaluate(MaybeScalar::Zero) * G
+    }
+
+    /// Deterministically derive a secret-sharing polynomial with `threshold`
+    /// coefficients, including the secret, from a 32-byte seed. A dealer who backs up
+    /// the seed can regenerate the same polynomial and reissue lost shares.
+    ///
+    /// Each coefficient is derived with HKDF-SHA256 from the seed, using `context`,
+    /// the threshold and the coefficient's index as the info string, so that different
+    /// contexts or thresholds produce independent polynomials from the same seed.
+    ///
+    /// Panics if `threshold` is zero.
+    pub fn from_seed(seed: &[u8; 32], threshold: usize, context: &[u8]) -> Self {
+        assert_ne!(threshold, 0, "threshold must be at least 1");
+        let coefficients = (0..threshold as u32)
+            .map(|i| {
+                let mut info = Vec::with_capacity(context.len() + 8);
+                info.extend_from_slice(context);
+                info.extend_from_slice(&(threshold as u32).to_be_bytes());
+                info.extend_from_slice(&i.to_be_bytes());
+
+                let mut wide = [0u8; 64];
+                wide.copy_from_slice(&hkdf_sha256(seed, b"qudoku/polynomial/seed", &info, 64));
+                scalar_from_wide(&wide)
+            })
+            .collect();
+        SecretSharingPolynomial::new(coefficients)
+    }
+}
 
 macro_rules! impl_derive_secret {
-    ( $t:ty ) => {
-        impl $t {
+    ( [$($generics:tt)*] $t:ty, $evaluate:ident ) => {
+        impl<$($generics)*> $t {
             /// Derive a secret `c` by hashing the output point produced by
             /// evaluating the polynomial on `x`.
+            ///
+            /// The hash is not domain-separated. New applications should prefer
+            /// [`derive_secret_tagged`][Self::derive_secret_tagged].
+            #[deprecated(note = "use derive_secret_tagged")]
             pub fn derive_secret(&self, x: MaybeScalar) -> [u8; 32] {
-                sha256(&self.evaluate(x).serialize())
+                sha256(&self.$evaluate(x).serialize())
+            }
+
+            /// Derive a secret `c` by computing a [`tagged_hash`] of the output point
+            /// produced by evaluating the polynomial on `x`, under the given domain or
+            /// application `tag`. Different tags yield independent secrets.
+            pub fn derive_secret_tagged(&self, tag: &[u8], x: MaybeScalar) -> [u8; 32] {
+                tagged_hash(tag, &self.$evaluate(x).serialize())
+            }
+
+            /// Derive a secret `c` like [`derive_secret`][Self::derive_secret], but
+            /// write it into a caller-provided buffer instead of returning it.
+            pub fn derive_secret_into(&self, x: MaybeScalar, out: &mut [u8; 32]) {
+                sha256_into(&self.$evaluate(x).serialize(), out);
+            }
+
+            /// Derive a secret `c` like [`derive_secret`][Self::derive_secret], but
+            /// return it in a buffer which is wiped when dropped.
+            #[cfg(feature = "zeroize")]
+            pub fn derive_secret_zeroizing(&self, x: MaybeScalar) -> zeroize::Zeroizing<[u8; 32]> {
+                let mut out = zeroize::Zeroizing::new([0u8; 32]);
+                self.derive_secret_into(x, &mut out);
+                out
+            }
+
+            /// Check in constant time whether `candidate` is the secret `c` which
+            /// [`derive_secret`][Self::derive_secret] produces at `x`.
+            #[cfg(feature = "ct-eq")]
+            #[allow(deprecated)]
+            pub fn derived_secret_matches(&self, x: MaybeScalar, candidate: &[u8; 32]) -> Choice {
+                use subtle::ConstantTimeEq as _;
+                self.derive_secret(x)[..].ct_eq(&candidate[..])
+            }
+
+            /// Derive `out_len` bytes of secret key material with HKDF-SHA256, from the
+            /// output point produced by evaluating the polynomial on `x`.
+            ///
+            /// Different `label`s produce independent keys from the same point, so one
+            /// evaluation can yield e.g. separate encryption and MAC keys.
+            ///
+            /// Panics if `out_len` is greater than `255 * 32`.
+            pub fn derive_secret_labeled(
+                &self,
+                x: MaybeScalar,
+                label: &[u8],
+                out_len: usize,
+            ) -> Vec<u8> {
+                hkdf_sha256(
+                    &self.$evaluate(x).serialize(),
+                    b"qudoku/derive_secret",
+                    label,
+                    out_len,
+                )
             }
         }
     };
+    ( [$($generics:tt)*] $t:ty ) => {
+        impl_derive_secret! { [$($generics)*] $t, evaluate }
+    };
+    ( $t:ty, $evaluate:ident ) => {
+        impl_derive_secret! { [] $t, $evaluate }
+    };
+}
+
+impl_derive_secret! { [C: Coefficients<MaybePoint>] PointSharingPolynomial<C> }
+impl_derive_secret! { InterpolatedPointPolynomial, evaluate_fast }
+
+/// The tag under which [`derive_secret_tagged`][PointSharingPolynomial::derive_secret_tagged]
+/// derives encryption keys.
+#[cfg(feature = "aead")]
+const AEAD_TAG: &[u8] = b"qudoku/aead";
+
+#[cfg(feature = "aead")]
+macro_rules! impl_aead {
+    ( [$($generics:tt)*] $t:ty ) => {
+        impl<$($generics)*> $t {
+            /// Encrypt a plaintext with ChaCha20-Poly1305, under a key derived from the
+            /// secret [`derive_secret_tagged`][Self::derive_secret_tagged] produces at
+            /// `x` under the tag `qudoku/aead`.
+            ///
+            /// The nonce is derived from the key and plaintext and prepended to the
+            /// ciphertext. Encryption is deterministic: encrypting the same plaintext
+            /// twice under the same `x` produces the same ciphertext, which reveals to
+            /// an observer that the plaintexts are equal. Use a distinct `x` per message,
+            /// or include a unique value in the plaintext, if that matters.
+            pub fn encrypt(&self, x: MaybeScalar, plaintext: &[u8]) -> Vec<u8> {
+                crate::aead::encrypt_with_secret(&self.derive_secret_tagged(AEAD_TAG, x), plaintext)
+            }
+
+            /// Decrypt a ciphertext produced by [`encrypt`][Self::encrypt] with the
+            /// same `x`. Returns `None` if the ciphertext fails authentication.
+            ///
+            /// Since encryption is deterministic, equal ciphertexts always decrypt to
+            /// equal plaintexts; see [`encrypt`][Self::encrypt].
+            pub fn decrypt(&self, x: MaybeScalar, ciphertext: &[u8]) -> Option<Vec<u8>> {
+                crate::aead::decrypt_with_secret(&self.derive_secret_tagged(AEAD_TAG, x), ciphertext)
+            }
+        }
+    };
+    ( $t:ty ) => {
+        impl_aead! { [] $t }
+    };
+}
+
+#[cfg(feature = "aead")]
+impl_aead! { [C: Coefficients<MaybePoint>] PointSharingPolynomial<C> }
+#[cfg(feature = "aead")]
+impl_aead! { InterpolatedPointPolynomial }
+
+/// The secp256k1 curve order `n`, in big-endian byte order.
+const CURVE_ORDER: [u8; 32] = [
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+    0xBA, 0xAE, 0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B, 0xBF, 0xD2, 0x5E, 0x8C, 0xD0, 0x36, 0x41, 0x41,
+];
+
+/// Returns a [`Choice`] which is truthy if the given big-endian integer is
+/// strictly less than the curve order `n`, without branching on the input.
+fn ct_less_than_curve_order(bytes: &[u8; 32]) -> Choice {
+    // Compute `bytes - n` from the least significant byte upwards,
+    // and check whether the subtraction underflows.
+    let mut borrow: u16 = 0;
+    for i in (0..32).rev() {
+        let diff = (bytes[i] as u16)
+            .wrapping_sub(CURVE_ORDER[i] as u16)
+            .wrapping_sub(borrow);
+        borrow = (diff >> 8) & 1;
+    }
+    Choice::from(borrow as u8)
+}
+
+/// Parses a scalar from 32 big-endian bytes. The range check and modular reduction
+/// are performed in constant time.
+fn maybe_scalar_from_bytes_ct(bytes: &[u8; 32]) -> CtOption<MaybeScalar> {
+    let is_canonical = ct_less_than_curve_order(bytes);
+
+    // Canonical encodings are unaffected by reduction.
+    let scalar = MaybeScalar::reduce_from(bytes);
+    CtOption::new(scalar, is_canonical)
+}
+
+/// Returned when parsing a share from bytes which do not encode a valid share.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InvalidShareBytes {
+    /// The share input `x` is zero or not a canonically encoded scalar.
+    Input,
+
+    /// The share output `y` is not a canonically encoded scalar or a valid point.
+    Output,
+}
+
+impl core::fmt::Display for InvalidShareBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InvalidShareBytes::Input => write!(f, "invalid share input bytes"),
+            InvalidShareBytes::Output => write!(f, "invalid share output bytes"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidShareBytes {}
+
+/// The bech32m human-readable part used to encode a [`SecretShare`].
+pub const SECRET_SHARE_HRP: &str = "qudoku";
+
+/// The bech32m human-readable part used to encode a [`PointShare`].
+pub const POINT_SHARE_HRP: &str = "qudokupt";
+
+/// Returned when parsing a share from an invalid bech32m string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidShareString {
+    /// The string is not valid bech32m.
+    Bech32(Bech32Error),
+
+    /// The human-readable part does not match the expected share type.
+    WrongHrp,
+
+    /// The encoded data has the wrong length for the share type.
+    WrongLength,
+
+    /// The encoded data is not a valid share.
+    Bytes(InvalidShareBytes),
+
+    /// The string exceeds the default [`Limits`].
+    LimitExceeded(LimitExceeded),
+}
+
+impl core::fmt::Display for InvalidShareString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InvalidShareString::Bech32(e) => e.fmt(f),
+            InvalidShareString::WrongHrp => write!(f, "share string has the wrong prefix"),
+            InvalidShareString::WrongLength => write!(f, "share string has the wrong length"),
+            InvalidShareString::Bytes(e) => e.fmt(f),
+            InvalidShareString::LimitExceeded(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidShareString {}
+
+/// Decode a bech32m share string with the given HRP into a fixed-size buffer.
+fn decode_share_string<const N: usize>(
+    s: &str,
+    expected_hrp: &str,
+) -> Result<[u8; N], InvalidShareString> {
+    Limits::default()
+        .check_message_size(s.len())
+        .map_err(InvalidShareString::LimitExceeded)?;
+    let (hrp, data) = bech32::decode(s).map_err(InvalidShareString::Bech32)?;
+    if hrp != expected_hrp {
+        return Err(InvalidShareString::WrongHrp);
+    }
+    data.try_into().map_err(|_| InvalidShareString::WrongLength)
+}
+
+impl core::fmt::Display for SecretShare {
+    /// Formats the share as a bech32m string with the prefix [`SECRET_SHARE_HRP`].
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&bech32::encode(SECRET_SHARE_HRP, &self.to_bytes()))
+    }
+}
+
+impl core::str::FromStr for SecretShare {
+    type Err = InvalidShareString;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = decode_share_string::<64>(s, SECRET_SHARE_HRP)?;
+        SecretShare::from_bytes(&bytes).map_err(InvalidShareString::Bytes)
+    }
+}
+
+impl core::fmt::Display for PointShare {
+    /// Formats the share as a bech32m string with the prefix [`POINT_SHARE_HRP`].
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&bech32::encode(POINT_SHARE_HRP, &self.to_bytes()))
+    }
+}
+
+impl core::str::FromStr for PointShare {
+    type Err = InvalidShareString;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = decode_share_string::<65>(s, POINT_SHARE_HRP)?;
+        PointShare::from_bytes(&bytes).map_err(InvalidShareString::Bytes)
+    }
+}
+
+/// Parse a share input, rejecting zero since a share at `x = 0` is the secret itself.
+fn share_input_from_slice(bytes: &[u8]) -> Result<MaybeScalar, InvalidShareBytes> {
+    match MaybeScalar::from_slice(bytes) {
+        Ok(MaybeScalar::Valid(x)) => Ok(MaybeScalar::Valid(x)),
+        _ => Err(InvalidShareBytes::Input),
+    }
+}
+
+impl SecretShare {
+    /// Convert the secret share into a point share by multiplying its output with
+    /// a fixed point `Q`. The resulting share has the same input.
+    pub fn to_point_share(&self, q: Point) -> PointShare {
+        Evaluation {
+            input: self.input,
+            output: self.output * q,
+        }
+    }
+
+    /// Compute the verification share for this secret share, which is the point
+    /// share for the secp256k1 generator point `G`. Verification shares can be
+    /// published without revealing the secret share.
+    pub fn verification_share(&self) -> PointShare {
+        Evaluation {
+            input: self.input,
+            output: self.output * G,
+        }
+    }
+
+    /// Convert a set of secret shares into point shares for a fixed point `Q`.
+    pub fn to_point_shares(shares: &[SecretShare], q: Point) -> Vec<PointShare> {
+        shares.iter().map(|share| share.to_point_share(q)).collect()
+    }
+
+    /// Compute the verification shares for a set of secret shares.
+    pub fn verification_shares(shares: &[SecretShare]) -> Vec<PointShare> {
+        shares.iter().map(SecretShare::verification_share).collect()
+    }
+
+    /// Serialize the secret share into a caller-provided 64-byte buffer, as `x || y`
+    /// where `x` is the share input and `y` is the share output, both as big-endian
+    /// scalars.
+    pub fn serialize_into(&self, out: &mut [u8; 64]) {
+        out[..32].copy_from_slice(&self.input.serialize());
+        out[32..].copy_from_slice(&self.output.serialize());
+    }
+
+    /// Serialize the secret share as 64 bytes `x || y`. See
+    /// [`serialize_into`][Self::serialize_into].
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        self.serialize_into(&mut out);
+        out
+    }
+
+    /// Parse a secret share from the 64-byte encoding produced by
+    /// [`to_bytes`][Self::to_bytes].
+    ///
+    /// Returns an error if either scalar is not canonically encoded, or if the
+    /// share input is zero.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<SecretShare, InvalidShareBytes> {
+        let input = share_input_from_slice(&bytes[..32])?;
+        let output =
+            MaybeScalar::from_slice(&bytes[32..]).map_err(|_| InvalidShareBytes::Output)?;
+        Ok(Evaluation::new(input, output))
+    }
+
+    /// Parse a secret share from its 64-byte encoding `x || y`, where `x` is the
+    /// share input and `y` is the share output, both as big-endian scalars.
+    ///
+    /// Unlike [`MaybeScalar::from_slice`], the validity checks on each scalar do
+    /// not branch on the bytes, so the time taken to parse a share does not depend
+    /// on the secret share value, except that a zero-valued `y` may still be
+    /// detected when it is converted into [`MaybeScalar::Zero`].
+    ///
+    /// Returns the same errors as [`from_bytes`][Self::from_bytes]: if either scalar is
+    /// not canonically encoded (i.e. is greater than or equal to the curve order), or
+    /// if the share input is zero. Only whether each half is valid is branched on.
+    pub fn from_bytes_ct(bytes: &[u8; 64]) -> Result<SecretShare, InvalidShareBytes> {
+        let mut x_bytes = [0u8; 32];
+        let mut y_bytes = [0u8; 32];
+        x_bytes.copy_from_slice(&bytes[..32]);
+        y_bytes.copy_from_slice(&bytes[32..]);
+
+        let input = maybe_scalar_from_bytes_ct(&x_bytes);
+        let output = maybe_scalar_from_bytes_ct(&y_bytes);
+        let input_is_zero = x_bytes[..].ct_eq(&[0u8; 32][..]);
+
+        if !bool::from(input.is_some() & !input_is_zero) {
+            return Err(InvalidShareBytes::Input);
+        }
+        if !bool::from(output.is_some()) {
+            return Err(InvalidShareBytes::Output);
+        }
+        Ok(Evaluation::new(input.unwrap(), output.unwrap()))
+    }
+}
+
+impl PointShare {
+    /// Serialize the point share into a caller-provided 65-byte buffer, as `x || Y`
+    /// where `x` is the share input as a big-endian scalar and `Y` is the compressed
+    /// share output point. The point at infinity is encoded as 33 zero bytes.
+    pub fn serialize_into(&self, out: &mut [u8; 65]) {
+        out[..32].copy_from_slice(&self.input.serialize());
+        out[32..].copy_from_slice(&self.output.serialize());
+    }
+
+    /// Serialize the point share as 65 bytes `x || Y`. See
+    /// [`serialize_into`][Self::serialize_into].
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut out = [0u8; 65];
+        self.serialize_into(&mut out);
+        out
+    }
+
+    /// Parse a point share from the 65-byte encoding produced by
+    /// [`to_bytes`][Self::to_bytes].
+    ///
+    /// Returns an error if the input is zero or not canonically encoded, or if
+    /// the output is neither a valid compressed point nor 33 zero bytes.
+    pub fn from_bytes(bytes: &[u8; 65]) -> Result<PointShare, InvalidShareBytes> {
+        let input = share_input_from_slice(&bytes[..32])?;
+        let output = MaybePoint::from_slice(&bytes[32..]).map_err(|_| InvalidShareBytes::Output)?;
+        Ok(Evaluation::new(input, output))
+    }
+}
+
+/// Recover the primary secret `f(0)` from a set of secret shares, of which at least
+/// `threshold` are required.
+///
+/// Returns an error if fewer than `threshold` shares are given, if `threshold` is zero,
+/// or if any two shares have the same input or any share has a zero input. Only the
+/// first `threshold` shares are used for interpolation. A threshold of two uses the
+/// closed-form [`interpolate_line`].
+pub fn recover_secret(shares: &[SecretShare], threshold: usize) -> Result<MaybeScalar, Error> {
+    if shares.len() < threshold {
+        return Err(Error::NotEnoughShares {
+            threshold,
+            count: shares.len(),
+        });
+    }
+    if let Some(index) = shares.iter().position(|share| share.input.is_zero()) {
+        return Err(Error::ZeroInput { index });
+    }
+    check_duplicate_inputs(shares)?;
+    if let [a, b] = &shares[..threshold] {
+        return Ok(interpolate_line(a, b, MaybeScalar::Zero));
+    }
+    try_interpolate(&shares[..threshold], MaybeScalar::Zero)
 }
 
-impl_derive_secret! { PointSharingPolynomial }
-impl_derive_secret! { InterpolatedPointPolynomial }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recover_secret() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(1234),
+            MaybeScalar::from(5),
+            MaybeScalar::from(6),
+        ]);
+        let shares: Vec<SecretShare> = (1..=4)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        assert_eq!(recover_secret(&shares, 3), Ok(MaybeScalar::from(1234)));
+        assert_eq!(recover_secret(&shares[1..], 3), Ok(MaybeScalar::from(1234)));
+        assert_eq!(
+            recover_secret(&shares[..2], 3),
+            Err(Error::NotEnoughShares {
+                threshold: 3,
+                count: 2
+            })
+        );
+        assert_eq!(
+            recover_secret(&[shares[0], shares[1], shares[0]], 3),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 2
+            })
+        );
+        assert_eq!(recover_secret(&shares, 0), Err(Error::Empty));
+    }
+
+    #[test]
+    fn test_issue_shares() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(1234),
+            MaybeScalar::from(5),
+            MaybeScalar::from(6),
+        ]);
+        let xs: Vec<MaybeScalar> = (1..=5).map(MaybeScalar::from).collect();
+        let shares = f.issue_shares(&xs);
+        for (share, &x) in shares.iter().zip(&xs) {
+            assert_eq!(*share, f.issue_share(x));
+        }
+
+        let interpolated = InterpolatedSecretPolynomial::new(shares[..3].to_vec());
+        assert_eq!(interpolated.issue_shares(&xs), shares);
+        assert_eq!(
+            (f.clone() * G).issue_shares(&xs[..2]),
+            vec![shares[0] * G, shares[1] * G]
+        );
+
+        assert_eq!(
+            f.try_issue_shares(&[xs[0], xs[1], xs[0]]),
+            Err(Error::DuplicateInput {
+                first: 0,
+                second: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_iter_shares() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(42), MaybeScalar::from(7)]);
+        let xs: Vec<MaybeScalar> = (3..8).map(MaybeScalar::from).collect();
+        let shares: Vec<SecretShare> = f.iter_shares(MaybeScalar::from(3)).take(5).collect();
+        assert_eq!(shares, f.issue_shares(&xs));
+
+        // The secret at zero is never issued, including after wrapping around.
+        let from_zero: Vec<SecretShare> = f.iter_shares(MaybeScalar::Zero).take(2).collect();
+        assert_eq!(from_zero[0].input, MaybeScalar::one());
+        let wrapped: Vec<SecretShare> = f.iter_shares(MaybeScalar::max()).take(2).collect();
+        assert_eq!(wrapped[1].input, MaybeScalar::one());
+
+        let commitment = f * G;
+        let mut iter = commitment.iter_shares(MaybeScalar::one());
+        assert_eq!(
+            iter.next().map(|share| share.input),
+            Some(MaybeScalar::from(1))
+        );
+        assert_eq!(
+            iter.next().map(|share| share.input),
+            Some(MaybeScalar::from(2))
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_with_secret() {
+        let secret = MaybeScalar::from(0xC0FFEE);
+        let f = SecretSharingPolynomial::random_with_secret(secret, 3, &mut rand::rngs::OsRng);
+        assert_eq!(f.coefficients.len(), 3);
+        assert_eq!(f.coefficients[0], secret);
+
+        let shares: Vec<SecretShare> = f.iter_shares(MaybeScalar::one()).take(3).collect();
+        assert_eq!(recover_secret(&shares, 3), Ok(secret));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_from_secret() {
+        let secret = secp::Scalar::reduce_from(&[0x42; 32]);
+        let f = SecretSharingPolynomial::from_secret(secret, 3, &mut rand::rngs::OsRng);
+        assert_eq!(f.group_pubkey(), MaybePoint::Valid(secret.base_point_mul()));
+        assert_eq!(f.group_pubkey(), f.commitment().public_key());
+
+        let shares = f.issue_shares(&[
+            MaybeScalar::from(4),
+            MaybeScalar::from(9),
+            MaybeScalar::from(2),
+        ]);
+        assert_eq!(recover_secret(&shares, 3), Ok(MaybeScalar::Valid(secret)));
+    }
+
+    #[test]
+    fn test_from_seed() {
+        let seed = [7u8; 32];
+        let f = SecretSharingPolynomial::from_seed(&seed, 4, b"treasury");
+        assert_eq!(f.coefficients.len(), 4);
+        assert_eq!(f, SecretSharingPolynomial::from_seed(&seed, 4, b"treasury"));
+        assert_ne!(f, SecretSharingPolynomial::from_seed(&seed, 4, b"payroll"));
+        assert_ne!(
+            f,
+            SecretSharingPolynomial::from_seed(&[8u8; 32], 4, b"treasury")
+        );
+
+        // Shares of polynomials with different thresholds must not be combinable.
+        let g = SecretSharingPolynomial::from_seed(&seed, 3, b"treasury");
+        assert_ne!(g.coefficients[0], f.coefficients[0]);
+    }
+
+    #[test]
+    fn test_secret_share_from_bytes_ct() {
+        let mut bytes = [0u8; 64];
+        bytes[31] = 3;
+        bytes[32..].copy_from_slice(&CURVE_ORDER);
+        bytes[63] -= 1;
+
+        let share = SecretShare::from_bytes_ct(&bytes).unwrap();
+        assert_eq!(share.input, MaybeScalar::from(3));
+        assert_eq!(share.output, -MaybeScalar::one());
+
+        // y = n
+        bytes[63] += 1;
+        assert_eq!(
+            SecretShare::from_bytes_ct(&bytes),
+            Err(InvalidShareBytes::Output)
+        );
+        assert_eq!(
+            SecretShare::from_bytes(&bytes),
+            Err(InvalidShareBytes::Output)
+        );
+
+        // y = 2^256 - 1
+        bytes[32..].copy_from_slice(&[0xFF; 32]);
+        assert_eq!(
+            SecretShare::from_bytes_ct(&bytes),
+            Err(InvalidShareBytes::Output)
+        );
+
+        // x = n
+        bytes[..32].copy_from_slice(&CURVE_ORDER);
+        bytes[32..].copy_from_slice(&[0; 32]);
+        assert_eq!(
+            SecretShare::from_bytes_ct(&bytes),
+            Err(InvalidShareBytes::Input)
+        );
+        assert_eq!(
+            SecretShare::from_bytes(&bytes),
+            Err(InvalidShareBytes::Input)
+        );
+
+        // x = 0 is rejected, as by from_bytes.
+        assert_eq!(
+            SecretShare::from_bytes_ct(&[0; 64]),
+            Err(InvalidShareBytes::Input)
+        );
+        assert_eq!(
+            SecretShare::from_bytes(&[0; 64]),
+            Err(InvalidShareBytes::Input)
+        );
+
+        // x = 1, y = 0
+        let mut bytes = [0u8; 64];
+        bytes[31] = 1;
+        let share = SecretShare::from_bytes_ct(&bytes).unwrap();
+        assert_eq!(share.input, MaybeScalar::one());
+        assert_eq!(share.output, MaybeScalar::Zero);
+        assert_eq!(SecretShare::from_bytes(&bytes), Ok(share));
+    }
+
+    #[test]
+    fn test_serialize_into() {
+        let share = SecretShare::new(MaybeScalar::from(7), MaybeScalar::from(0xABCD));
+        let mut buf = [0xFF; 64];
+        share.serialize_into(&mut buf);
+        assert_eq!(buf[31], 7);
+        assert_eq!(&buf[60..], &[0, 0, 0xAB, 0xCD]);
+        assert_eq!(SecretShare::from_bytes_ct(&buf), Ok(share));
+
+        let point_share = share.verification_share();
+        let mut buf = [0xFF; 65];
+        point_share.serialize_into(&mut buf);
+        assert_eq!(&buf[..32], &share.input.serialize());
+        assert_eq!(&buf[32..], &point_share.output.serialize());
+    }
+
+    #[test]
+    fn test_share_bytes() {
+        let share = SecretShare::new(MaybeScalar::from(9), MaybeScalar::from(0x1234));
+        let bytes = share.to_bytes();
+        assert_eq!(SecretShare::from_bytes(&bytes), Ok(share));
+
+        let mut zero_input = bytes;
+        zero_input[31] = 0;
+        assert_eq!(
+            SecretShare::from_bytes(&zero_input),
+            Err(InvalidShareBytes::Input)
+        );
+
+        let mut overflow = bytes;
+        overflow[32..].copy_from_slice(&CURVE_ORDER);
+        assert_eq!(
+            SecretShare::from_bytes(&overflow),
+            Err(InvalidShareBytes::Output)
+        );
+
+        let point_share = share.verification_share();
+        let bytes = point_share.to_bytes();
+        assert_eq!(PointShare::from_bytes(&bytes), Ok(point_share));
+
+        let infinity = PointShare::new(MaybeScalar::from(9), MaybePoint::Infinity);
+        assert_eq!(PointShare::from_bytes(&infinity.to_bytes()), Ok(infinity));
+
+        // x coordinate larger than the field size.
+        let mut bad_point = bytes;
+        bad_point[33..].copy_from_slice(&[0xFF; 32]);
+        assert_eq!(
+            PointShare::from_bytes(&bad_point),
+            Err(InvalidShareBytes::Output)
+        );
+    }
+
+    #[test]
+    fn test_share_strings() {
+        let share = SecretShare::new(MaybeScalar::from(9), MaybeScalar::from(0x1234));
+        let s = share.to_string();
+        assert!(s.starts_with("qudoku1"));
+        assert_eq!(s.parse::<SecretShare>(), Ok(share));
+        assert_eq!(s.to_uppercase().parse::<SecretShare>(), Ok(share));
+
+        let point_share = share.verification_share();
+        let s = point_share.to_string();
+        assert!(s.starts_with("qudokupt1"));
+        assert_eq!(s.parse::<PointShare>(), Ok(point_share));
+        assert_eq!(s.parse::<SecretShare>(), Err(InvalidShareString::WrongHrp));
+
+        // A single transcription error is detected.
+        let mut typo = share.to_string().into_bytes();
+        typo[20] = if typo[20] == b'q' { b'p' } else { b'q' };
+        assert_eq!(
+            String::from_utf8(typo).unwrap().parse::<SecretShare>(),
+            Err(InvalidShareString::Bech32(Bech32Error::InvalidChecksum))
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_derive_secret_into() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(6)]);
+        let z = secp::Point::generator() * f;
+        let mut out = [0; 32];
+        z.derive_secret_into(MaybeScalar::from(3), &mut out);
+        assert_eq!(out, z.derive_secret(MaybeScalar::from(3)));
+
+        let tagged = z.derive_secret_tagged(b"app", MaybeScalar::from(3));
+        assert_ne!(tagged, out);
+        assert_ne!(
+            tagged,
+            z.derive_secret_tagged(b"other", MaybeScalar::from(3))
+        );
+    }
+
+    #[test]
+    fn test_derive_secret_labeled() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(6)]);
+        let z = secp::Point::generator() * f;
+        let x = MaybeScalar::from(3);
+
+        let encryption = z.derive_secret_labeled(x, b"encryption", 32);
+        let mac = z.derive_secret_labeled(x, b"mac", 64);
+        assert_eq!(encryption.len(), 32);
+        assert_eq!(mac.len(), 64);
+        assert_ne!(encryption[..], mac[..32]);
+        assert_eq!(z.derive_secret_labeled(x, b"mac", 16), mac[..16]);
+        assert_ne!(
+            z.derive_secret_labeled(MaybeScalar::from(4), b"mac", 64),
+            mac
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "aead")]
+    fn test_aead() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(6)]);
+        let z = secp::Point::generator() * f;
+        let x = MaybeScalar::from(3);
+
+        let ciphertext = z.encrypt(x, b"hello world");
+        assert_eq!(
+            z.decrypt(x, &ciphertext).as_deref(),
+            Some(&b"hello world"[..])
+        );
+        assert_eq!(z.decrypt(MaybeScalar::from(4), &ciphertext), None);
+
+        let shares = vec![
+            z.issue_share(MaybeScalar::from(1)),
+            z.issue_share(MaybeScalar::from(2)),
+        ];
+        let interpolated = InterpolatedPointPolynomial::new(shares);
+        assert_eq!(
+            interpolated.decrypt(x, &ciphertext).as_deref(),
+            Some(&b"hello world"[..])
+        );
+
+        let fixed: PointSharingPolynomial<[MaybePoint; 2]> = z.clone().try_into().unwrap();
+        assert_eq!(fixed.encrypt(x, b"hello world"), ciphertext);
+
+        let mut tampered = ciphertext.clone();
+        tampered[20] ^= 1;
+        assert_eq!(z.decrypt(x, &tampered), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(6)]);
+        let share = f.issue_share(MaybeScalar::from(2));
+        let point_share = share.verification_share();
+
+        let json = serde_json::to_string(&share).unwrap();
+        assert_eq!(serde_json::from_str::<SecretShare>(&json).unwrap(), share);
+
+        let json = serde_json::to_string(&point_share).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PointShare>(&json).unwrap(),
+            point_share
+        );
+
+        let json = serde_json::to_string(&f).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SecretSharingPolynomial>(&json).unwrap(),
+            f
+        );
+
+        let interpolated =
+            InterpolatedSecretPolynomial::new(vec![share, f.issue_share(MaybeScalar::from(3))]);
+        let json = serde_json::to_string(&interpolated).unwrap();
+        let decoded: InterpolatedSecretPolynomial = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.evaluations, interpolated.evaluations);
+    }
+
+    #[test]
+    fn test_secret_share_to_point_share() {
+        let shares = [
+            SecretShare::new(MaybeScalar::from(1), MaybeScalar::from(300)),
+            SecretShare::new(MaybeScalar::from(2), MaybeScalar::from(400)),
+        ];
+        let q = crate::hash_to_point_tagged(b"qudoku/test", b"test");
+
+        let point_share = shares[0].to_point_share(q);
+        assert_eq!(point_share.input, MaybeScalar::from(1));
+        assert_eq!(point_share.output, MaybeScalar::from(300) * q);
+        assert_eq!(point_share, shares[0] * q);
+
+        let verification_share = shares[1].verification_share();
+        assert_eq!(verification_share.input, MaybeScalar::from(2));
+        assert_eq!(verification_share.output, MaybeScalar::from(400) * G);
+
+        assert_eq!(
+            SecretShare::to_point_shares(&shares, q),
+            vec![shares[0] * q, shares[1] * q]
+        );
+        assert_eq!(
+            SecretShare::verification_shares(&shares),
+            vec![shares[0] * G, shares[1] * G]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{tagged_hash, Evaluation, PointShare, SecretShare};
+use secp::{errors::ZeroScalarError, MaybePoint, MaybeScalar, Point, Scalar, G};
+
+/// A device which holds a shareholder's [`SecretShare`] and performs operations
+/// with it on the shareholder's behalf, such as a hardware wallet.
+///
+/// The crate drives the protocol, and calls into the device only for the
+/// operations which need the secret share output `s_i`. The share input `x_i`
+/// is public information and is exposed directly.
+pub trait ShareSigner {
+    /// The error type returned by the device.
+    type Error;
+
+    /// Returns the input `x_i` of the secret share held by the device.
+    fn share_input(&self) -> MaybeScalar;
+
+    /// Computes the scalar-point multiplication `s_i * Q` using the secret share output `s_i`.
+    fn mul_point(&self, q: Point) -> Result<MaybePoint, Self::Error>;
+
+    /// Signs a 32-byte message with a [BIP-340] Schnorr signature, using the secret
+    /// share output `s_i` as the private key. The corresponding public key is
+    /// `s_i * G`.
+    ///
+    /// [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+    fn sign_schnorr(&self, message: &[u8; 32]) -> Result<[u8; 64], Self::Error>;
+}
+
+/// Ask a [`ShareSigner`] to produce a point share for the given `Q` point.
+pub fn point_share_from_signer<S: ShareSigner>(
+    signer: &S,
+    q: Point,
+) -> Result<PointShare, S::Error> {
+    Ok(Evaluation {
+        input: signer.share_input(),
+        output: signer.mul_point(q)?,
+    })
+}
+
+/// A software implementation of [`ShareSigner`], where the secret share is held in memory.
+impl ShareSigner for SecretShare {
+    type Error = ZeroScalarError;
+
+    fn share_input(&self) -> MaybeScalar {
+        self.input
+    }
+
+    fn mul_point(&self, q: Point) -> Result<MaybePoint, Self::Error> {
+        Ok(self.output * q)
+    }
+
+    fn sign_schnorr(&self, message: &[u8; 32]) -> Result<[u8; 64], Self::Error> {
+        sign_schnorr(self.output.not_zero()?, message)
+    }
+}
+
+/// Sign a message with [BIP-340], deterministically deriving the nonce from the
+/// secret key and message.
+///
+/// [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+pub(crate) fn sign_schnorr(
+    seckey: Scalar,
+    message: &[u8; 32],
+) -> Result<[u8; 64], ZeroScalarError> {
+    let pubkey = seckey * G;
+    let d = seckey.negate_if(pubkey.parity());
+
+    // Auxiliary randomness is all zeros, so the nonce depends only on the key and message.
+    let aux_hash = tagged_hash(b"BIP0340/aux", &[0; 32]);
+    let mut t = d.serialize();
+    for (t_byte, aux_byte) in t.iter_mut().zip(aux_hash) {
+        *t_byte ^= aux_byte;
+    }
+
+    let mut nonce_preimage = [0u8; 96];
+    nonce_preimage[..32].copy_from_slice(&t);
+    nonce_preimage[32..64].copy_from_slice(&pubkey.serialize_xonly());
+    nonce_preimage[64..].copy_from_slice(message);
+    let k = MaybeScalar::reduce_from(&tagged_hash(b"BIP0340/nonce", &nonce_preimage)).not_zero()?;
+
+    let nonce_point = k * G;
+    let k = k.negate_if(nonce_point.parity());
+
+    let e = schnorr_challenge(&nonce_point, &pubkey, message);
+    let s = k + e * d;
+
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&nonce_point.serialize_xonly());
+    signature[32..].copy_from_slice(&s.serialize());
+    Ok(signature)
+}
+
+/// Compute the BIP-340 challenge `e = H(R || P || m)`.
+pub(crate) fn schnorr_challenge(
+    nonce_point: &Point,
+    pubkey: &Point,
+    message: &[u8; 32],
+) -> MaybeScalar {
+    let mut preimage = [0u8; 96];
+    preimage[..32].copy_from_slice(&nonce_point.serialize_xonly());
+    preimage[32..64].copy_from_slice(&pubkey.serialize_xonly());
+    preimage[64..].copy_from_slice(message);
+    MaybeScalar::reduce_from(&tagged_hash(b"BIP0340/challenge", &preimage))
+}
+
+/// Verify a [BIP-340] Schnorr signature on a message, under the given public key.
+/// Only the X coordinate of the public key is used.
+///
+/// [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+pub fn verify_schnorr(pubkey: Point, message: &[u8; 32], signature: &[u8; 64]) -> bool {
+    let pubkey = pubkey.to_even_y();
+
+    let mut r_bytes = [0u8; 32];
+    r_bytes.copy_from_slice(&signature[..32]);
+    let nonce_point = match Point::lift_x(&r_bytes) {
+        Ok(point) => point,
+        Err(_) => return false,
+    };
+    let s = match MaybeScalar::from_slice(&signature[32..]) {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+
+    let e = schnorr_challenge(&nonce_point, &pubkey, message);
+    match s * G - e * pubkey {
+        MaybePoint::Valid(point) => point == nonce_point,
+        MaybePoint::Infinity => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_share_signer() {
+        let share = SecretShare::new(MaybeScalar::from(3), MaybeScalar::from(0xC0FFEE));
+        let q = Point::generator() * Scalar::try_from(12345).unwrap();
+
+        let point_share = point_share_from_signer(&share, q).unwrap();
+        assert_eq!(point_share, share.to_point_share(q));
+
+        let pubkey = (share.output * G).unwrap();
+        let message = [0xAB; 32];
+        let signature = share.sign_schnorr(&message).unwrap();
+        assert!(verify_schnorr(pubkey, &message, &signature));
+        assert!(verify_schnorr(-pubkey, &message, &signature));
+        assert!(!verify_schnorr(pubkey, &[0xAC; 32], &signature));
+
+        let mut bad_signature = signature;
+        bad_signature[63] ^= 1;
+        assert!(!verify_schnorr(pubkey, &message, &bad_signature));
+
+        let zero_share = SecretShare::new(MaybeScalar::from(3), MaybeScalar::Zero);
+        assert_eq!(zero_share.sign_schnorr(&message), Err(ZeroScalarError));
+    }
+
+    #[test]
+    fn test_sign_schnorr_bip340_vector() {
+        // Test vector 0 from BIP-340, which uses all-zero auxiliary randomness.
+        let share = SecretShare::new(MaybeScalar::one(), MaybeScalar::from(3));
+        let signature = share.sign_schnorr(&[0; 32]).unwrap();
+        assert_eq!(
+            hex::encode(signature),
+            "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca8215\
+             25f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0"
+        );
+    }
+}
@@ -0,0 +1,503 @@
+//! Threshold [BIP-340] Schnorr signing with shares of a group key `f(0) * G`.
+//!
+//! The protocol follows [FROST](https://eprint.iacr.org/2020/852), with two nonces per
+//! signer bound together by a per-signer binding factor, which makes it safe to run
+//! many signing sessions concurrently.
+//!
+//! 1. Each signer samples fresh [`SigningNonces`], or derives them from their share
+//!    and a unique session ID with [`SigningNonces::derive`], and publishes their
+//!    [`NonceCommitments`].
+//! 2. Once a threshold of commitments has been collected, every signer constructs the
+//!    same [`SigningPackage`] from the group key, the message and the commitments, and
+//!    produces a [`PartialSignature`] with [`SigningPackage::sign`].
+//! 3. A combiner checks each partial signature against its signer's verification share
+//!    with [`SigningPackage::verify_partial`], and sums them with
+//!    [`SigningPackage::aggregate`] into an ordinary BIP-340 signature.
+//!
+//! Nonces must never be reused across signing sessions. [`SigningPackage::new`] rejects
+//! any nonce commitment which appears twice in the same session.
+//!
+//! [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+use crate::{
+    lagrange_coefficients_at_zero, schnorr_challenge, tagged_hash, verify_schnorr, PointShare,
+    SecretShare,
+};
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar, Point, Scalar, G};
+
+/// Errors which can occur while signing. Where applicable, each variant identifies
+/// the input of the signer at fault.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SigningError {
+    /// Nonce commitments were received more than once from the same signer.
+    DuplicateSigner(MaybeScalar),
+
+    /// A signer is not among the signers of the [`SigningPackage`].
+    UnknownSigner(MaybeScalar),
+
+    /// A signer's nonces do not match their published commitments.
+    MismatchedNonces(MaybeScalar),
+
+    /// No partial signature was received from a signer.
+    Missing(MaybeScalar),
+
+    /// The aggregated signature is invalid, because some partial signature was invalid.
+    InvalidSignature,
+
+    /// The aggregated nonce is the point at infinity. This happens with negligible
+    /// probability unless commitments were chosen maliciously.
+    InvalidNonce,
+
+    /// A signer's input is zero, which is not a valid share input.
+    ZeroInput,
+
+    /// A signer's nonce commitment repeats one already in the package, either their
+    /// own or another signer's, so a nonce has been reused.
+    ReusedNonce(MaybeScalar),
+}
+
+impl core::fmt::Display for SigningError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SigningError::DuplicateSigner(x) => write!(f, "duplicate signer {:?}", x),
+            SigningError::UnknownSigner(x) => write!(f, "unknown signer {:?}", x),
+            SigningError::MismatchedNonces(x) => write!(f, "mismatched nonces from {:?}", x),
+            SigningError::Missing(x) => write!(f, "missing partial signature from {:?}", x),
+            SigningError::InvalidSignature => write!(f, "invalid aggregate signature"),
+            SigningError::InvalidNonce => write!(f, "aggregate nonce is infinity"),
+            SigningError::ZeroInput => write!(f, "signer input is zero"),
+            SigningError::ReusedNonce(x) => write!(f, "reused nonce from {:?}", x),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SigningError {}
+
+/// A signer's secret nonces for a single signing session.
+///
+/// Nonces cannot be cloned, so that they are used for at most one call to
+/// [`SigningPackage::sign`]. With the `zeroize` feature, they are wiped when dropped.
+pub struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+}
+
+/// Omits the secret nonces.
+impl core::fmt::Debug for SigningNonces {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SigningNonces").finish_non_exhaustive()
+    }
+}
+
+/// Overwrites both nonces with a public value, since a [`Scalar`] cannot be zero.
+#[cfg(feature = "zeroize")]
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        // SAFETY: both fields are valid, aligned, unique references. Volatile writes
+        // prevent the wipe being optimized out.
+        unsafe {
+            core::ptr::write_volatile(&mut self.hiding, Scalar::one());
+            core::ptr::write_volatile(&mut self.binding, Scalar::one());
+        }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SigningNonces {}
+
+impl SigningNonces {
+    /// Construct nonces from two secret scalars, which must be uniformly random
+    /// and never reused.
+    pub fn new(hiding: Scalar, binding: Scalar) -> Self {
+        SigningNonces { hiding, binding }
+    }
+
+    /// Sample random nonces.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        SigningNonces::new(Scalar::random(rng), Scalar::random(rng))
+    }
+
+    /// Derive nonces deterministically from a secret share, the message to be signed,
+    /// a session ID, and a transcript of any public context of the session, such as
+    /// the group key and the inputs of the other signers.
+    ///
+    /// Signers without a reliable source of randomness can use this in place of
+    /// random nonces. The same arguments always give the same nonces, so
+    /// `session_id` must be unique to each signing session: a session run twice with
+    /// the same session ID and message, but different co-signers' commitments, would
+    /// reveal the share. Each nonce is hashed with [`tagged_hash`], with every
+    /// variable-length argument prefixed by its length.
+    pub fn derive(
+        share: &SecretShare,
+        message: &[u8; 32],
+        session_id: &[u8],
+        transcript: &[u8],
+    ) -> Self {
+        let mut preimage = Vec::with_capacity(64 + 32 + 16 + session_id.len() + transcript.len());
+        preimage.extend_from_slice(&share.to_bytes());
+        preimage.extend_from_slice(message);
+        preimage.extend_from_slice(&(session_id.len() as u64).to_be_bytes());
+        preimage.extend_from_slice(session_id);
+        preimage.extend_from_slice(&(transcript.len() as u64).to_be_bytes());
+        preimage.extend_from_slice(transcript);
+
+        let hiding = Scalar::reduce_from(&tagged_hash(b"qudoku/signing/nonce/hiding", &preimage));
+        let binding = Scalar::reduce_from(&tagged_hash(b"qudoku/signing/nonce/binding", &preimage));
+        SigningNonces::new(hiding, binding)
+    }
+
+    /// Compute the public commitments to these nonces, for the signer at `input`.
+    pub fn commitments(&self, input: MaybeScalar) -> NonceCommitments {
+        NonceCommitments {
+            input,
+            hiding: self.hiding * G,
+            binding: self.binding * G,
+        }
+    }
+}
+
+/// The public commitments to a signer's [`SigningNonces`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonceCommitments {
+    /// The input `x` of the signer's share.
+    pub input: MaybeScalar,
+    pub hiding: Point,
+    pub binding: Point,
+}
+
+/// A signer's partial signature, to be aggregated with the others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartialSignature {
+    /// The input `x` of the signer's share.
+    pub input: MaybeScalar,
+    pub response: MaybeScalar,
+}
+
+/// The public state of a signing session, which every signer and the combiner
+/// construct identically.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningPackage {
+    group_key: Point,
+    message: [u8; 32],
+    commitments: Vec<NonceCommitments>,
+    binding_factors: Vec<MaybeScalar>,
+    lagrange_coefficients: Vec<MaybeScalar>,
+    nonce_point: Point,
+    challenge: MaybeScalar,
+}
+
+impl SigningPackage {
+    /// Construct the signing package for a message, under the group key `f(0) * G`,
+    /// from the nonce commitments of a threshold of signers.
+    ///
+    /// Returns an error if any signer's input is zero or appears more than once, or
+    /// if any nonce commitment appears more than once, which means a nonce was reused.
+    pub fn new(
+        group_key: Point,
+        message: [u8; 32],
+        commitments: Vec<NonceCommitments>,
+    ) -> Result<Self, SigningError> {
+        if commitments.iter().any(|c| c.input.is_zero()) {
+            return Err(SigningError::ZeroInput);
+        }
+        for (i, c) in commitments.iter().enumerate() {
+            if commitments[..i].iter().any(|other| other.input == c.input) {
+                return Err(SigningError::DuplicateSigner(c.input));
+            }
+        }
+        for (i, c) in commitments.iter().enumerate() {
+            let reused = c.hiding == c.binding
+                || commitments[..i].iter().any(|other| {
+                    [other.hiding, other.binding].contains(&c.hiding)
+                        || [other.hiding, other.binding].contains(&c.binding)
+                });
+            if reused {
+                return Err(SigningError::ReusedNonce(c.input));
+            }
+        }
+
+        // The binding factors commit to every signer's nonces, so that no signer
+        // can adapt their nonce after seeing the others.
+        let mut encoded = Vec::with_capacity(32 + 32 + commitments.len() * (32 + 33 + 33));
+        encoded.extend_from_slice(&group_key.serialize_xonly());
+        encoded.extend_from_slice(&message);
+        for c in &commitments {
+            encoded.extend_from_slice(&c.input.serialize());
+            encoded.extend_from_slice(&c.hiding.serialize());
+            encoded.extend_from_slice(&c.binding.serialize());
+        }
+        let binding_factors: Vec<MaybeScalar> = commitments
+            .iter()
+            .map(|c| {
+                let mut preimage = c.input.serialize().to_vec();
+                preimage.extend_from_slice(&encoded);
+                MaybeScalar::reduce_from(&tagged_hash(b"qudoku/signing/binding", &preimage))
+            })
+            .collect();
+
+        let nonce_point = commitments
+            .iter()
+            .zip(&binding_factors)
+            .fold(MaybePoint::Infinity, |sum, (c, &rho)| {
+                sum + c.hiding + rho * c.binding
+            })
+            .not_inf()
+            .map_err(|_| SigningError::InvalidNonce)?;
+
+        let inputs: Vec<MaybeScalar> = commitments.iter().map(|c| c.input).collect();
+        let lagrange_coefficients = lagrange_coefficients_at_zero(&inputs);
+
+        let challenge =
+            schnorr_challenge(&nonce_point.to_even_y(), &group_key.to_even_y(), &message);
+
+        Ok(SigningPackage {
+            group_key,
+            message,
+            commitments,
+            binding_factors,
+            lagrange_coefficients,
+            nonce_point,
+            challenge,
+        })
+    }
+
+    /// Returns the group public key `f(0) * G`.
+    pub fn group_key(&self) -> Point {
+        self.group_key
+    }
+
+    /// Returns the message being signed.
+    pub fn message(&self) -> &[u8; 32] {
+        &self.message
+    }
+
+    /// Returns the nonce commitments of each signer.
+    pub fn commitments(&self) -> &[NonceCommitments] {
+        &self.commitments
+    }
+
+    fn signer_index(&self, input: MaybeScalar) -> Result<usize, SigningError> {
+        self.commitments
+            .iter()
+            .position(|c| c.input == input)
+            .ok_or(SigningError::UnknownSigner(input))
+    }
+
+    /// Produce a partial signature with a secret share and the nonces whose commitments
+    /// were included in this package. The nonces are consumed to discourage reuse.
+    pub fn sign(
+        &self,
+        share: &SecretShare,
+        nonces: SigningNonces,
+    ) -> Result<PartialSignature, SigningError> {
+        let i = self.signer_index(share.input)?;
+        let expected = nonces.commitments(share.input);
+        if expected != self.commitments[i] {
+            return Err(SigningError::MismatchedNonces(share.input));
+        }
+
+        // BIP-340 uses the even-Y versions of the nonce point and group key.
+        let k = MaybeScalar::from(nonces.hiding) + self.binding_factors[i] * nonces.binding;
+        let k = k.negate_if(self.nonce_point.parity());
+        let s = share.output.negate_if(self.group_key.parity());
+
+        Ok(PartialSignature {
+            input: share.input,
+            response: k + self.lagrange_coefficients[i] * self.challenge * s,
+        })
+    }
+
+    /// Check a partial signature against the signer's verification share `f(x) * G`.
+    pub fn verify_partial(
+        &self,
+        partial: &PartialSignature,
+        verification_share: &PointShare,
+    ) -> bool {
+        let i = match self.signer_index(partial.input) {
+            Ok(i) => i,
+            Err(_) => return false,
+        };
+        if verification_share.input != partial.input {
+            return false;
+        }
+
+        let c = &self.commitments[i];
+        let mut nonce = c.hiding + self.binding_factors[i] * c.binding;
+        if self.nonce_point.has_odd_y() {
+            nonce = -nonce;
+        }
+        let mut public = verification_share.output;
+        if self.group_key.has_odd_y() {
+            public = -public;
+        }
+
+        partial.response * G == nonce + self.lagrange_coefficients[i] * self.challenge * public
+    }
+
+    /// Aggregate a partial signature from every signer into a BIP-340 signature, which
+    /// is checked before being returned. If this fails with
+    /// [`SigningError::InvalidSignature`], use [`verify_partial`][Self::verify_partial]
+    /// to find the faulty signer.
+    pub fn aggregate(&self, partials: &[PartialSignature]) -> Result<[u8; 64], SigningError> {
+        for partial in partials {
+            self.signer_index(partial.input)?;
+        }
+        let mut response = MaybeScalar::Zero;
+        for c in &self.commitments {
+            let mut matching = partials.iter().filter(|p| p.input == c.input);
+            match (matching.next(), matching.next()) {
+                (Some(p), None) => response += p.response,
+                (None, _) => return Err(SigningError::Missing(c.input)),
+                (Some(_), Some(_)) => return Err(SigningError::DuplicateSigner(c.input)),
+            }
+        }
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&self.nonce_point.serialize_xonly());
+        signature[32..].copy_from_slice(&response.serialize());
+
+        if !verify_schnorr(self.group_key, &self.message, &signature) {
+            return Err(SigningError::InvalidSignature);
+        }
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretSharingPolynomial;
+
+    #[test]
+    fn test_threshold_signing() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(0xDEADBEEF),
+            MaybeScalar::from(0xCAFE),
+            MaybeScalar::from(0xBABE),
+        ]);
+        let group_key = f.commitment().public_key().unwrap();
+        let message = [0x42; 32];
+
+        for signers in [[1u128, 2, 3], [2, 4, 5], [5, 1, 3]] {
+            let shares: Vec<SecretShare> = signers
+                .iter()
+                .map(|&i| f.issue_share(MaybeScalar::from(i)))
+                .collect();
+            let nonces: Vec<SigningNonces> = signers
+                .iter()
+                .map(|&i| {
+                    SigningNonces::new(
+                        Scalar::try_from(100 + i).unwrap(),
+                        Scalar::try_from(200 + i).unwrap(),
+                    )
+                })
+                .collect();
+            let commitments = shares
+                .iter()
+                .zip(&nonces)
+                .map(|(share, n)| n.commitments(share.input))
+                .collect();
+
+            let package = SigningPackage::new(group_key, message, commitments).unwrap();
+            let partials: Vec<PartialSignature> = shares
+                .iter()
+                .zip(nonces)
+                .map(|(share, n)| package.sign(share, n).unwrap())
+                .collect();
+
+            for (partial, share) in partials.iter().zip(&shares) {
+                assert!(package.verify_partial(partial, &share.verification_share()));
+            }
+
+            let signature = package.aggregate(&partials).unwrap();
+            assert!(verify_schnorr(group_key, &message, &signature));
+
+            let mut bad_partials = partials.clone();
+            bad_partials[1].response += MaybeScalar::one();
+            assert!(!package.verify_partial(&bad_partials[1], &shares[1].verification_share()));
+            assert_eq!(
+                package.aggregate(&bad_partials),
+                Err(SigningError::InvalidSignature)
+            );
+            assert_eq!(
+                package.aggregate(&partials[..2]),
+                Err(SigningError::Missing(shares[2].input))
+            );
+        }
+    }
+
+    #[test]
+    fn test_derived_nonces() {
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(0xF00D), MaybeScalar::from(3)]);
+        let group_key = f.commitment().public_key().unwrap();
+        let message = [0x24; 32];
+        let shares: Vec<SecretShare> = (1..=2)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        let transcript = group_key.serialize();
+        let derive = |share: &SecretShare, session_id: &[u8]| {
+            SigningNonces::derive(share, &message, session_id, &transcript)
+        };
+        let commitments = |session_id: &[u8]| -> Vec<NonceCommitments> {
+            shares
+                .iter()
+                .map(|share| derive(share, session_id).commitments(share.input))
+                .collect()
+        };
+
+        // Derivation is deterministic, and bound to the share and the session.
+        assert_eq!(commitments(b"session 1"), commitments(b"session 1"));
+        assert_ne!(commitments(b"session 1"), commitments(b"session 2"));
+        assert_ne!(
+            derive(&shares[0], b"session 1").commitments(shares[0].input),
+            derive(&shares[0], b"session 1").commitments(shares[1].input)
+        );
+        assert_ne!(
+            SigningNonces::derive(&shares[0], &[0; 32], b"session 1", &transcript)
+                .commitments(shares[0].input),
+            derive(&shares[0], b"session 1").commitments(shares[0].input)
+        );
+
+        let package = SigningPackage::new(group_key, message, commitments(b"session 1")).unwrap();
+        let partials: Vec<PartialSignature> = shares
+            .iter()
+            .map(|share| package.sign(share, derive(share, b"session 1")).unwrap())
+            .collect();
+        let signature = package.aggregate(&partials).unwrap();
+        assert!(verify_schnorr(group_key, &message, &signature));
+
+        // A signer who reuses another signer's nonces is rejected.
+        let mut reused = commitments(b"session 1");
+        reused[1].hiding = reused[0].hiding;
+        assert_eq!(
+            SigningPackage::new(group_key, message, reused),
+            Err(SigningError::ReusedNonce(shares[1].input))
+        );
+        let mut reused = commitments(b"session 1");
+        reused[1].binding = reused[1].hiding;
+        assert_eq!(
+            SigningPackage::new(group_key, message, reused),
+            Err(SigningError::ReusedNonce(shares[1].input))
+        );
+    }
+
+    #[test]
+    fn test_signing_rejects_zero_signer() {
+        let nonces = SigningNonces::new(Scalar::try_from(7u128).unwrap(), Scalar::one());
+        let group_key = (MaybeScalar::from(5) * G).unwrap();
+        let commitments = vec![
+            nonces.commitments(MaybeScalar::one()),
+            nonces.commitments(MaybeScalar::Zero),
+        ];
+        assert_eq!(
+            SigningPackage::new(group_key, [0; 32], commitments),
+            Err(SigningError::ZeroInput)
+        );
+        assert_eq!(format!("{:?}", nonces), "SigningNonces { .. }");
+    }
+}
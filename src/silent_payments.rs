@@ -0,0 +1,110 @@
+//! Derivation of [BIP-352](https://github.com/bitcoin/bips/blob/master/bip-0352.mediawiki)
+//! silent payment keys from a qudoku group.
+//!
+//! The spend key is the group's primary secret `f(0)`, so spending requires a
+//! quorum to reconstruct it. The scan key is a nested secret derived from a fixed
+//! `Q` point. Since the dealer knows the point-sharing polynomial `Z(x) = f(x) * Q`,
+//! the scan key can be exported at dealing time and handed to a watch-only scanner
+//! without weakening the spend key. A quorum can also recompute the scan key later
+//! from point shares.
+//!
+//! Encoding the resulting keys as a `sp1...` address is left to the caller.
+
+use crate::{
+    hash_to_point_tagged, InterpolatedPointPolynomial, PointShare, PointSharingPolynomial,
+    SecretShare, SecretSharingPolynomial,
+};
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar, Point, G};
+
+/// Returns the `Q` point used to derive the scan key.
+pub fn scan_key_point() -> Point {
+    hash_to_point_tagged(b"qudoku/silent-payments", b"scan")
+}
+
+/// The tag under which the scan key is derived.
+const SCAN_KEY_TAG: &[u8] = b"qudoku/silent-payments/scan";
+
+fn scan_key_from_secret(secret: [u8; 32]) -> MaybeScalar {
+    MaybeScalar::reduce_from(&secret)
+}
+
+/// The key material needed to publish a silent payment address for a group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SilentPaymentKeys {
+    /// The private scan key `b_scan`, used to detect incoming payments.
+    pub scan_key: MaybeScalar,
+
+    /// The public spend key `B_spend = f(0) * G`.
+    pub spend_pubkey: MaybePoint,
+}
+
+impl SilentPaymentKeys {
+    /// Derive the silent payment keys for a group from the dealer's secret-sharing polynomial.
+    pub fn from_dealer(secret_polynomial: &SecretSharingPolynomial) -> Self {
+        let scan_polynomial = scan_key_point() * secret_polynomial;
+        Self::from_scan_polynomial(&scan_polynomial, secret_polynomial.coefficients[0] * G)
+    }
+
+    /// Derive the silent payment keys from the point-sharing polynomial
+    /// `Z(x) = f(x) * Q`, where `Q` is [`scan_key_point`], and the group's public key.
+    ///
+    /// This allows deriving the scan key without access to the secret polynomial.
+    pub fn from_scan_polynomial(
+        scan_polynomial: &PointSharingPolynomial,
+        spend_pubkey: MaybePoint,
+    ) -> Self {
+        SilentPaymentKeys {
+            scan_key: scan_key_from_secret(
+                scan_polynomial.derive_secret_tagged(SCAN_KEY_TAG, MaybeScalar::Zero),
+            ),
+            spend_pubkey,
+        }
+    }
+
+    /// Returns the public scan key `B_scan = b_scan * G`.
+    pub fn scan_pubkey(&self) -> MaybePoint {
+        self.scan_key * G
+    }
+}
+
+/// Compute a shareholder's point share towards recomputing the scan key.
+pub fn scan_key_share(share: &SecretShare) -> PointShare {
+    share.to_point_share(scan_key_point())
+}
+
+/// Recompute the private scan key from a quorum of point shares issued
+/// using [`scan_key_share`].
+pub fn recover_scan_key(point_shares: Vec<PointShare>) -> MaybeScalar {
+    let secret = InterpolatedPointPolynomial::new(point_shares)
+        .derive_secret_tagged(SCAN_KEY_TAG, MaybeScalar::Zero);
+    scan_key_from_secret(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InterpolatedSecretPolynomial, Polynomial};
+
+    #[test]
+    fn test_silent_payment_keys() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(31337),
+            MaybeScalar::from(2),
+            MaybeScalar::from(3),
+        ]);
+        let keys = SilentPaymentKeys::from_dealer(&f);
+        assert_eq!(keys.spend_pubkey, MaybeScalar::from(31337) * G);
+        assert_ne!(keys.scan_key, MaybeScalar::Zero);
+
+        let shares: Vec<SecretShare> = (1..=3)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+
+        let scan_key = recover_scan_key(shares.iter().map(scan_key_share).collect());
+        assert_eq!(scan_key, keys.scan_key);
+
+        let spend_key = InterpolatedSecretPolynomial::new(shares).evaluate(MaybeScalar::Zero);
+        assert_eq!(spend_key * G, keys.spend_pubkey);
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{
+    check_duplicate_inputs, derive_q,
+    dleq::{prove_point_share, verify_point_shares, DleqProof},
+    InterpolatedPointPolynomial, PointShare, PointSharingPolynomial, Polynomial, SecretShare,
+    SecretSharingPolynomial,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use secp::{MaybePoint, MaybeScalar, Point, G};
+
+/// Returns the `Q` point used to derive the nested secret with the given label, for
+/// the vault with public key `group_key`. This is [`derive_q`], so the nested secrets
+/// of different vaults are unrelated even if they share labels.
+pub fn vault_q_point(group_key: Point, label: &str) -> Point {
+    derive_q(group_key, label.as_bytes())
+}
+
+/// The tag under which a vault's nested secrets are derived.
+const NESTED_SECRET_TAG: &[u8] = b"qudoku/vault/nested";
+
+/// Returns the `Q` point for `label`, given a vault's public key `f(0) * G`.
+///
+/// Panics if the public key is infinity, i.e. if the vault's primary secret is zero.
+fn q_point_for(public_key: MaybePoint, label: &str) -> Point {
+    let group_key = public_key
+        .not_inf()
+        .expect("vault primary secret must not be zero");
+    vault_q_point(group_key, label)
+}
+
+/// A high-level entry point for dealing a qudoku group, for applications which
+/// do not want to compose the polynomial APIs themselves.
+///
+/// A vault is held by the dealer. It issues [`SecretShare`]s to shareholders and
+/// derives any number of labeled nested secrets, each from its own `Q` point
+/// (see [`vault_q_point`]). The dealer then publishes the [`VaultPublicInfo`],
+/// which is all the shareholders need to verify their shares and later recover
+/// either the primary secret or any nested secret.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Vault {
+    polynomial: SecretSharingPolynomial,
+    labels: Vec<String>,
+}
+
+impl core::fmt::Debug for Vault {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Vault")
+            .field("threshold", &self.threshold())
+            .field("labels", &self.labels)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Vault {
+    /// Construct a vault from the dealer's secret-sharing polynomial.
+    pub fn new(polynomial: SecretSharingPolynomial) -> Self {
+        Vault {
+            polynomial,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Deal a new vault for the given primary secret, such that `threshold`
+    /// shares are needed to recover it.
+    ///
+    /// Panics if `threshold` is zero.
+    #[cfg(feature = "rand")]
+    pub fn deal<R: rand::RngCore + rand::CryptoRng>(
+        secret: MaybeScalar,
+        threshold: usize,
+        rng: &mut R,
+    ) -> Self {
+        Vault::new(SecretSharingPolynomial::random_with_secret(
+            secret, threshold, rng,
+        ))
+    }
+
+    /// Returns the primary secret `f(0)`, which is zero if the polynomial has no
+    /// coefficients.
+    pub fn secret(&self) -> MaybeScalar {
+        self.polynomial.evaluate(MaybeScalar::Zero)
+    }
+
+    /// Returns the number of shares needed to recover the vault's secrets.
+    pub fn threshold(&self) -> usize {
+        self.polynomial.coefficients.len()
+    }
+
+    /// Register a labeled nested secret, returning its value. Registering the
+    /// same label twice has no further effect.
+    ///
+    /// Panics if the primary secret is zero.
+    pub fn register_secret(&mut self, label: &str) -> [u8; 32] {
+        if !self.labels.iter().any(|l| l == label) {
+            self.labels.push(label.to_string());
+        }
+        self.nested_secret(label)
+    }
+
+    /// Compute the nested secret with the given label, whether or not it is registered.
+    ///
+    /// Panics if the primary secret is zero.
+    pub fn nested_secret(&self, label: &str) -> [u8; 32] {
+        let q = q_point_for(G * self.secret(), label);
+        let z: PointSharingPolynomial = q * &self.polynomial;
+        z.derive_secret_tagged(NESTED_SECRET_TAG, MaybeScalar::Zero)
+    }
+
+    /// Issue a share to a shareholder at the given input `x`.
+    pub fn issue_share(&self, x: MaybeScalar) -> SecretShare {
+        self.polynomial.issue_share(x)
+    }
+
+    /// Export the vault's public information.
+    pub fn public_info(&self) -> VaultPublicInfo {
+        VaultPublicInfo {
+            commitment: G * &self.polynomial,
+            labels: self.labels.clone(),
+        }
+    }
+}
+
+/// The public information about a [`Vault`], which is safe to distribute to
+/// every shareholder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VaultPublicInfo {
+    /// The commitment `f(x) * G` to the secret-sharing polynomial.
+    pub commitment: PointSharingPolynomial,
+
+    /// The labels of the registered nested secrets.
+    pub labels: Vec<String>,
+}
+
+impl VaultPublicInfo {
+    /// Returns the group's public key `f(0) * G`.
+    pub fn public_key(&self) -> MaybePoint {
+        self.commitment.evaluate(MaybeScalar::Zero)
+    }
+
+    /// Returns the number of shares needed to recover the vault's secrets.
+    pub fn threshold(&self) -> usize {
+        self.commitment.coefficients.len()
+    }
+
+    /// Check that a secret share is consistent with the vault's commitment.
+    pub fn verify_share(&self, share: &SecretShare) -> bool {
+        self.commitment.evaluate(share.input) == share.output * G
+    }
+
+    /// Recover the primary secret from a quorum of secret shares. Returns `None`
+    /// if too few shares are given, if any share fails verification, or if two
+    /// shares have the same input.
+    pub fn recover_secret(&self, shares: &[SecretShare]) -> Option<MaybeScalar> {
+        if !shares.iter().all(|s| self.verify_share(s)) {
+            return None;
+        }
+        crate::recover_secret(shares, self.threshold()).ok()
+    }
+
+    /// Compute a shareholder's point share towards recovering the nested secret
+    /// with the given label, along with a proof that it was computed from the same
+    /// scalar as their verification share.
+    ///
+    /// Panics if the vault's public key is infinity.
+    pub fn nested_secret_share(&self, share: &SecretShare, label: &str) -> (PointShare, DleqProof) {
+        prove_point_share(share, q_point_for(self.public_key(), label))
+    }
+
+    /// Recover the nested secret with the given label from a quorum of point shares
+    /// and proofs issued with [`nested_secret_share`][Self::nested_secret_share],
+    /// given as parallel slices.
+    ///
+    /// Returns `None` if the label is not registered, if too few shares are given,
+    /// if two shares have the same input, if the vault's public key is infinity, or if
+    /// any point share fails verification against the commitment.
+    pub fn recover_nested_secret(
+        &self,
+        label: &str,
+        point_shares: &[PointShare],
+        proofs: &[DleqProof],
+    ) -> Option<[u8; 32]> {
+        if !self.labels.iter().any(|l| l == label)
+            || point_shares.len() < self.threshold()
+            || point_shares.iter().any(|z| z.input == MaybeScalar::Zero)
+            || check_duplicate_inputs(point_shares).is_err()
+        {
+            return None;
+        }
+        let group_key = self.public_key().not_inf().ok()?;
+
+        let verification_shares: Vec<PointShare> = point_shares
+            .iter()
+            .map(|z| PointShare::new(z.input, self.commitment.evaluate(z.input)))
+            .collect();
+        verify_point_shares(
+            &verification_shares,
+            point_shares,
+            vault_q_point(group_key, label),
+            proofs,
+        )
+        .ok()?;
+
+        let z = InterpolatedPointPolynomial::new(point_shares.to_vec());
+        Some(z.derive_secret_tagged(NESTED_SECRET_TAG, MaybeScalar::Zero))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault() {
+        let mut vault = Vault::new(SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(1000),
+            MaybeScalar::from(2000),
+            MaybeScalar::from(3000),
+        ]));
+        let wallet_secret = vault.register_secret("wallet");
+        let backup_secret = vault.register_secret("backup");
+        assert_ne!(wallet_secret, backup_secret);
+
+        let info = vault.public_info();
+        assert_eq!(info.labels, vec!["wallet", "backup"]);
+        assert_eq!(info.threshold(), 3);
+        assert_eq!(info.public_key(), MaybeScalar::from(1000) * G);
+
+        let shares: Vec<SecretShare> = (1..=5)
+            .map(|i| vault.issue_share(MaybeScalar::from(i)))
+            .collect();
+        assert!(shares.iter().all(|share| info.verify_share(share)));
+
+        assert_eq!(info.recover_secret(&shares[1..4]), Some(vault.secret()));
+        assert_eq!(info.recover_secret(&shares[..2]), None);
+
+        let mut bad_share = shares[0];
+        bad_share.output = MaybeScalar::from(1);
+        assert!(!info.verify_share(&bad_share));
+        assert_eq!(
+            info.recover_secret(&[bad_share, shares[1], shares[2]]),
+            None
+        );
+
+        assert_eq!(
+            info.recover_secret(&[shares[0], shares[1], shares[1]]),
+            None
+        );
+
+        let (point_shares, proofs): (Vec<PointShare>, Vec<DleqProof>) = shares[2..]
+            .iter()
+            .map(|share| info.nested_secret_share(share, "wallet"))
+            .unzip();
+        assert_eq!(
+            info.recover_nested_secret("wallet", &point_shares, &proofs),
+            Some(wallet_secret)
+        );
+        assert_eq!(
+            info.recover_nested_secret("unknown", &point_shares, &proofs),
+            None
+        );
+
+        // A point share computed under another label fails verification.
+        let mut forged = point_shares.clone();
+        forged[0] = info.nested_secret_share(&shares[2], "backup").0;
+        assert_eq!(info.recover_nested_secret("wallet", &forged, &proofs), None);
+
+        let duplicated = [point_shares[0], point_shares[1], point_shares[1]];
+        let duplicated_proofs = [proofs[0], proofs[1], proofs[1]];
+        assert_eq!(
+            info.recover_nested_secret("wallet", &duplicated, &duplicated_proofs),
+            None
+        );
+
+        // Nested secrets are bound to the vault's public key.
+        let other = Vault::new(SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(1001),
+            MaybeScalar::from(2000),
+            MaybeScalar::from(3000),
+        ]));
+        assert_ne!(other.nested_secret("wallet"), wallet_secret);
+
+        let debug = format!("{:?}", vault);
+        assert!(debug.contains("threshold: 3"));
+        assert!(!debug.contains("polynomial"));
+
+        assert_eq!(
+            Vault::new(SecretSharingPolynomial::new(vec![])).secret(),
+            MaybeScalar::Zero
+        );
+    }
+}
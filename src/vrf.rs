@@ -0,0 +1,257 @@
+//! A verifiable random function (VRF) keyed by a group's primary secret, in the
+//! style of [ECVRF](https://www.rfc-editor.org/rfc/rfc9381.html).
+//!
+//! The message is hashed to a point `H` using [`hash_to_point_tagged`], and the VRF output
+//! is derived from `Gamma = k * H`, where `k` is the secret key. A DLEQ proof shows
+//! that `Gamma` was computed with the same secret `k` as the public key `Y = k * G`,
+//! so anyone can verify that the output is the unique correct output for the message.
+//!
+//! The VRF can also be evaluated by a quorum of shareholders without reconstructing
+//! the group secret `f(0)`, for use as a randomness beacon. Each shareholder publishes
+//! a [`VrfShare`] containing `s_i * H` and a DLEQ proof against their verification
+//! share `s_i * G`. A combiner checks each proof and Lagrange-interpolates the
+//! point shares to obtain `Gamma = f(0) * H`, yielding the same output as if
+//! the group secret had been used directly.
+
+use crate::{
+    check_duplicate_inputs, dleq::DleqProof, hash_to_point_tagged, tagged_hash, Evaluation,
+    InterpolatedPointPolynomial, PointShare, SecretShare,
+};
+use alloc::vec::Vec;
+use secp::{MaybePoint, MaybeScalar, Point, G};
+
+/// Hash a VRF message to the point `H` which the secret key is applied to.
+pub fn message_point(message: &[u8]) -> Point {
+    hash_to_point_tagged(b"qudoku/vrf", message)
+}
+
+/// Derive the 32-byte VRF output from the point `Gamma`.
+fn output_from_gamma(gamma: &MaybePoint) -> [u8; 32] {
+    tagged_hash(b"qudoku/vrf/output", &gamma.serialize())
+}
+
+/// A proof that a VRF output was computed correctly for a given message and public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VrfProof {
+    /// The point `Gamma = k * H`, from which the output is derived.
+    pub gamma: MaybePoint,
+
+    /// The Fiat-Shamir challenge of the DLEQ proof.
+    pub challenge: MaybeScalar,
+
+    /// The response of the DLEQ proof.
+    pub response: MaybeScalar,
+}
+
+impl VrfProof {
+    fn dleq(&self) -> DleqProof {
+        DleqProof {
+            challenge: self.challenge,
+            response: self.response,
+        }
+    }
+
+    /// Returns the VRF output which this proof attests to. The output should only
+    /// be trusted after the proof has been verified with [`verify`].
+    pub fn output(&self) -> [u8; 32] {
+        output_from_gamma(&self.gamma)
+    }
+}
+
+/// Evaluate the VRF on a message using the given secret key, returning the
+/// 32-byte output and a proof of its correctness.
+pub fn prove(secret_key: MaybeScalar, message: &[u8]) -> ([u8; 32], VrfProof) {
+    let h = MaybePoint::from(message_point(message));
+    let gamma = secret_key * h;
+    let dleq = DleqProof::prove(secret_key, MaybePoint::from(Point::generator()), h);
+
+    let proof = VrfProof {
+        gamma,
+        challenge: dleq.challenge,
+        response: dleq.response,
+    };
+    (proof.output(), proof)
+}
+
+/// Verify a VRF proof for a message under the given public key `Y = k * G`.
+/// Returns the VRF output if the proof is valid.
+pub fn verify(public_key: MaybePoint, message: &[u8], proof: &VrfProof) -> Option<[u8; 32]> {
+    let h = MaybePoint::from(message_point(message));
+    if proof.dleq().verify(
+        MaybePoint::from(Point::generator()),
+        public_key,
+        h,
+        proof.gamma,
+    ) {
+        Some(proof.output())
+    } else {
+        None
+    }
+}
+
+/// Compute the public key `Y = k * G` for a VRF secret key.
+pub fn public_key(secret_key: MaybeScalar) -> MaybePoint {
+    secret_key * G
+}
+
+/// A shareholder's contribution towards evaluating the VRF with the group secret.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VrfShare {
+    /// The point share `s_i * H`, issued at the shareholder's input `x_i`.
+    pub share: PointShare,
+
+    /// The Fiat-Shamir challenge of the DLEQ proof.
+    pub challenge: MaybeScalar,
+
+    /// The response of the DLEQ proof.
+    pub response: MaybeScalar,
+}
+
+impl VrfShare {
+    /// Verify this VRF share against the shareholder's verification share `s_i * G`.
+    pub fn verify(&self, verification_share: &PointShare, message: &[u8]) -> bool {
+        if self.share.input != verification_share.input {
+            return false;
+        }
+        let h = MaybePoint::from(message_point(message));
+        let dleq = DleqProof {
+            challenge: self.challenge,
+            response: self.response,
+        };
+        dleq.verify(
+            MaybePoint::from(Point::generator()),
+            verification_share.output,
+            h,
+            self.share.output,
+        )
+    }
+}
+
+/// Compute a shareholder's contribution towards the group's VRF output on a message.
+pub fn prove_share(share: &SecretShare, message: &[u8]) -> VrfShare {
+    let h = MaybePoint::from(message_point(message));
+    let dleq = DleqProof::prove(share.output, MaybePoint::from(Point::generator()), h);
+    VrfShare {
+        share: Evaluation::new(share.input, share.output * h),
+        challenge: dleq.challenge,
+        response: dleq.response,
+    }
+}
+
+/// Combine a quorum of VRF shares into the group's VRF output on a message.
+///
+/// Each VRF share is checked against the verification share at the same position
+/// in `verification_shares`. Returns `None` if the slices differ in length, if two
+/// shares have the same input, or if any share fails to verify. The caller is responsible for supplying at least as many
+/// shares as the group's threshold; otherwise the output will be incorrect.
+pub fn combine_shares(
+    message: &[u8],
+    vrf_shares: &[VrfShare],
+    verification_shares: &[PointShare],
+) -> Option<[u8; 32]> {
+    if vrf_shares.len() != verification_shares.len() {
+        return None;
+    }
+    let point_shares: Vec<PointShare> =
+        vrf_shares.iter().map(|vrf_share| vrf_share.share).collect();
+    if check_duplicate_inputs(&point_shares).is_err() {
+        return None;
+    }
+    for (vrf_share, verification_share) in vrf_shares.iter().zip(verification_shares) {
+        if !vrf_share.verify(verification_share, message) {
+            return None;
+        }
+    }
+
+    let gamma = InterpolatedPointPolynomial::new(point_shares).evaluate_fast(MaybeScalar::Zero);
+    Some(output_from_gamma(&gamma))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vrf() {
+        let secret_key = MaybeScalar::from(0x1234567890);
+        let pubkey = public_key(secret_key);
+
+        let (output, proof) = prove(secret_key, b"round 1");
+        assert_eq!(verify(pubkey, b"round 1", &proof), Some(output));
+        assert_eq!(verify(pubkey, b"round 2", &proof), None);
+
+        let (output2, _) = prove(secret_key, b"round 2");
+        assert_ne!(output, output2);
+
+        // Determinism
+        assert_eq!(prove(secret_key, b"round 1"), (output, proof));
+
+        // A forged gamma point must not verify.
+        let mut forged = proof;
+        forged.gamma = MaybeScalar::from(5) * message_point(b"round 1");
+        assert_eq!(verify(pubkey, b"round 1", &forged), None);
+
+        let other_key = public_key(MaybeScalar::from(99));
+        assert_eq!(verify(other_key, b"round 1", &proof), None);
+    }
+
+    #[test]
+    fn test_threshold_vrf() {
+        let f = crate::SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(777),
+            MaybeScalar::from(31),
+            MaybeScalar::from(9),
+        ]);
+        let shares: Vec<SecretShare> = (1..=5)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+        let verification_shares = SecretShare::verification_shares(&shares);
+
+        let message = b"beacon round 42";
+        let (expected, _) = prove(MaybeScalar::from(777), message);
+
+        let vrf_shares: Vec<VrfShare> = shares
+            .iter()
+            .map(|share| prove_share(share, message))
+            .collect();
+        for (vrf_share, verification_share) in vrf_shares.iter().zip(&verification_shares) {
+            assert!(vrf_share.verify(verification_share, message));
+            assert!(!vrf_share.verify(verification_share, b"other"));
+        }
+
+        assert_eq!(
+            combine_shares(message, &vrf_shares[..3], &verification_shares[..3]),
+            Some(expected)
+        );
+        assert_eq!(
+            combine_shares(message, &vrf_shares[2..], &verification_shares[2..]),
+            Some(expected)
+        );
+
+        // Mismatched verification shares are rejected.
+        assert_eq!(
+            combine_shares(message, &vrf_shares[..3], &verification_shares[1..4]),
+            None
+        );
+
+        // A corrupted share is rejected.
+        let mut corrupted = vrf_shares[..3].to_vec();
+        corrupted[1].share.output = vrf_shares[3].share.output;
+        assert_eq!(
+            combine_shares(message, &corrupted, &verification_shares[..3]),
+            None
+        );
+
+        // Duplicate shares are rejected rather than causing a division by zero.
+        let duplicated = [vrf_shares[0], vrf_shares[1], vrf_shares[1]];
+        let duplicated_verification = [
+            verification_shares[0],
+            verification_shares[1],
+            verification_shares[1],
+        ];
+        assert_eq!(
+            combine_shares(message, &duplicated, &duplicated_verification),
+            None
+        );
+    }
+}
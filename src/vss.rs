@@ -0,0 +1,216 @@
+use crate::{
+    hash_to_point_tagged, parallel::par_map, Evaluation, PointShare, PointSharingPolynomial,
+    Polynomial, SecretShare, SecretSharingPolynomial,
+};
+use secp::{MaybePoint, MaybeScalar, Point, G};
+
+/// A [Feldman VSS](https://en.wikipedia.org/wiki/Verifiable_secret_sharing) commitment
+/// to a secret-sharing polynomial `f(x)`, consisting of each coefficient multiplied by
+/// the generator point `G`.
+///
+/// The commitment is itself the point-sharing polynomial `f(x) * G`, so evaluating it
+/// at a share's input gives the expected verification share. Publishing a commitment
+/// reveals the group's public key `f(0) * G`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VssCommitment {
+    pub polynomial: PointSharingPolynomial,
+}
+
+impl VssCommitment {
+    /// Construct a commitment from the point-sharing polynomial `f(x) * G`.
+    pub fn new(polynomial: PointSharingPolynomial) -> Self {
+        VssCommitment { polynomial }
+    }
+
+    /// Returns the committed coefficient points, starting with `f(0) * G`.
+    pub fn coefficients(&self) -> &[MaybePoint] {
+        &self.polynomial.coefficients
+    }
+
+    /// Returns the number of shares needed to recover the committed secret.
+    pub fn threshold(&self) -> usize {
+        self.polynomial.coefficients.len()
+    }
+
+    /// Returns the group's public key `f(0) * G`.
+    pub fn public_key(&self) -> MaybePoint {
+        self.polynomial.evaluate(MaybeScalar::Zero)
+    }
+
+    /// Compute the expected verification share `f(x) * G` at input `x`.
+    pub fn verification_share(&self, x: MaybeScalar) -> PointShare {
+        Evaluation::new(x, self.polynomial.evaluate(x))
+    }
+
+    /// Check that a secret share is consistent with the committed polynomial,
+    /// i.e. that `share.output * G == f(share.input) * G`.
+    pub fn verify_share(&self, share: &SecretShare) -> bool {
+        self.polynomial.evaluate(share.input) == share.output * G
+    }
+
+    /// Check a batch of secret shares against the commitment, like
+    /// [`verify_share`][Self::verify_share]. With the `parallel` feature enabled,
+    /// the shares are verified on the rayon thread pool.
+    ///
+    /// Returns `Err(i)` with the index of the first share which fails verification.
+    pub fn verify_shares(&self, shares: &[SecretShare]) -> Result<(), usize> {
+        match par_map(shares, |share| self.verify_share(share))
+            .into_iter()
+            .position(|valid| !valid)
+        {
+            Some(i) => Err(i),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<PointSharingPolynomial> for VssCommitment {
+    fn from(polynomial: PointSharingPolynomial) -> Self {
+        VssCommitment::new(polynomial)
+    }
+}
+
+impl SecretSharingPolynomial {
+    /// Compute the Feldman VSS commitment to this polynomial, which the dealer
+    /// publishes so that shareholders can verify their shares.
+    ///
+    /// With the `parallel` feature enabled, the coefficients are multiplied by `G`
+    /// on the rayon thread pool.
+    pub fn commitment(&self) -> VssCommitment {
+        let coefficients = par_map(&self.coefficients, |&c| c * G);
+        VssCommitment::new(PointSharingPolynomial::new(coefficients))
+    }
+}
+
+/// Returns the second generator point `H` used for Pedersen commitments, which has
+/// no known discrete log relative to `G`.
+pub fn pedersen_generator() -> Point {
+    hash_to_point_tagged(b"qudoku/pedersen", b"H")
+}
+
+/// A [Pedersen VSS](https://link.springer.com/chapter/10.1007/3-540-46766-1_9)
+/// commitment to a secret-sharing polynomial `f(x)` and a random blinding polynomial
+/// `g(x)`, consisting of the points `a_i * G + b_i * H` for each pair of coefficients
+/// `a_i` and `b_i`, where `H` is [`pedersen_generator`].
+///
+/// Unlike a [`VssCommitment`], a Pedersen commitment is information-theoretically
+/// hiding, and does not reveal `f(0) * G`. Each shareholder receives a blinding share
+/// `g(x_i)` alongside their secret share `f(x_i)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PedersenCommitment {
+    pub polynomial: PointSharingPolynomial,
+}
+
+impl PedersenCommitment {
+    /// Returns the number of shares needed to recover the committed secret.
+    pub fn threshold(&self) -> usize {
+        self.polynomial.coefficients.len()
+    }
+
+    /// Check that a secret share and blinding share issued at the same input are
+    /// consistent with the commitment, i.e. that `f(x) * G + g(x) * H == C(x)`.
+    pub fn verify_share(&self, share: &SecretShare, blinding_share: &SecretShare) -> bool {
+        share.input == blinding_share.input
+            && self.polynomial.evaluate(share.input)
+                == share.output * G + blinding_share.output * pedersen_generator()
+    }
+}
+
+impl SecretSharingPolynomial {
+    /// Compute the Pedersen commitment to this polynomial, using the given blinding
+    /// polynomial. The blinding polynomial must be sampled randomly and kept secret.
+    ///
+    /// Panics if the blinding polynomial does not have the same number of coefficients.
+    pub fn pedersen_commitment(&self, blinding: &SecretSharingPolynomial) -> PedersenCommitment {
+        assert_eq!(
+            self.coefficients.len(),
+            blinding.coefficients.len(),
+            "blinding polynomial must have the same number of coefficients"
+        );
+        let h = pedersen_generator();
+        let coefficients = self
+            .coefficients
+            .iter()
+            .zip(blinding.coefficients.iter())
+            .map(|(&a, &b)| a * G + b * h)
+            .collect();
+        PedersenCommitment {
+            polynomial: PointSharingPolynomial::new(coefficients),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feldman_vss() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(123),
+            MaybeScalar::from(456),
+            MaybeScalar::from(789),
+        ]);
+        let commitment = f.commitment();
+        assert_eq!(commitment.threshold(), 3);
+        assert_eq!(commitment.public_key(), MaybeScalar::from(123) * G);
+
+        for i in 1..=5 {
+            let share = f.issue_share(MaybeScalar::from(i));
+            assert!(commitment.verify_share(&share));
+            assert_eq!(
+                commitment.verification_share(share.input),
+                share.verification_share()
+            );
+
+            let mut bad_share = share;
+            bad_share.output = share.output + MaybeScalar::one();
+            assert!(!commitment.verify_share(&bad_share));
+        }
+
+        let other =
+            SecretSharingPolynomial::new(vec![MaybeScalar::from(123), MaybeScalar::from(1)]);
+        assert!(!commitment.verify_share(&other.issue_share(MaybeScalar::from(2))));
+
+        let mut shares: Vec<SecretShare> = (1..=5)
+            .map(|i| f.issue_share(MaybeScalar::from(i)))
+            .collect();
+        assert_eq!(commitment.verify_shares(&shares), Ok(()));
+        shares[3].output += MaybeScalar::one();
+        assert_eq!(commitment.verify_shares(&shares), Err(3));
+    }
+
+    #[test]
+    fn test_pedersen_vss() {
+        let f = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(123),
+            MaybeScalar::from(456),
+            MaybeScalar::from(789),
+        ]);
+        let g = SecretSharingPolynomial::new(vec![
+            MaybeScalar::from(999),
+            MaybeScalar::from(888),
+            MaybeScalar::from(777),
+        ]);
+        let commitment = f.pedersen_commitment(&g);
+        assert_eq!(commitment.threshold(), 3);
+        assert_ne!(
+            commitment.polynomial.coefficients[0],
+            MaybeScalar::from(123) * G
+        );
+
+        for i in 1..=5 {
+            let x = MaybeScalar::from(i);
+            let share = f.issue_share(x);
+            let blinding_share = g.issue_share(x);
+            assert!(commitment.verify_share(&share, &blinding_share));
+
+            let mut bad_share = share;
+            bad_share.output = share.output + MaybeScalar::one();
+            assert!(!commitment.verify_share(&bad_share, &blinding_share));
+
+            let mismatched = g.issue_share(x + MaybeScalar::one());
+            assert!(!commitment.verify_share(&share, &mismatched));
+        }
+    }
+}
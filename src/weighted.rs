@@ -0,0 +1,174 @@
+use crate::{recover_secret, DealerError, Error, SecretShare, SecretSharingPolynomial};
+use alloc::vec::Vec;
+use secp::MaybeScalar;
+
+/// The bundle of shares issued to one participant of a weighted group, with one
+/// evaluation of the secret-sharing polynomial per unit of weight.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeightedShare {
+    /// The participant's position in the weights given to the [`WeightedDealer`].
+    pub participant: usize,
+
+    /// The participant's evaluations, at consecutive inputs.
+    pub shares: Vec<SecretShare>,
+}
+
+impl WeightedShare {
+    /// Returns the participant's weight, i.e. how many evaluations they hold.
+    pub fn weight(&self) -> usize {
+        self.shares.len()
+    }
+}
+
+/// A dealer for groups in which participants carry different weights: a participant
+/// with weight `w` receives `w` evaluations of the polynomial, and any set of
+/// participants whose weights sum to at least the threshold can recover the secret.
+///
+/// Inputs are assigned consecutively from 1 in order of participants, so participant 0
+/// with weight 2 holds the evaluations at 1 and 2, participant 1 starts at 3, and so on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WeightedDealer {
+    threshold: usize,
+    weights: Vec<usize>,
+}
+
+impl WeightedDealer {
+    /// Start dealing a group in which the given total weight is needed to recover the
+    /// secret, with one weight per participant.
+    pub fn new(threshold: usize, weights: Vec<usize>) -> Self {
+        WeightedDealer { threshold, weights }
+    }
+
+    /// Returns the weight needed to recover the secret.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns the sum of all participants' weights.
+    pub fn total_weight(&self) -> usize {
+        self.weights.iter().sum()
+    }
+
+    /// Issue every participant's bundle of shares of the given polynomial, which must
+    /// have `threshold` coefficients.
+    pub fn issue(
+        &self,
+        polynomial: &SecretSharingPolynomial,
+    ) -> Result<Vec<WeightedShare>, DealerError> {
+        if self.threshold == 0 {
+            return Err(DealerError::ZeroThreshold);
+        }
+        if self.threshold > self.total_weight() {
+            return Err(DealerError::ThresholdExceedsShares);
+        }
+        if polynomial.coefficients.len() != self.threshold {
+            return Err(DealerError::WrongCoefficientCount);
+        }
+
+        let mut next_input = 1u128;
+        let bundles = self
+            .weights
+            .iter()
+            .enumerate()
+            .map(|(participant, &weight)| {
+                let start = next_input;
+                next_input += weight as u128;
+                WeightedShare {
+                    participant,
+                    shares: polynomial
+                        .iter_shares(MaybeScalar::from(start))
+                        .take(weight)
+                        .collect(),
+                }
+            })
+            .collect();
+        Ok(bundles)
+    }
+
+    /// Issue every participant's bundle of shares of `secret`, using random
+    /// higher-order coefficients.
+    #[cfg(feature = "rand")]
+    pub fn issue_random<R: rand::RngCore + rand::CryptoRng>(
+        &self,
+        secret: MaybeScalar,
+        rng: &mut R,
+    ) -> Result<Vec<WeightedShare>, DealerError> {
+        if self.threshold == 0 {
+            return Err(DealerError::ZeroThreshold);
+        }
+        self.issue(&SecretSharingPolynomial::random_with_secret(
+            secret,
+            self.threshold,
+            rng,
+        ))
+    }
+}
+
+/// Flatten bundles of weighted shares back into their individual evaluations.
+pub fn flatten_weighted_shares(bundles: &[WeightedShare]) -> Vec<SecretShare> {
+    bundles
+        .iter()
+        .flat_map(|bundle| bundle.shares.iter().copied())
+        .collect()
+}
+
+/// Recover the secret from the bundles of a set of participants whose weights sum to
+/// at least `threshold`.
+///
+/// Returns an error if the total weight is too low, or if two evaluations have the
+/// same input.
+pub fn recover_weighted_secret(
+    bundles: &[WeightedShare],
+    threshold: usize,
+) -> Result<MaybeScalar, Error> {
+    recover_secret(&flatten_weighted_shares(bundles), threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_dealer() {
+        let secret = MaybeScalar::from(31337);
+        let polynomial = SecretSharingPolynomial::new(vec![
+            secret,
+            MaybeScalar::from(2),
+            MaybeScalar::from(3),
+            MaybeScalar::from(4),
+        ]);
+        let dealer = WeightedDealer::new(4, vec![3, 1, 1, 2]);
+        assert_eq!(dealer.total_weight(), 7);
+
+        let bundles = dealer.issue(&polynomial).unwrap();
+        assert_eq!(bundles[0].weight(), 3);
+        assert_eq!(bundles[2].shares[0].input, MaybeScalar::from(5));
+        assert_eq!(
+            bundles[3].shares[1],
+            polynomial.issue_share(MaybeScalar::from(7))
+        );
+
+        // The heavy participant plus any other reaches the threshold.
+        assert_eq!(
+            recover_weighted_secret(&[bundles[0].clone(), bundles[2].clone()], 4),
+            Ok(secret)
+        );
+        assert_eq!(recover_weighted_secret(&bundles[1..], 4), Ok(secret));
+        assert_eq!(
+            recover_weighted_secret(&bundles[1..3], 4),
+            Err(Error::NotEnoughShares {
+                threshold: 4,
+                count: 2
+            })
+        );
+
+        assert_eq!(
+            WeightedDealer::new(8, vec![3, 4]).issue(&polynomial),
+            Err(DealerError::ThresholdExceedsShares)
+        );
+        assert_eq!(
+            WeightedDealer::new(3, vec![3, 4]).issue(&polynomial),
+            Err(DealerError::WrongCoefficientCount)
+        );
+    }
+}
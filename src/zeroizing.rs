@@ -0,0 +1,143 @@
+use crate::{
+    Evaluation, InterpolatedSecretPolynomial, Secret, SecretShare, SecretSharingPolynomial,
+};
+use alloc::vec::Vec;
+use secp::{MaybeScalar, Scalar};
+use zeroize::Zeroize;
+
+/// Overwrite a scalar with zero, in a way which will not be optimized out.
+fn zeroize_scalar(scalar: &mut MaybeScalar) {
+    // Writing only the `Zero` variant could leave the secret bytes of the `Valid`
+    // payload in memory, so the payload is first overwritten with a public value.
+    //
+    // SAFETY: scalar is a valid, aligned, unique reference. Volatile writes prevent
+    // the wipe being optimized out.
+    unsafe {
+        core::ptr::write_volatile(scalar, MaybeScalar::Valid(Scalar::one()));
+        core::ptr::write_volatile(scalar, MaybeScalar::Zero);
+    }
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
+/// Overwrite the whole allocation of a vector with zero bytes, including its spare
+/// capacity, and leave it empty.
+///
+/// Types with drop glue are only emptied, since their elements must be dropped
+/// normally. Memory freed by earlier reallocations of the vector, e.g. while it
+/// was growing, cannot be reached and is left untouched.
+fn wipe_vec<T>(values: &mut Vec<T>) {
+    if core::mem::needs_drop::<T>() {
+        values.clear();
+        return;
+    }
+
+    let byte_len = values.capacity() * core::mem::size_of::<T>();
+    // SAFETY: T has no drop glue, so forgetting the elements is sound. The elements
+    // are never read again as T, and the allocation holds capacity * size_of::<T>()
+    // bytes. Volatile writes prevent the wipe being optimized out.
+    unsafe {
+        values.set_len(0);
+        let ptr = values.as_mut_ptr() as *mut u8;
+        for i in 0..byte_len {
+            core::ptr::write_volatile(ptr.add(i), 0);
+        }
+    }
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
+/// Wipes the share input and output. `SecretShare` is `Copy` and so cannot wipe
+/// itself on drop; wrap it in [`zeroize::Zeroizing`] for that.
+impl Zeroize for SecretShare {
+    fn zeroize(&mut self) {
+        zeroize_scalar(&mut self.input);
+        zeroize_scalar(&mut self.output);
+    }
+}
+
+/// Wipes every coefficient, then empties the polynomial.
+///
+/// Only the current allocation is wiped. If the coefficient vector was reallocated
+/// while growing, copies left in the earlier allocations are not; build the vector
+/// at its final capacity to avoid this.
+impl Zeroize for SecretSharingPolynomial {
+    fn zeroize(&mut self) {
+        self.coefficients.iter_mut().for_each(zeroize_scalar);
+        wipe_vec(&mut self.coefficients);
+    }
+}
+
+/// Wipes every coefficient, leaving them all zero.
+impl<const N: usize> Zeroize for SecretSharingPolynomial<[MaybeScalar; N]> {
+    fn zeroize(&mut self) {
+        self.coefficients.iter_mut().for_each(zeroize_scalar);
+    }
+}
+
+/// Wipes every evaluation, then empties the polynomial.
+///
+/// As with [`SecretSharingPolynomial`], earlier allocations of the evaluation
+/// vector are not wiped.
+impl Zeroize for InterpolatedSecretPolynomial {
+    fn zeroize(&mut self) {
+        self.evaluations
+            .iter_mut()
+            .for_each(Evaluation::<MaybeScalar, MaybeScalar>::zeroize);
+        wipe_vec(&mut self.evaluations);
+    }
+}
+
+/// Wipes the secret value. Wrap the `Secret` in [`zeroize::Zeroizing`] to do this
+/// when it is dropped.
+impl<T: Zeroize> Zeroize for Secret<T> {
+    fn zeroize(&mut self) {
+        self.expose_secret_mut().zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zeroize::Zeroizing;
+
+    #[test]
+    fn test_zeroize() {
+        let mut share = SecretShare::new(MaybeScalar::from(3), MaybeScalar::from(0xC0FFEE));
+        share.zeroize();
+        assert_eq!(
+            share,
+            SecretShare::new(MaybeScalar::Zero, MaybeScalar::Zero)
+        );
+
+        let f = SecretSharingPolynomial::new(vec![MaybeScalar::from(5), MaybeScalar::from(6)]);
+        let mut interpolated = InterpolatedSecretPolynomial::new(vec![
+            f.issue_share(MaybeScalar::from(1)),
+            f.issue_share(MaybeScalar::from(2)),
+        ]);
+
+        let mut f = Zeroizing::new(f);
+        f.zeroize();
+        assert!(f.coefficients.is_empty());
+
+        let mut h = Zeroizing::new(Secret::new(SecretSharingPolynomial::new([
+            MaybeScalar::from(7),
+            MaybeScalar::from(8),
+        ])));
+        h.zeroize();
+        assert_eq!(h.expose_secret().coefficients, [MaybeScalar::Zero; 2]);
+
+        interpolated.zeroize();
+        assert!(interpolated.evaluations.is_empty());
+
+        // Spare capacity is wiped too.
+        let mut coefficients = Vec::with_capacity(4);
+        coefficients.push(MaybeScalar::from(0xBEEF));
+        coefficients.push(MaybeScalar::from(0xCAFE));
+        let ptr = coefficients.as_ptr() as *const u8;
+        let byte_len = coefficients.capacity() * core::mem::size_of::<MaybeScalar>();
+        let mut g = SecretSharingPolynomial::new(coefficients);
+        g.zeroize();
+        // SAFETY: the allocation is still owned by g, which has not been dropped.
+        let bytes = unsafe { core::slice::from_raw_parts(ptr, byte_len) };
+        assert!(bytes.iter().all(|&b| b == 0));
+    }
+}